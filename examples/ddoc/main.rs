@@ -5,10 +5,12 @@ use clap::Arg;
 use deno_doc::find_nodes_by_name_recursively;
 use deno_doc::html::HrefResolver;
 use deno_doc::html::ShortPath;
+use deno_doc::DocMode;
 use deno_doc::DocNodeKind;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
 use deno_doc::DocPrinter;
+use deno_doc::TreePrinter;
 use deno_graph::source::LoadFuture;
 use deno_graph::source::LoadResponse;
 use deno_graph::source::Loader;
@@ -71,6 +73,13 @@ async fn run() -> anyhow::Result<()> {
         .conflicts_with("html"),
     )
     .arg(Arg::with_name("private").long("private"))
+    .arg(
+      Arg::with_name("format")
+        .long("format")
+        .takes_value(true)
+        .possible_values(&["text", "tree"])
+        .conflicts_with("html"),
+    )
     .get_matches();
   let source_files = matches.values_of("source_files").unwrap();
   let html = matches.is_present("html");
@@ -96,6 +105,7 @@ async fn run() -> anyhow::Result<()> {
   };
   let maybe_filter = matches.value_of("filter");
   let private = matches.is_present("private");
+  let tree = matches.value_of("format") == Some("tree");
   let source_files: Vec<ModuleSpecifier> = source_files
     .into_iter()
     .map(|source_file| {
@@ -126,6 +136,11 @@ async fn run() -> anyhow::Result<()> {
     DocParserOptions {
       diagnostics: false,
       private,
+      prefer_declaration_files: false,
+      include_source: false,
+      mode: DocMode::Full,
+      max_depth: Some(10),
+      isolated_declarations: false,
     },
   )?;
 
@@ -146,6 +161,19 @@ async fn run() -> anyhow::Result<()> {
     return Ok(());
   }
 
+  if tree {
+    for source_file in source_files {
+      let mut doc_nodes = parser.parse_with_reexports(&source_file)?;
+      if let Some(filter) = maybe_filter {
+        doc_nodes =
+          find_nodes_by_name_recursively(doc_nodes, filter.to_string());
+      }
+      let result = TreePrinter::new(source_file.as_str(), &doc_nodes, private);
+      print!("{}", result);
+    }
+    return Ok(());
+  }
+
   let mut doc_nodes = Vec::with_capacity(1024);
   for source_file in source_files {
     let nodes = parser.parse_with_reexports(&source_file)?;
@@ -223,6 +251,19 @@ fn generate_docs_directory(
     rewrite_map: Some(index_map),
     hide_module_doc_title: false,
     sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    syntax_theme: Default::default(),
+    og_base_url: None,
+    og_image: None,
+    sitemap_base_url: None,
+    base_path: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: true,
+    collapse_type_threshold: None,
   };
   let html = deno_doc::html::generate(options.clone(), doc_nodes_by_url)?;
 
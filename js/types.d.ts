@@ -16,14 +16,40 @@ export type DocNode =
  * module. `"declare"` indicates that it is a type only declaration. */
 export type DeclarationKind = "private" | "export" | "declare";
 
+/** Indicates whether the node is exported with its value, or only with its
+ * type, eg. through `export type * from "./mod.ts"`. */
+export type ExportKind = "value" | "type";
+
 interface DocNodeBase {
   kind: DocNodeKind;
   name: string;
   location: Location;
   declarationKind: DeclarationKind;
+  /** Omitted for value exports. */
+  exportKind?: ExportKind;
   jsDoc?: JsDoc;
+  /** Set by a `@protected`, `@private` or `@package` JSDoc tag. */
+  visibility?: Visibility;
+  /** Set by an `@experimental` JSDoc tag of the node, or of the namespace or
+   * module that declares it. */
+  stability?: Stability;
+  stabilitySource?: StabilitySource;
+  reExportSource?: string;
 }
 
+export type Visibility =
+  | "public"
+  | "protected"
+  | "private"
+  | "packagePrivate";
+
+export type Stability = "stable" | "experimental";
+
+export type StabilitySource =
+  | "direct"
+  | "inheritedFromNamespace"
+  | "inheritedFromModule";
+
 export type DocNodeKind =
   | "moduleDoc"
   | "function"
@@ -86,14 +112,17 @@ export interface ClassDef {
   defName?: string;
   isAbstract: boolean;
   constructors: ClassConstructorDef[];
+  constructorOverloads?: ClassConstructorDef[];
   properties: ClassPropertyDef[];
   indexSignatures: ClassIndexSignatureDef[];
   methods: ClassMethodDef[];
   extends?: string;
+  mixins?: TsTypeDef[];
   implements: TsTypeDef[];
   typeParams: TsTypeParamDef[];
   superTypeParams: TsTypeDef[];
   decorators?: DecoratorDef[];
+  bodySource?: string;
 }
 
 export type ClassConstructorParamDef = ParamDef & {
@@ -125,6 +154,8 @@ export interface ClassMethodDef {
   isAbstract: boolean;
   isStatic: boolean;
   isOverride?: boolean;
+  overrides?: string;
+  overriddenBy?: string[];
   name: string;
   kind: MethodKind;
   functionDef: FunctionDef;
@@ -140,6 +171,11 @@ export interface ClassPropertyDef {
   isAbstract: boolean;
   isStatic: boolean;
   isOverride?: boolean;
+  isDeclare?: boolean;
+  hasInitializer?: boolean;
+  initializerText?: string;
+  fromConstructor?: boolean;
+  overrides?: string;
   name: string;
   decorators?: DecoratorDef[];
   location: Location;
@@ -148,6 +184,7 @@ export interface ClassPropertyDef {
 export interface DecoratorDef {
   name: string;
   args?: string[];
+  isCall?: boolean;
   location: Location;
 }
 
@@ -165,8 +202,13 @@ export interface EnumMemberDef {
 export interface FunctionDef {
   defName?: string;
   params: ParamDef[];
+  thisType?: TsTypeDef;
   returnType?: TsTypeDef;
+  /** The description of the `@returns` JSDoc tag. */
+  returnTypeDoc?: string;
   hasBody?: boolean;
+  bodySpan?: BodySpan;
+  isOverload?: boolean;
   isAsync: boolean;
   isGenerator: boolean;
   typeParams: TsTypeParamDef[];
@@ -210,6 +252,17 @@ export interface InterfaceMethodDef {
   computed?: boolean;
   optional: boolean;
   params: ParamDef[];
+  thisType?: TsTypeDef;
+  returnType?: TsTypeDef;
+  typeParams: TsTypeParamDef[];
+  overloads?: FunctionSignatureDef[];
+}
+
+export interface FunctionSignatureDef {
+  location: Location;
+  jsDoc?: JsDoc;
+  params: ParamDef[];
+  thisType?: TsTypeDef;
   returnType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
 }
@@ -236,22 +289,33 @@ export type JsDocTagKind =
   | "category"
   | "constructor"
   | "default"
+  | "deployhandler"
   | "deprecated"
   | "enum"
   | "example"
+  | "experimental"
   | "extends"
+  | "fires"
   | "ignore"
+  | "inheritdoc"
   | "module"
+  | "override"
+  | "package"
+  | "packagedocumentation"
   | "param"
   | "public"
   | "private"
   | "property"
   | "protected"
+  | "proxy"
   | "readonly"
   | "return"
+  | "satisfies"
+  | "tag"
   | "tags"
   | "template"
   | "this"
+  | "throws"
   | "typedef"
   | "type"
   | "see"
@@ -262,11 +326,13 @@ export type JsDocTag =
   | JsDocTagDoc
   | JsDocTagDocRequired
   | JsDocTagNamed
+  | JsDocTagTemplate
   | JsDocTagValued
   | JsDocTagTyped
   | JsDocTagNamedTyped
   | JsDocTagParam
   | JsDocTagReturn
+  | JsDocTagThrows
   | JsDocTagTags
   | JsDocTagUnsupported;
 
@@ -277,8 +343,14 @@ export interface JsDocTagBase {
 export interface JsDocTagOnly extends JsDocTagBase {
   kind:
     | "constructor"
+    | "deployhandler"
+    | "experimental"
     | "ignore"
+    | "inheritdoc"
     | "module"
+    | "override"
+    | "package"
+    | "packagedocumentation"
     | "public"
     | "private"
     | "protected"
@@ -291,16 +363,23 @@ export interface JsDocTagDoc extends JsDocTagBase {
 }
 
 export interface JsDocTagDocRequired extends JsDocTagBase {
-  kind: "category" | "example" | "see";
+  kind: "category" | "example" | "see" | "tag";
   doc: string;
 }
 
 export interface JsDocTagNamed extends JsDocTagBase {
-  kind: "callback" | "template";
+  kind: "callback" | "fires";
   name: string;
   doc?: string;
 }
 
+export interface JsDocTagTemplate extends JsDocTagBase {
+  kind: "template";
+  name: string;
+  type?: string;
+  doc?: string;
+}
+
 export interface JsDocTagValued extends JsDocTagBase {
   kind: "default";
   value: string;
@@ -308,7 +387,7 @@ export interface JsDocTagValued extends JsDocTagBase {
 }
 
 export interface JsDocTagTyped extends JsDocTagBase {
-  kind: "enum" | "extends" | "this" | "type";
+  kind: "enum" | "extends" | "proxy" | "satisfies" | "this" | "type";
   type: string;
   doc?: string;
 }
@@ -335,6 +414,12 @@ export interface JsDocTagReturn extends JsDocTagBase {
   doc?: string;
 }
 
+export interface JsDocTagThrows extends JsDocTagBase {
+  kind: "throws";
+  type?: string;
+  doc?: string;
+}
+
 export interface JsDocTagTags extends JsDocTagBase {
   kind: "tags";
   tags: string[];
@@ -420,6 +505,12 @@ export interface LiteralPropertyDef {
   typeParams: TsTypeParamDef[];
 }
 
+/** The byte range of the body of a function in the source text. */
+export interface BodySpan {
+  start: number;
+  end: number;
+}
+
 export interface Location {
   filename: string;
   line: number;
@@ -430,6 +521,9 @@ export type MethodKind = "method" | "getter" | "setter";
 
 export interface NamespaceDef {
   elements: DocNode[];
+  isWildcardModule?: true;
+  isGlobalAugmentation?: true;
+  parent?: string;
 }
 
 export type ObjectPatPropDef =
@@ -475,6 +569,7 @@ export interface ParamAssignDef {
   right: string;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  defaultValueText?: string;
 }
 
 export interface ParamIdentifierDef {
@@ -513,6 +608,7 @@ export interface TsFnOrConstructorDef {
   constructor: boolean;
   tsType: TsTypeDef;
   params: ParamDef[];
+  thisType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
 }
 
@@ -554,8 +650,12 @@ export interface TsTypeOperatorDef {
 
 export interface TsTypeParamDef {
   name: string;
+  isConst?: boolean;
+  isIn?: boolean;
+  isOut?: boolean;
   constraint?: TsTypeDef;
   default?: TsTypeDef;
+  description?: string;
 }
 
 export interface TsTypePredicateDef {
@@ -732,7 +832,14 @@ export interface TypeAliasDef {
 
 export type VariableDeclKind = "var" | "let" | "const";
 
+export type UsingDeclKind = "using" | "awaitUsing";
+
 export interface VariableDef {
   tsType?: TsTypeDef;
   kind: VariableDeclKind;
+  /** Set for `using` and `await using` declarations, in which case `kind` is
+   * `"const"`. */
+  using?: UsingDeclKind;
+  /** Set for default exports that are Deno Deploy handlers. */
+  deployHandler?: boolean;
 }
@@ -238,6 +238,11 @@ async fn inner_doc(
     deno_doc::DocParserOptions {
       diagnostics: false,
       private: include_all,
+      prefer_declaration_files: false,
+      include_source: false,
+      mode: deno_doc::DocMode::Full,
+      max_depth: Some(10),
+      isolated_declarations: false,
     },
   )?
   .parse_with_reexports(&root_specifier)?;
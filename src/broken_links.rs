@@ -0,0 +1,351 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type_param::TsTypeParamDef;
+
+pub use crate::ts_type::DEFAULT_KNOWN_TYPES;
+
+use std::collections::HashSet;
+
+/// A reference to a type that is neither documented nor known, which can't
+/// be linked to in the HTML output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+  /// The name of the symbol containing the reference, qualified by the
+  /// namespaces it is declared in, eg. `Foo.bar`.
+  pub node_name: String,
+  /// Where the reference is in the symbol, eg. `params.input`,
+  /// `returnType` or `properties.value`.
+  pub field: String,
+  /// The name of the referenced type.
+  pub referenced_type: String,
+}
+
+/// Finds the type references of the nodes that are broken, assuming the
+/// [`DEFAULT_KNOWN_TYPES`] are known.
+pub fn broken_links_report(nodes: &[DocNode]) -> Vec<BrokenLink> {
+  let known_types = DEFAULT_KNOWN_TYPES
+    .iter()
+    .map(|name| name.to_string())
+    .collect();
+  broken_links_report_with_known_types(nodes, &known_types)
+}
+
+/// Finds the type references of the nodes that are broken, ie. that don't
+/// refer to a type parameter in scope, to one of the nodes or to one of the
+/// known types.
+pub fn broken_links_report_with_known_types(
+  nodes: &[DocNode],
+  known_types: &HashSet<String>,
+) -> Vec<BrokenLink> {
+  let mut checker = Checker {
+    known_types,
+    documented: HashSet::new(),
+    broken_links: vec![],
+  };
+  checker.collect_documented(None, nodes);
+  for node in nodes {
+    checker.check_node(None, node);
+  }
+  checker.broken_links
+}
+
+struct Checker<'a> {
+  known_types: &'a HashSet<String>,
+  documented: HashSet<String>,
+  broken_links: Vec<BrokenLink>,
+}
+
+impl<'a> Checker<'a> {
+  fn collect_documented(&mut self, namespace: Option<&str>, nodes: &[DocNode]) {
+    for node in nodes {
+      let name = qualified_name(namespace, &node.name);
+      if let Some(namespace_def) = &node.namespace_def {
+        self.collect_documented(Some(&name), &namespace_def.elements);
+      }
+      self.documented.insert(name);
+    }
+  }
+
+  fn is_known(&self, type_name: &str, type_params: &HashSet<String>) -> bool {
+    let root = type_name.split('.').next().unwrap();
+    root == "Deno"
+      || type_params.contains(type_name)
+      || self.documented.contains(type_name)
+      || self.documented.contains(root)
+      || self.known_types.contains(type_name)
+      || self.known_types.contains(root)
+  }
+
+  fn check_node(&mut self, namespace: Option<&str>, node: &DocNode) {
+    let node_name = qualified_name(namespace, &node.name);
+    match node.kind {
+      DocNodeKind::Class => {
+        let class_def = node.class_def.as_ref().unwrap();
+        let scope = type_param_names(&HashSet::new(), &class_def.type_params);
+        self.check_type_params(&node_name, "", &scope, &class_def.type_params);
+        if let Some(extends) = &class_def.extends {
+          if !self.is_known(extends, &scope) {
+            self.broken_links.push(BrokenLink {
+              node_name: node_name.clone(),
+              field: "extends".to_string(),
+              referenced_type: extends.clone(),
+            });
+          }
+        }
+        for ts_type in &class_def.super_type_params {
+          self.check_type(&node_name, "extends", &scope, ts_type);
+        }
+        for ts_type in &class_def.implements {
+          self.check_type(&node_name, "implements", &scope, ts_type);
+        }
+        for constructor in class_def
+          .constructor_overloads
+          .iter()
+          .chain(&class_def.constructors)
+        {
+          let params = constructor
+            .params
+            .iter()
+            .map(|param| &param.param)
+            .collect::<Vec<_>>();
+          self.check_params(&node_name, "constructor.", &scope, &params);
+        }
+        for property in &class_def.properties {
+          if let Some(ts_type) = &property.ts_type {
+            let field = format!("properties.{}", property.name);
+            self.check_type(&node_name, &field, &scope, ts_type);
+          }
+        }
+        for index_signature in &class_def.index_signatures {
+          let params = index_signature.params.iter().collect::<Vec<_>>();
+          self.check_params(&node_name, "indexSignatures.", &scope, &params);
+          if let Some(ts_type) = &index_signature.ts_type {
+            self.check_type(&node_name, "indexSignatures", &scope, ts_type);
+          }
+        }
+        for method in &class_def.methods {
+          let function_def = &method.function_def;
+          let scope = type_param_names(&scope, &function_def.type_params);
+          let field = format!("methods.{}", method.name);
+          self.check_signature(
+            &node_name,
+            &field,
+            &scope,
+            &function_def.type_params,
+            &function_def.signature_params().iter().collect::<Vec<_>>(),
+            function_def.return_type.as_ref(),
+          );
+        }
+      }
+      DocNodeKind::Function => {
+        let function_def = node.function_def.as_ref().unwrap();
+        let scope =
+          type_param_names(&HashSet::new(), &function_def.type_params);
+        self.check_signature(
+          &node_name,
+          "",
+          &scope,
+          &function_def.type_params,
+          &function_def.signature_params().iter().collect::<Vec<_>>(),
+          function_def.return_type.as_ref(),
+        );
+      }
+      DocNodeKind::Interface => {
+        let interface_def = node.interface_def.as_ref().unwrap();
+        let scope =
+          type_param_names(&HashSet::new(), &interface_def.type_params);
+        self.check_type_params(
+          &node_name,
+          "",
+          &scope,
+          &interface_def.type_params,
+        );
+        for ts_type in &interface_def.extends {
+          self.check_type(&node_name, "extends", &scope, ts_type);
+        }
+        for property in &interface_def.properties {
+          let scope = type_param_names(&scope, &property.type_params);
+          let field = format!("properties.{}", property.name);
+          self.check_signature(
+            &node_name,
+            &field,
+            &scope,
+            &property.type_params,
+            &property.params.iter().collect::<Vec<_>>(),
+            property.ts_type.as_ref(),
+          );
+        }
+        for method in interface_def.methods.iter().flat_map(|m| m.signatures())
+        {
+          let scope = type_param_names(&scope, &method.type_params);
+          let field = format!("methods.{}", method.name);
+          self.check_signature(
+            &node_name,
+            &field,
+            &scope,
+            &method.type_params,
+            &method.signature_params().iter().collect::<Vec<_>>(),
+            method.return_type.as_ref(),
+          );
+        }
+        for call_signature in &interface_def.call_signatures {
+          let scope = type_param_names(&scope, &call_signature.type_params);
+          self.check_signature(
+            &node_name,
+            "callSignatures",
+            &scope,
+            &call_signature.type_params,
+            &call_signature.params.iter().collect::<Vec<_>>(),
+            call_signature.ts_type.as_ref(),
+          );
+        }
+        for index_signature in &interface_def.index_signatures {
+          let params = index_signature.params.iter().collect::<Vec<_>>();
+          self.check_params(&node_name, "indexSignatures.", &scope, &params);
+          if let Some(ts_type) = &index_signature.ts_type {
+            self.check_type(&node_name, "indexSignatures", &scope, ts_type);
+          }
+        }
+      }
+      DocNodeKind::TypeAlias => {
+        let type_alias_def = node.type_alias_def.as_ref().unwrap();
+        let scope =
+          type_param_names(&HashSet::new(), &type_alias_def.type_params);
+        self.check_type_params(
+          &node_name,
+          "",
+          &scope,
+          &type_alias_def.type_params,
+        );
+        self.check_type(&node_name, "type", &scope, &type_alias_def.ts_type);
+      }
+      DocNodeKind::Variable => {
+        let variable_def = node.variable_def.as_ref().unwrap();
+        if let Some(ts_type) = &variable_def.ts_type {
+          self.check_type(&node_name, "type", &HashSet::new(), ts_type);
+        }
+      }
+      DocNodeKind::Enum
+      | DocNodeKind::Import
+      | DocNodeKind::ModuleDoc
+      | DocNodeKind::Namespace => {}
+    }
+    // namespaces, including the ones merged with an enum or an interface
+    if let Some(namespace_def) = &node.namespace_def {
+      for element in &namespace_def.elements {
+        self.check_node(Some(&node_name), element);
+      }
+    }
+  }
+
+  /// Checks the type parameters, parameters and return type of a function
+  /// like signature, with the fields prefixed by the given one.
+  fn check_signature(
+    &mut self,
+    node_name: &str,
+    field: &str,
+    scope: &HashSet<String>,
+    type_params: &[TsTypeParamDef],
+    params: &[&ParamDef],
+    return_type: Option<&TsTypeDef>,
+  ) {
+    let prefix = if field.is_empty() {
+      String::new()
+    } else {
+      format!("{field}.")
+    };
+    self.check_type_params(node_name, &prefix, scope, type_params);
+    self.check_params(node_name, &prefix, scope, params);
+    if let Some(return_type) = return_type {
+      let field = if field.is_empty() {
+        "returnType"
+      } else {
+        field
+      };
+      self.check_type(node_name, field, scope, return_type);
+    }
+  }
+
+  fn check_type_params(
+    &mut self,
+    node_name: &str,
+    prefix: &str,
+    scope: &HashSet<String>,
+    type_params: &[TsTypeParamDef],
+  ) {
+    for type_param in type_params {
+      let field = format!("{prefix}typeParams.{}", type_param.name);
+      for ts_type in type_param.constraint.iter().chain(&type_param.default) {
+        self.check_type(node_name, &field, scope, ts_type);
+      }
+    }
+  }
+
+  fn check_params(
+    &mut self,
+    node_name: &str,
+    prefix: &str,
+    scope: &HashSet<String>,
+    params: &[&ParamDef],
+  ) {
+    for (i, param) in params.iter().enumerate() {
+      if let Some(ts_type) = &param.ts_type {
+        let field = format!("{prefix}params.{}", param_name(i, param));
+        self.check_type(node_name, &field, scope, ts_type);
+      }
+    }
+  }
+
+  fn check_type(
+    &mut self,
+    node_name: &str,
+    field: &str,
+    scope: &HashSet<String>,
+    ts_type: &TsTypeDef,
+  ) {
+    let mut scope = scope.clone();
+    scope.extend(ts_type.inner_type_param_names());
+    for referenced_type in ts_type.referenced_type_names() {
+      if !self.is_known(&referenced_type, &scope) {
+        self.broken_links.push(BrokenLink {
+          node_name: node_name.to_string(),
+          field: field.to_string(),
+          referenced_type,
+        });
+      }
+    }
+  }
+}
+
+pub(crate) fn qualified_name(namespace: Option<&str>, name: &str) -> String {
+  match namespace {
+    Some(namespace) => format!("{namespace}.{name}"),
+    None => name.to_string(),
+  }
+}
+
+fn type_param_names(
+  outer: &HashSet<String>,
+  type_params: &[TsTypeParamDef],
+) -> HashSet<String> {
+  let mut names = outer.clone();
+  names.extend(type_params.iter().map(|param| param.name.clone()));
+  names
+}
+
+/// The name of a parameter, or its position if it is destructured.
+fn param_name(index: usize, param: &ParamDef) -> String {
+  match &param.pattern {
+    ParamPatternDef::Identifier { name, .. } => name.clone(),
+    ParamPatternDef::Rest { arg } => param_name(index, arg),
+    ParamPatternDef::Assign { left, .. } => param_name(index, left),
+    ParamPatternDef::Array { .. } | ParamPatternDef::Object { .. } => {
+      index.to_string()
+    }
+  }
+}
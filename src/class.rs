@@ -1,5 +1,6 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use deno_ast::swc::ast::Accessibility;
 use deno_ast::ParsedSource;
 use deno_ast::SourceRangedForSpanned;
 use serde::Deserialize;
@@ -9,16 +10,22 @@ use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::function::function_to_function_def;
 use crate::function::FunctionDef;
+use crate::interface::InterfaceDef;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
 use crate::node::DeclarationKind;
 use crate::params::assign_pat_to_param_def;
 use crate::params::ident_to_param_def;
 use crate::params::param_to_param_def;
 use crate::params::prop_name_to_string;
 use crate::params::ts_fn_param_to_param_def;
+use crate::params::ParamPatternDef;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::maybe_type_param_instantiation_to_type_defs;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeDefOptions;
+use crate::ts_type::TsTypeRefDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::util::swc::get_location;
@@ -35,6 +42,7 @@ cfg_if! {
     use crate::display::display_abstract;
     use crate::display::display_accessibility;
     use crate::display::display_async;
+    use crate::display::display_declare;
     use crate::display::display_generator;
     use crate::display::display_method;
     use crate::display::display_optional;
@@ -119,6 +127,26 @@ pub struct ClassPropertyDef {
   pub is_static: bool,
   #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
+  /// Whether the property is declared with the `declare` modifier, which
+  /// only informs the type system and emits no JavaScript.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_declare: bool,
+  /// Whether the property has a default value, ie. an initializer or, for
+  /// parameter properties, a default parameter value.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub has_initializer: bool,
+  /// The source text of the initializer when it is a literal, eg. `"hello"`,
+  /// `42`, `true` or `null`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub initializer_text: Option<String>,
+  /// Whether the property is declared by a parameter property of the
+  /// constructor, eg. `constructor(public x: number)`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub from_constructor: bool,
+  /// The fully qualified name of the property or accessor of a parent class
+  /// which this property overrides, eg. `Base.name`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub overrides: Option<String>,
   pub name: String,
   pub location: Location,
 }
@@ -133,6 +161,8 @@ impl From<ClassPropertyDef> for DocNode {
       VariableDef {
         ts_type: def.ts_type,
         kind: deno_ast::swc::ast::VarDeclKind::Const,
+        using: None,
+        deploy_handler: false,
       },
     )
   }
@@ -143,10 +173,11 @@ impl Display for ClassPropertyDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(
       f,
-      "{}{}{}{}{}{}{}",
+      "{}{}{}{}{}{}{}{}",
       display_abstract(self.is_abstract),
       display_override(self.is_override),
       display_accessibility(self.accessibility, false),
+      display_declare(self.is_declare),
       display_static(self.is_static),
       display_readonly(self.readonly),
       colors::bold(&self.name),
@@ -197,6 +228,15 @@ pub struct ClassMethodDef {
   pub is_static: bool,
   #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
+  /// The fully qualified name of the method, accessor or property of a
+  /// parent class which this method overrides, eg. `Base.method` or
+  /// `ns.Base.method` for a class declared in a namespace.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub overrides: Option<String>,
+  /// The names of the subclasses whose methods override this one, among the
+  /// classes documented alongside it, see [`compute_overrides`].
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub overridden_by: Vec<String>,
   pub name: String,
   pub kind: deno_ast::swc::ast::MethodKind,
   pub function_def: FunctionDef,
@@ -230,7 +270,7 @@ impl Display for ClassMethodDef {
       display_generator(self.function_def.is_generator),
       colors::bold(&self.name),
       display_optional(self.optional),
-      SliceDisplayer::new(&self.function_def.params, ", ", false),
+      SliceDisplayer::new(&self.function_def.signature_params(), ", ", false),
     )?;
     if let Some(return_type) = &self.function_def.return_type {
       write!(f, ": {}", return_type)?;
@@ -246,20 +286,478 @@ pub struct ClassDef {
   /// set when the class is a default export and has a name in its declaration
   pub def_name: Option<String>,
   pub is_abstract: bool,
+  /// The implementation of the constructor, or the constructor signatures
+  /// of an ambient class, which has no implementation.
   pub constructors: Vec<ClassConstructorDef>,
+  /// The overload signatures of the constructor, in their order of
+  /// declaration, when the class also declares its implementation.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub constructor_overloads: Vec<ClassConstructorDef>,
   pub properties: Vec<ClassPropertyDef>,
   pub index_signatures: Vec<ClassIndexSignatureDef>,
   pub methods: Vec<ClassMethodDef>,
   pub extends: Option<String>,
+  /// The mixins applied to the class that is extended, outermost first, eg.
+  /// `Mixin1` and `Mixin2` for `extends Mixin1(Mixin2(Base))`, in which
+  /// case `extends` is `Base`.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub mixins: Vec<TsTypeDef>,
   pub implements: Vec<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
   pub super_type_params: Vec<TsTypeDef>,
   #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub decorators: Vec<DecoratorDef>,
+  /// The source text of the class, only set when
+  /// [`DocParserOptions::include_source`](crate::DocParserOptions::include_source)
+  /// is enabled.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub body_source: Option<String>,
+}
+
+/// Counts of the members declared by the class itself, inherited members are
+/// not included. Each overload and accessor counts as a method.
+impl ClassDef {
+  /// The number of methods, static or not.
+  pub fn method_count(&self) -> usize {
+    self.methods.len()
+  }
+
+  /// The number of properties, static or not, including the ones declared
+  /// by constructor parameters.
+  pub fn property_count(&self) -> usize {
+    self.properties.len()
+  }
+
+  /// The number of static methods.
+  pub fn static_method_count(&self) -> usize {
+    self
+      .methods
+      .iter()
+      .filter(|method| method.is_static)
+      .count()
+  }
+
+  /// The number of constructors, including overloads, properties, index
+  /// signatures and methods.
+  pub fn total_member_count(&self) -> usize {
+    self.constructors.len()
+      + self.constructor_overloads.len()
+      + self.properties.len()
+      + self.index_signatures.len()
+      + self.methods.len()
+  }
+
+  /// The number of members declared `public` or without an accessibility
+  /// modifier, index signatures included, which are what consumers of the
+  /// class use.
+  pub fn public_count(&self) -> usize {
+    self
+      .member_accessibilities()
+      .filter(|accessibility| {
+        matches!(accessibility, None | Some(Accessibility::Public))
+      })
+      .count()
+  }
+
+  /// The number of `protected` members, which only subclasses use.
+  pub fn protected_count(&self) -> usize {
+    self
+      .member_accessibilities()
+      .filter(|accessibility| *accessibility == Some(Accessibility::Protected))
+      .count()
+  }
+
+  /// The number of `private` members, which are not part of the API of the
+  /// class.
+  pub fn private_count(&self) -> usize {
+    self
+      .member_accessibilities()
+      .filter(|accessibility| *accessibility == Some(Accessibility::Private))
+      .count()
+  }
+
+  fn member_accessibilities(
+    &self,
+  ) -> impl Iterator<Item = Option<Accessibility>> + '_ {
+    let constructors = self.constructors.iter().map(|c| c.accessibility);
+    let overloads = self.constructor_overloads.iter().map(|c| c.accessibility);
+    let properties = self.properties.iter().map(|p| p.accessibility);
+    let index_signatures = self.index_signatures.iter().map(|_| None);
+    let methods = self.methods.iter().map(|m| m.accessibility);
+    constructors
+      .chain(overloads)
+      .chain(properties)
+      .chain(index_signatures)
+      .chain(methods)
+  }
+}
+
+/// A required member of an interface in the `implements` clause of a class
+/// which the class does not declare, see [`ClassDef::interface_impls_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnimplementedMember<'a> {
+  /// The name of the interface as written in the `implements` clause.
+  pub interface_name: String,
+  pub name: &'a str,
+  pub kind: UnimplementedMemberKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnimplementedMemberKind {
+  Property,
+  Method,
+}
+
+/// Whether a class declares all the required members of the interfaces in
+/// its `implements` clause, see [`ClassDef::check_implements`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImplementsCheckResult {
+  pub fully_implemented: bool,
+  /// The names of the required members the class doesn't declare, in the
+  /// order of the interfaces, without duplicates.
+  pub missing_members: Vec<String>,
+}
+
+impl ClassDef {
+  /// Summarizes [`ClassDef::interface_impls_check`], where `interfaces` are
+  /// the definitions of the interfaces of the `implements` clause, in the
+  /// same order.
+  pub fn check_implements(
+    &self,
+    interfaces: &[&InterfaceDef],
+  ) -> ImplementsCheckResult {
+    let mut missing_members: Vec<String> = vec![];
+    for member in self.unimplemented_members(interfaces.iter().copied()) {
+      if !missing_members.iter().any(|name| name == member.name) {
+        missing_members.push(member.name.to_string());
+      }
+    }
+    ImplementsCheckResult {
+      fully_implemented: missing_members.is_empty(),
+      missing_members,
+    }
+  }
+
+  /// The required members of the implemented interfaces that the class does
+  /// not declare, where `interfaces` are the definitions of the interfaces
+  /// of the `implements` clause, in the same order.
+  ///
+  /// This is a check of the documentation, not of the types: a member is
+  /// implemented by any instance property, method or accessor of the same
+  /// name, and the members inherited from a parent class, or from the
+  /// interfaces extended by an interface, are not considered.
+  pub fn interface_impls_check<'a>(
+    &self,
+    interfaces: &'a [InterfaceDef],
+  ) -> Vec<UnimplementedMember<'a>> {
+    self.unimplemented_members(interfaces)
+  }
+
+  fn unimplemented_members<'a>(
+    &self,
+    interfaces: impl IntoIterator<Item = &'a InterfaceDef>,
+  ) -> Vec<UnimplementedMember<'a>> {
+    let is_declared = |name: &str| {
+      self
+        .properties
+        .iter()
+        .any(|property| !property.is_static && property.name == name)
+        || self
+          .methods
+          .iter()
+          .any(|method| !method.is_static && method.name == name)
+    };
+
+    let mut unimplemented = vec![];
+    for (implements, interface_def) in self.implements.iter().zip(interfaces) {
+      let interface_name = implements
+        .type_ref
+        .as_ref()
+        .map_or(&implements.repr, |type_ref| &type_ref.type_name);
+      let properties = interface_def
+        .properties
+        .iter()
+        .filter(|property| !property.optional)
+        .map(|property| (&property.name, UnimplementedMemberKind::Property));
+      let methods = interface_def
+        .methods
+        .iter()
+        .filter(|method| !method.optional)
+        .map(|method| (&method.name, UnimplementedMemberKind::Method));
+      for (name, kind) in properties.chain(methods) {
+        let is_reported =
+          unimplemented.iter().any(|member: &UnimplementedMember| {
+            member.interface_name == *interface_name && member.name == name
+          });
+        if !is_declared(name) && !is_reported {
+          unimplemented.push(UnimplementedMember {
+            interface_name: interface_name.clone(),
+            name,
+            kind,
+          });
+        }
+      }
+    }
+    unimplemented
+  }
+}
+
+/// Sets `overrides` on the methods, accessors and properties of `class_def`
+/// which are also declared on `parent_class`, unless they were already found
+/// to override a member of a closer ancestor. `parent_name` is the qualified
+/// name of the parent class in the module that declares it.
+pub fn resolve_overridden_members(
+  class_def: &mut ClassDef,
+  parent_name: &str,
+  parent_source: &ParsedSource,
+  parent_class: &deno_ast::swc::ast::Class,
+) {
+  let parent_members = parent_overridable_members(parent_source, parent_class);
+  let overrides = |name: &str, is_static: bool| {
+    parent_members
+      .iter()
+      .any(|member| member.0 == name && member.1 == is_static)
+      .then(|| format!("{parent_name}.{name}"))
+  };
+
+  for method in &mut class_def.methods {
+    if method.overrides.is_none() {
+      method.overrides = overrides(&method.name, method.is_static);
+    }
+  }
+  for property in &mut class_def.properties {
+    if property.overrides.is_none() {
+      property.overrides = overrides(&property.name, property.is_static);
+    }
+  }
+}
+
+/// The names of the members of a class which can be overridden by a
+/// subclass, along with whether they are static: its non private methods,
+/// accessors and properties, including the ones declared by the parameter
+/// properties of its constructor.
+fn parent_overridable_members(
+  parsed_source: &ParsedSource,
+  class: &deno_ast::swc::ast::Class,
+) -> Vec<(String, bool)> {
+  use deno_ast::swc::ast::ClassMember;
+  use deno_ast::swc::ast::ParamOrTsParamProp;
+  use deno_ast::swc::ast::TsParamPropParam;
+
+  let is_visible = |accessibility: Option<Accessibility>| {
+    accessibility != Some(Accessibility::Private)
+  };
+  let mut members = vec![];
+  for member in &class.body {
+    match member {
+      ClassMember::Method(method) if is_visible(method.accessibility) => {
+        members.push((
+          prop_name_to_string(parsed_source, &method.key),
+          method.is_static,
+        ));
+      }
+      ClassMember::ClassProp(prop) if is_visible(prop.accessibility) => {
+        members.push((
+          prop_name_to_string(parsed_source, &prop.key),
+          prop.is_static,
+        ));
+      }
+      ClassMember::Constructor(ctor) => {
+        for param in &ctor.params {
+          let ParamOrTsParamProp::TsParamProp(param_prop) = param else {
+            continue;
+          };
+          if !is_visible(param_prop.accessibility) {
+            continue;
+          }
+          let ident = match &param_prop.param {
+            TsParamPropParam::Ident(ident) => ident,
+            TsParamPropParam::Assign(assign_pat) => {
+              match assign_pat.left.as_ident() {
+                Some(ident) => ident,
+                None => continue,
+              }
+            }
+          };
+          members.push((ident.id.sym.to_string(), false));
+        }
+      }
+      _ => {}
+    }
+  }
+  members
+}
+
+/// Sets `overridden_by` on the methods of the classes among `doc_nodes` to
+/// the names of the subclasses among them whose methods override those, in
+/// their order of declaration. The overridden methods are found by the
+/// fully qualified name in their `overrides`, which is the one of the parent
+/// class in the module that declares it, whatever the name it was imported
+/// by, so the nodes of several modules can be passed together to find the
+/// overrides across them. The names of the subclasses are qualified by the
+/// namespaces they are declared in, eg. `ns.Subclass`.
+pub fn compute_overrides(doc_nodes: &mut [DocNode]) {
+  let mut overrides = vec![];
+  collect_overrides(None, doc_nodes, &mut overrides);
+
+  for (parent, name, is_static, subclass) in overrides {
+    let path = parent.split('.').collect::<Vec<_>>();
+    let mut classes = vec![];
+    find_classes(doc_nodes, &path, &mut classes);
+    let methods = classes
+      .into_iter()
+      .flat_map(|class_def| class_def.methods.iter_mut())
+      .filter(|method| method.name == name && method.is_static == is_static);
+    for method in methods {
+      if !method.overridden_by.contains(&subclass) {
+        method.overridden_by.push(subclass.clone());
+      }
+    }
+  }
+}
+
+/// Collects the qualified names of the parent classes and of the methods
+/// overridden by the methods of the classes, along with whether they are
+/// static and the qualified names of the subclasses.
+fn collect_overrides(
+  namespace: Option<&str>,
+  doc_nodes: &[DocNode],
+  overrides: &mut Vec<(String, String, bool, String)>,
+) {
+  for doc_node in doc_nodes {
+    let name = match namespace {
+      Some(namespace) => format!("{namespace}.{}", doc_node.name),
+      None => doc_node.name.clone(),
+    };
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_overrides(Some(&name), &namespace_def.elements, overrides);
+    }
+    let Some(class_def) = &doc_node.class_def else {
+      continue;
+    };
+    for method in &class_def.methods {
+      if let Some((parent, method_name)) =
+        method.overrides.as_ref().and_then(|o| o.rsplit_once('.'))
+      {
+        overrides.push((
+          parent.to_string(),
+          method_name.to_string(),
+          method.is_static,
+          name.clone(),
+        ));
+      }
+    }
+  }
+}
+
+/// The classes among `doc_nodes` at the qualified name split into `path`,
+/// eg. `["ns", "Base"]` for the class `Base` of the namespace `ns`.
+fn find_classes<'a>(
+  doc_nodes: &'a mut [DocNode],
+  path: &[&str],
+  classes: &mut Vec<&'a mut ClassDef>,
+) {
+  let Some((name, rest)) = path.split_first() else {
+    return;
+  };
+  for doc_node in doc_nodes.iter_mut().filter(|node| node.name == *name) {
+    if rest.is_empty() {
+      classes.extend(doc_node.class_def.as_mut());
+    } else if let Some(namespace_def) = &mut doc_node.namespace_def {
+      find_classes(&mut namespace_def.elements, rest, classes);
+    }
+  }
+}
+
+/// Documents a constructor parameter property as a property of the class,
+/// falling back to the constructor's `@param` tag for its description.
+fn param_prop_to_class_property_def(
+  parsed_source: &ParsedSource,
+  ctor_js_doc: &JsDoc,
+  ts_param_prop: &deno_ast::swc::ast::TsParamProp,
+  param: &ParamDef,
+) -> Option<ClassPropertyDef> {
+  let mut js_doc = js_doc_for_range(parsed_source, &ts_param_prop.range())?;
+  let (name, optional, ts_type) = match &param.pattern {
+    ParamPatternDef::Identifier { name, optional, .. } => {
+      (name, *optional, param.ts_type.clone())
+    }
+    ParamPatternDef::Assign { left, .. } => match &left.pattern {
+      ParamPatternDef::Identifier { name, optional, .. } => (
+        name,
+        *optional,
+        param.ts_type.clone().or_else(|| left.ts_type.clone()),
+      ),
+      _ => return None,
+    },
+    _ => return None,
+  };
+
+  if js_doc.doc.is_none() {
+    js_doc.doc = ctor_js_doc.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Param {
+        name: param_name,
+        doc,
+        ..
+      } if param_name == name => doc.clone(),
+      _ => None,
+    });
+  }
+
+  let initializer = match &ts_param_prop.param {
+    deno_ast::swc::ast::TsParamPropParam::Assign(assign_pat) => {
+      Some(&assign_pat.right)
+    }
+    deno_ast::swc::ast::TsParamPropParam::Ident(_) => None,
+  };
+
+  Some(ClassPropertyDef {
+    js_doc,
+    ts_type,
+    has_initializer: initializer.is_some(),
+    initializer_text: initializer
+      .and_then(|expr| literal_initializer_text(parsed_source, expr)),
+    readonly: ts_param_prop.readonly,
+    accessibility: ts_param_prop.accessibility,
+    decorators: decorators_to_defs(parsed_source, &ts_param_prop.decorators),
+    optional,
+    is_abstract: false,
+    is_static: false,
+    is_override: ts_param_prop.is_override,
+    is_declare: false,
+    from_constructor: true,
+    overrides: None,
+    name: name.clone(),
+    location: get_location(parsed_source, ts_param_prop.start()),
+  })
+}
+
+/// The source text of initializers that are literals, eg. `"hello"`, `42`,
+/// `-1`, `true` or `null`, as opposed to arbitrary expressions.
+fn literal_initializer_text(
+  parsed_source: &ParsedSource,
+  expr: &deno_ast::swc::ast::Expr,
+) -> Option<String> {
+  use deno_ast::swc::ast::Expr;
+  use deno_ast::swc::ast::Lit;
+  use deno_ast::swc::ast::UnaryOp;
+
+  let is_literal = match expr {
+    Expr::Lit(
+      Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_) | Lit::BigInt(_),
+    ) => true,
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Minus
+        && matches!(&*unary.arg, Expr::Lit(Lit::Num(_) | Lit::BigInt(_)))
+    }
+    _ => false,
+  };
+  is_literal.then(|| expr.text_fast(parsed_source.text_info()).to_string())
 }
 
 pub fn class_to_class_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   class: &deno_ast::swc::ast::Class,
   def_name: Option<String>,
 ) -> (ClassDef, JsDoc) {
@@ -286,18 +784,51 @@ pub fn class_to_class_def(
     }
   }
 
-  let extends: Option<String> = match &class.super_class {
-    Some(boxed) => {
-      let expr: &Expr = boxed;
-      walk_class_extends(expr)
+  // `Mixin1(Mixin2(Base))`, where each mixin is a function which returns a
+  // class extending the given one
+  let mut mixins = vec![];
+  let mut super_class = class.super_class.as_deref();
+  while let Some(Expr::Call(call_expr)) = super_class {
+    let (Some(callee), [arg]) = (
+      call_expr
+        .callee
+        .as_expr()
+        .and_then(|callee| walk_class_extends(callee)),
+      call_expr.args.as_slice(),
+    ) else {
+      break;
+    };
+    if arg.spread.is_some() {
+      break;
     }
-    None => None,
-  };
+    let type_params = call_expr.type_args.as_ref().map(|type_args| {
+      type_args
+        .params
+        .iter()
+        .map(|ts_type| TsTypeDef::new(parsed_source, options, ts_type))
+        .collect()
+    });
+    mixins.push(TsTypeDef {
+      repr: callee.clone(),
+      kind: Some(TsTypeDefKind::TypeRef),
+      type_ref: Some(TsTypeRefDef {
+        type_params,
+        type_name: callee,
+      }),
+      ..Default::default()
+    });
+    super_class = Some(&arg.expr);
+  }
+
+  let extends = super_class.and_then(walk_class_extends);
+  if extends.is_none() {
+    mixins.clear();
+  }
 
   let implements = class
     .implements
     .iter()
-    .map(|expr| TsTypeDef::ts_expr_with_type_args(parsed_source, expr))
+    .map(|expr| TsTypeDef::ts_expr_with_type_args(parsed_source, options, expr))
     .collect::<Vec<_>>();
 
   for member in &class.body {
@@ -319,7 +850,7 @@ pub fn class_to_class_def(
               Param(param) => ClassConstructorParamDef {
                 accessibility: None,
                 is_override: false,
-                param: param_to_param_def(parsed_source, param),
+                param: param_to_param_def(parsed_source, options, param),
                 readonly: false,
               },
               TsParamProp(ts_param_prop) => {
@@ -327,13 +858,23 @@ pub fn class_to_class_def(
 
                 let param = match &ts_param_prop.param {
                   TsParamPropParam::Ident(ident) => {
-                    ident_to_param_def(parsed_source, ident)
+                    ident_to_param_def(parsed_source, options, ident)
                   }
                   TsParamPropParam::Assign(assign_pat) => {
-                    assign_pat_to_param_def(parsed_source, assign_pat)
+                    assign_pat_to_param_def(parsed_source, options, assign_pat)
                   }
                 };
 
+                // parameter properties also declare a property on the class
+                if let Some(prop_def) = param_prop_to_class_property_def(
+                  parsed_source,
+                  &ctor_js_doc,
+                  ts_param_prop,
+                  &param,
+                ) {
+                  properties.push(prop_def);
+                }
+
                 ClassConstructorParamDef {
                   accessibility: ts_param_prop.accessibility,
                   is_override: ts_param_prop.is_override,
@@ -363,18 +904,27 @@ pub fn class_to_class_def(
         {
           let method_name =
             prop_name_to_string(parsed_source, &class_method.key);
-          let fn_def = function_to_function_def(
+          let mut fn_def = function_to_function_def(
             parsed_source,
+            options,
             &class_method.function,
             None,
           );
+          fn_def.apply_returns_tag(&method_js_doc);
+          let is_override = class_method.is_override
+            || method_js_doc
+              .tags
+              .iter()
+              .any(|tag| matches!(tag, JsDocTag::Override));
           let method_def = ClassMethodDef {
             js_doc: method_js_doc,
             accessibility: class_method.accessibility,
             optional: class_method.is_optional,
             is_abstract: class_method.is_abstract,
             is_static: class_method.is_static,
-            is_override: class_method.is_override,
+            is_override,
+            overrides: None,
+            overridden_by: vec![],
             name: method_name,
             kind: class_method.kind,
             function_def: fn_def,
@@ -389,10 +939,14 @@ pub fn class_to_class_def(
         {
           let ts_type = if let Some(type_ann) = &class_prop.type_ann {
             // if the property has a type annotation, use it
-            Some(TsTypeDef::new(parsed_source, &type_ann.type_ann))
-          } else if let Some(value) = &class_prop.value {
+            Some(TsTypeDef::new(parsed_source, options, &type_ann.type_ann))
+          } else if let Some(value) = class_prop
+            .value
+            .as_ref()
+            .filter(|_| !options.isolated_declarations)
+          {
             // else, if it has an initializer, try to infer the type
-            infer_ts_type_from_expr(parsed_source, value, false)
+            infer_ts_type_from_expr(parsed_source, options, value, false)
           } else {
             // else, none
             None
@@ -411,6 +965,14 @@ pub fn class_to_class_def(
             is_abstract: class_prop.is_abstract,
             is_static: class_prop.is_static,
             is_override: class_prop.is_override,
+            is_declare: class_prop.declare,
+            has_initializer: class_prop.value.is_some(),
+            initializer_text: class_prop
+              .value
+              .as_ref()
+              .and_then(|value| literal_initializer_text(parsed_source, value)),
+            from_constructor: false,
+            overrides: None,
             accessibility: class_prop.accessibility,
             name: prop_name,
             decorators,
@@ -425,14 +987,15 @@ pub fn class_to_class_def(
         {
           let mut params = vec![];
           for param in &ts_index_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let ts_type = ts_index_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let index_sig_def = ClassIndexSignatureDef {
             location: get_location(parsed_source, ts_index_sig.start()),
@@ -453,14 +1016,27 @@ pub fn class_to_class_def(
 
   let type_params = maybe_type_param_decl_to_type_param_defs(
     parsed_source,
+    options,
     class.type_params.as_deref(),
   );
 
   let super_type_params = maybe_type_param_instantiation_to_type_defs(
     parsed_source,
+    options,
     class.super_type_params.as_deref(),
   );
 
+  // the constructors without a body are overloads of the implementation, or
+  // the constructor signatures of an ambient class if there is none
+  let mut constructor_overloads = vec![];
+  if constructors.iter().any(|constructor| constructor.has_body) {
+    let (implementations, overloads): (Vec<_>, Vec<_>) = constructors
+      .into_iter()
+      .partition(|constructor| constructor.has_body);
+    constructors = implementations;
+    constructor_overloads = overloads;
+  }
+
   let decorators = decorators_to_defs(parsed_source, &class.decorators);
 
   // JSDoc associated with the class may actually be a leading comment on a
@@ -476,14 +1052,17 @@ pub fn class_to_class_def(
       def_name,
       is_abstract: class.is_abstract,
       extends,
+      mixins,
       implements,
       constructors,
+      constructor_overloads,
       properties,
       index_signatures,
       methods,
       type_params,
       super_type_params,
       decorators,
+      body_source: None,
     },
     js_doc,
   )
@@ -491,11 +1070,12 @@ pub fn class_to_class_def(
 
 pub fn get_doc_for_class_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   class_decl: &deno_ast::swc::ast::ClassDecl,
 ) -> (String, ClassDef, JsDoc) {
   let class_name = class_decl.ident.sym.to_string();
   let (class_def, js_doc) =
-    class_to_class_def(parsed_source, &class_decl.class, None);
+    class_to_class_def(parsed_source, options, &class_decl.class, None);
 
   (class_name, class_def, js_doc)
 }
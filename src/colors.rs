@@ -1,4 +1,5 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+use std::cell::Cell;
 use std::fmt;
 use std::io::Write;
 use std::sync::atomic::AtomicBool;
@@ -26,8 +27,21 @@ pub fn disable_color() {
   USE_COLOR.store(false, Ordering::Relaxed);
 }
 
+thread_local! {
+  static SUPPRESS_COLOR: Cell<bool> = Cell::new(false);
+}
+
+/// Runs `f` with colors disabled on the current thread, regardless of the
+/// global setting.
+pub fn without_color<R>(f: impl FnOnce() -> R) -> R {
+  let prev = SUPPRESS_COLOR.with(|suppress| suppress.replace(true));
+  let result = f();
+  SUPPRESS_COLOR.with(|suppress| suppress.set(prev));
+  result
+}
+
 pub fn use_color() -> bool {
-  USE_COLOR.load(Ordering::Relaxed)
+  !SUPPRESS_COLOR.with(Cell::get) && USE_COLOR.load(Ordering::Relaxed)
 }
 
 fn style<S: AsRef<str>>(s: S, colorspec: ColorSpec) -> impl fmt::Display {
@@ -3,6 +3,7 @@
 use crate::colors;
 use crate::node::Location;
 use crate::util::swc::get_location;
+use crate::util::swc::is_false;
 
 use deno_ast::swc::ast::Decorator;
 use deno_ast::swc::ast::Expr;
@@ -20,13 +21,17 @@ pub struct DecoratorDef {
   pub name: String,
   #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub args: Vec<String>,
+  /// Whether the decorator is called, eg. `@Injectable()`, which would have
+  /// no arguments, as opposed to `@sealed`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_call: bool,
   pub location: Location,
 }
 
 impl Display for DecoratorDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(f, "@{}", self.name)?;
-    if !self.args.is_empty() {
+    if self.is_call {
       let args = self
         .args
         .iter()
@@ -56,6 +61,7 @@ impl DecoratorDef {
             return Self {
               name: ident.sym.to_string(),
               args,
+              is_call: true,
               location: get_location(parsed_source, ident.start()),
             };
           }
@@ -63,17 +69,20 @@ impl DecoratorDef {
         Self {
           name: "[UNSUPPORTED]".to_string(),
           args: vec![],
+          is_call: true,
           location: get_location(parsed_source, call_expr.start()),
         }
       }
       Expr::Ident(ident) => Self {
         name: ident.sym.to_string(),
         args: vec![],
+        is_call: false,
         location: get_location(parsed_source, ident.start()),
       },
       _ => Self {
         name: "[UNSUPPORTED]".to_string(),
         args: vec![],
+        is_call: false,
         location: get_location(parsed_source, decorator.start()),
       },
     }
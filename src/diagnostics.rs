@@ -417,15 +417,16 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
   }
 
   fn visit_class_def(&mut self, def: &crate::class::ClassDef) {
-    // ctors
-    if def.constructors.len() == 1 {
-      self.visit_class_ctor_def(&def.constructors[0]);
-    } else if !def.constructors.is_empty() {
-      // skip the first one
-      let ctors = &def.constructors[1..];
-      for ctor in ctors {
-        self.visit_class_ctor_def(ctor);
-      }
+    // ctors, the overloads being declared before the implementation
+    let ctors = def
+      .constructor_overloads
+      .iter()
+      .chain(&def.constructors)
+      .collect::<Vec<_>>();
+    // skip the first one when there are several
+    let skip = usize::from(ctors.len() > 1);
+    for ctor in &ctors[skip..] {
+      self.visit_class_ctor_def(ctor);
     }
 
     // properties
@@ -532,7 +533,7 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
     }
 
     // methods
-    for method in &def.methods {
+    for method in def.methods.iter().flat_map(|m| m.signatures()) {
       self
         .diagnostics
         .check_missing_js_doc(&method.js_doc, &method.location);
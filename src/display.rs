@@ -1,7 +1,56 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 use crate::colors;
+use std::cell::Cell;
 use std::fmt::{Display, Formatter, Result};
 
+/// Options for rendering types as TypeScript source text, see
+/// `TsTypeDef::to_ts_string`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+  /// The number of nested type levels to render before eliding the rest as
+  /// `...`. `None` renders the whole type.
+  pub max_depth: Option<usize>,
+}
+
+thread_local! {
+  static MAX_DEPTH: Cell<Option<usize>> = Cell::new(None);
+  static DEPTH: Cell<usize> = Cell::new(0);
+}
+
+/// Runs `f` with colors disabled and the depth limit of `opts` applied to
+/// all types displayed on the current thread.
+pub(crate) fn with_display_options<R>(
+  opts: DisplayOptions,
+  f: impl FnOnce() -> R,
+) -> R {
+  let prev_max_depth =
+    MAX_DEPTH.with(|max_depth| max_depth.replace(opts.max_depth));
+  let prev_depth = DEPTH.with(|depth| depth.replace(0));
+  let result = colors::without_color(f);
+  MAX_DEPTH.with(|max_depth| max_depth.set(prev_max_depth));
+  DEPTH.with(|depth| depth.set(prev_depth));
+  result
+}
+
+/// Displays a nested type, or `...` if the depth limit has been reached.
+pub(crate) fn display_nested(
+  f: &mut Formatter<'_>,
+  display: impl FnOnce(&mut Formatter<'_>) -> Result,
+) -> Result {
+  let depth = DEPTH.with(Cell::get);
+  if MAX_DEPTH
+    .with(Cell::get)
+    .is_some_and(|max_depth| depth >= max_depth)
+  {
+    return write!(f, "...");
+  }
+
+  DEPTH.with(|cell| cell.set(depth + 1));
+  let result = display(f);
+  DEPTH.with(|cell| cell.set(depth));
+  result
+}
+
 pub(crate) struct Indent(pub i64);
 
 impl Display for Indent {
@@ -83,6 +132,10 @@ cfg_if! {
       colors::magenta(if is_async { "async " } else { "" })
     }
 
+    pub(crate) fn display_declare(is_declare: bool) -> impl Display {
+      colors::magenta(if is_declare { "declare " } else { "" })
+    }
+
     pub(crate) fn display_generator(is_generator: bool) -> impl Display {
       colors::magenta(if is_generator { "*" } else { "" })
     }
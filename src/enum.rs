@@ -8,6 +8,7 @@ use serde::Serialize;
 use crate::js_doc::JsDoc;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefOptions;
 use crate::util::swc::get_location;
 use crate::util::swc::js_doc_for_range;
 use crate::Location;
@@ -31,6 +32,7 @@ pub struct EnumDef {
 
 pub fn get_doc_for_ts_enum_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   enum_decl: &deno_ast::swc::ast::TsEnumDecl,
 ) -> (String, EnumDef) {
   let enum_name = enum_decl.id.sym.to_string();
@@ -46,7 +48,7 @@ pub fn get_doc_for_ts_enum_decl(
         Str(str_) => str_.value.to_string(),
       };
       let init = if let Some(expr) = &enum_member.init {
-        infer_ts_type_from_expr(parsed_source, expr, true)
+        infer_ts_type_from_expr(parsed_source, options, expr, true)
       } else {
         None
       };
@@ -0,0 +1,31 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::node::DocNode;
+
+/// A predicate over doc nodes, eg. to only document a feature area of a
+/// package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocFilter {
+  /// The nodes that have the given `@tag`.
+  Tagged(String),
+}
+
+impl DocFilter {
+  /// Matches the nodes with the given `@tag`, eg.
+  /// `DocFilter::tagged("networking")` for the symbols documented with
+  /// `@tag networking`.
+  pub fn tagged(tag: impl Into<String>) -> Self {
+    Self::Tagged(tag.into())
+  }
+
+  pub fn matches(&self, node: &DocNode) -> bool {
+    match self {
+      Self::Tagged(tag) => node.tags().contains(&tag.as_str()),
+    }
+  }
+
+  /// The nodes that match the filter.
+  pub fn filter<'a>(&self, nodes: &'a [DocNode]) -> Vec<&'a DocNode> {
+    nodes.iter().filter(|node| self.matches(node)).collect()
+  }
+}
@@ -2,8 +2,15 @@
 
 use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
-use crate::params::param_to_param_def;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::params::params_to_param_defs;
+use crate::params::signature_params;
+use crate::params::ParamPatternDef;
+use crate::ts_type::TsFnOrConstructorDef;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeDefOptions;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::util::swc::is_false;
@@ -11,6 +18,7 @@ use crate::ParamDef;
 use deno_ast::swc::ast::ReturnStmt;
 use deno_ast::swc::ast::Stmt;
 use deno_ast::ParsedSource;
+use deno_ast::SourceRangedForSpanned;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -21,9 +29,25 @@ pub struct FunctionDef {
   /// set when the function is a default export and has a name in its declaration
   pub def_name: Option<String>,
   pub params: Vec<ParamDef>,
+  /// The type of the `this` pseudo-parameter, eg. `MyClass` for
+  /// `function f(this: MyClass)`, which is not included in `params`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub this_type: Option<TsTypeDef>,
   pub return_type: Option<TsTypeDef>,
+  /// The description of the return value, from the `@returns` tag of the
+  /// JSDoc of the function, see [`FunctionDef::apply_returns_tag`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub return_type_doc: Option<String>,
   #[serde(skip_serializing_if = "is_false", default)]
   pub has_body: bool,
+  /// Where the body is in the source text of the module, absent for
+  /// signatures without a body, like overloads and interface methods.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub body_span: Option<BodySpan>,
+  /// Whether this is one of the overload signatures of a function, whose
+  /// implementation signature is then omitted from the documentation.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_overload: bool,
   pub is_async: bool,
   pub is_generator: bool,
   pub type_params: Vec<TsTypeParamDef>,
@@ -31,59 +55,179 @@ pub struct FunctionDef {
   pub decorators: Vec<DecoratorDef>,
 }
 
+/// The byte range of the body of a function, including its braces, in the
+/// source text of its module, ie. `&source[span.start..span.end]`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BodySpan {
+  /// The 0-indexed byte offset of the opening brace.
+  pub start: usize,
+  /// The 0-indexed byte offset following the closing brace.
+  pub end: usize,
+}
+
+impl FunctionDef {
+  /// Sets the [`FunctionDef::return_type_doc`] from the description of the
+  /// `@returns` tag of the JSDoc of the function, if any.
+  pub(crate) fn apply_returns_tag(&mut self, js_doc: &JsDoc) {
+    self.return_type_doc = js_doc.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Return { doc, .. } => doc.clone(),
+      _ => None,
+    });
+  }
+
+  /// The parameters as written in the signature, ie. preceded by the `this`
+  /// pseudo-parameter when the function declares its type.
+  pub fn signature_params(&self) -> Vec<ParamDef> {
+    signature_params(self.this_type.as_ref(), &self.params)
+  }
+
+  /// Whether the two signatures can be overloads of the same function, ie.
+  /// they have the same number of type parameters and the same return type,
+  /// and the parameters of one are compatible with the ones of the other:
+  /// the parameters the shorter list lacks are optional, and the types at
+  /// each position are equal, or the members of one union are a subset of
+  /// the members of the other, eg. `string` and `string | number`.
+  /// Parameters without a type are compatible with any. The names of the
+  /// functions are not compared, see [`DocNode::matches_overload`].
+  ///
+  /// [`DocNode::matches_overload`]: crate::DocNode::matches_overload
+  pub fn has_compatible_signature(&self, other: &FunctionDef) -> bool {
+    if self.type_params.len() != other.type_params.len()
+      || self.return_type != other.return_type
+      || self.this_type != other.this_type
+    {
+      return false;
+    }
+
+    let (shorter, longer) = if self.params.len() <= other.params.len() {
+      (&self.params, &other.params)
+    } else {
+      (&other.params, &self.params)
+    };
+    if !longer[shorter.len()..].iter().all(is_optional_param) {
+      return false;
+    }
+    shorter.iter().zip(longer.iter()).all(|(a, b)| {
+      match (param_ts_type(a), param_ts_type(b)) {
+        (Some(a), Some(b)) => {
+          let a = union_members(a);
+          let b = union_members(b);
+          a.iter().all(|member| b.contains(member))
+            || b.iter().all(|member| a.contains(member))
+        }
+        _ => true,
+      }
+    })
+  }
+}
+
+fn is_optional_param(param: &ParamDef) -> bool {
+  match &param.pattern {
+    ParamPatternDef::Array { optional, .. }
+    | ParamPatternDef::Identifier { optional, .. }
+    | ParamPatternDef::Object { optional, .. } => *optional,
+    ParamPatternDef::Assign { .. } | ParamPatternDef::Rest { .. } => true,
+  }
+}
+
+fn param_ts_type(param: &ParamDef) -> Option<&TsTypeDef> {
+  match &param.pattern {
+    ParamPatternDef::Assign { left, .. } if param.ts_type.is_none() => {
+      left.ts_type.as_ref()
+    }
+    _ => param.ts_type.as_ref(),
+  }
+}
+
+fn union_members(ts_type: &TsTypeDef) -> Vec<&TsTypeDef> {
+  match &ts_type.union {
+    Some(members) => members.iter().collect(),
+    None => vec![ts_type],
+  }
+}
+
+/// The type of the function, eg. `(a: string) => number`. Return types that
+/// are neither declared nor inferred are `unknown`.
+impl From<&FunctionDef> for TsTypeDef {
+  fn from(function_def: &FunctionDef) -> Self {
+    TsTypeDef {
+      kind: Some(TsTypeDefKind::FnOrConstructor),
+      fn_or_constructor: Some(Box::new(TsFnOrConstructorDef {
+        constructor: false,
+        ts_type: function_def
+          .return_type
+          .clone()
+          .unwrap_or_else(|| TsTypeDef::keyword("unknown")),
+        params: function_def.params.clone(),
+        this_type: function_def.this_type.clone(),
+        type_params: function_def.type_params.clone(),
+      })),
+      ..Default::default()
+    }
+  }
+}
+
 pub fn function_to_function_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   function: &deno_ast::swc::ast::Function,
   def_name: Option<String>,
 ) -> FunctionDef {
-  let params = function
-    .params
-    .iter()
-    .map(|param| param_to_param_def(parsed_source, param))
-    .collect();
-
-  let maybe_return_type = match function
-    .return_type
-    .as_deref()
-    .map(|return_type| TsTypeDef::new(parsed_source, &return_type.type_ann))
-  {
-    Some(return_type) => Some(return_type),
-    None
-      if !function.is_generator
-        && function.body.is_some()
-        && get_return_stmt_with_arg_from_function(function).is_none() =>
-    {
-      if function.is_async {
-        Some(TsTypeDef {
-          repr: "Promise".to_string(),
-          kind: Some(crate::ts_type::TsTypeDefKind::TypeRef),
-          type_ref: Some(crate::ts_type::TsTypeRefDef {
-            type_params: Some(vec![TsTypeDef::keyword("void")]),
-            type_name: "Promise".to_string(),
-          }),
-          ..Default::default()
-        })
-      } else {
-        Some(TsTypeDef::keyword("void"))
+  let (this_type, params) =
+    params_to_param_defs(parsed_source, options, &function.params);
+
+  let maybe_return_type =
+    match function.return_type.as_deref().map(|return_type| {
+      TsTypeDef::new(parsed_source, options, &return_type.type_ann)
+    }) {
+      Some(return_type) => Some(return_type),
+      None
+        if !function.is_generator
+          && !options.isolated_declarations
+          && function.body.is_some()
+          && get_return_stmt_with_arg_from_function(function).is_none() =>
+      {
+        if function.is_async {
+          Some(TsTypeDef {
+            repr: "Promise".to_string(),
+            kind: Some(crate::ts_type::TsTypeDefKind::TypeRef),
+            type_ref: Some(crate::ts_type::TsTypeRefDef {
+              type_params: Some(vec![TsTypeDef::keyword("void")]),
+              type_name: "Promise".to_string(),
+            }),
+            ..Default::default()
+          })
+        } else {
+          Some(TsTypeDef::keyword("void"))
+        }
       }
-    }
-    None => None,
-  };
+      None => None,
+    };
 
   let type_params = maybe_type_param_decl_to_type_param_defs(
     parsed_source,
+    options,
     function.type_params.as_deref(),
   );
 
   let has_body = function.body.is_some();
+  let source_start = parsed_source.text_info().range().start;
+  let body_span = function.body.as_ref().map(|body| BodySpan {
+    start: body.start().as_byte_index(source_start),
+    end: body.end().as_byte_index(source_start),
+  });
 
   let decorators = decorators_to_defs(parsed_source, &function.decorators);
 
   FunctionDef {
     def_name,
     params,
+    this_type,
     return_type: maybe_return_type,
+    return_type_doc: None,
     has_body,
+    body_span,
+    is_overload: false,
     is_async: function.is_async,
     is_generator: function.is_generator,
     type_params,
@@ -93,10 +237,12 @@ pub fn function_to_function_def(
 
 pub fn get_doc_for_fn_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   fn_decl: &deno_ast::swc::ast::FnDecl,
 ) -> (String, FunctionDef) {
   let name = fn_decl.ident.sym.to_string();
-  let fn_def = function_to_function_def(parsed_source, &fn_decl.function, None);
+  let fn_def =
+    function_to_function_def(parsed_source, options, &fn_decl.function, None);
   (name, fn_def)
 }
 
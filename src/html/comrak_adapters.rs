@@ -22,6 +22,7 @@ pub struct HighlightAdapter {
   pub language_cb:
     fn(&str) -> Option<&'static tree_sitter_highlight::HighlightConfiguration>,
   pub show_line_numbers: bool,
+  pub syntax_theme: super::SyntaxTheme,
 }
 
 impl HighlightAdapter {
@@ -106,7 +107,7 @@ impl SyntaxHighlighterAdapter for HighlightAdapter {
             .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
         });
 
-    let theme = &self.theme_set.themes["InspiredGitHub"];
+    let theme = &self.theme_set.themes[self.syntax_theme.syntect_theme_name()];
     let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
 
     match self.highlight_html(
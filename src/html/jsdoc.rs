@@ -16,6 +16,7 @@ use serde::Serialize;
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::cmp::Ordering;
+use std::collections::HashSet;
 
 #[cfg(feature = "ammonia")]
 use crate::html::comrak_adapters::URLRewriter;
@@ -436,35 +437,39 @@ pub(crate) fn jsdoc_body_to_html(
   js_doc: &JsDoc,
   summary: bool,
 ) -> Option<String> {
-  if let Some(doc) = js_doc.doc.as_deref() {
-    if doc.is_empty() {
-      None
-    } else {
-      Some(markdown_to_html(ctx, doc, summary, false).html)
-    }
-  } else {
+  let satisfies = if summary {
     None
-  }
+  } else {
+    js_doc.tags.iter().find_map(|tag| {
+      if let JsDocTag::Satisfies { type_ref, .. } = tag {
+        Some(format!("Satisfies {{@linkcode {type_ref}}}"))
+      } else {
+        None
+      }
+    })
+  };
+
+  let doc = match (js_doc.doc.as_deref(), satisfies) {
+    (Some(doc), Some(satisfies)) if !doc.is_empty() => {
+      Cow::Owned(format!("{doc}\n\n{satisfies}"))
+    }
+    (_, Some(satisfies)) => Cow::Owned(satisfies),
+    (Some(doc), None) if !doc.is_empty() => Cow::Borrowed(doc),
+    _ => return None,
+  };
+
+  Some(markdown_to_html(ctx, &doc, summary, false).html)
 }
 
 pub(crate) fn jsdoc_examples(
   ctx: &RenderContext,
   js_doc: &JsDoc,
 ) -> Option<SectionCtx> {
-  let mut i = 0;
-
   let examples = js_doc
-    .tags
-    .iter()
-    .filter_map(|tag| {
-      if let JsDocTag::Example { doc } = tag {
-        let example = ExampleCtx::new(ctx, doc, i);
-        i += 1;
-        Some(example)
-      } else {
-        None
-      }
-    })
+    .examples()
+    .into_iter()
+    .enumerate()
+    .map(|(i, doc)| ExampleCtx::new(ctx, doc, i))
     .collect::<Vec<ExampleCtx>>();
 
   if !examples.is_empty() {
@@ -477,6 +482,39 @@ pub(crate) fn jsdoc_examples(
   }
 }
 
+/// The section of the events documented by the `@fires` and `@emits` tags.
+pub(crate) fn jsdoc_events(
+  ctx: &RenderContext,
+  js_doc: &JsDoc,
+  location: &crate::Location,
+) -> Option<SectionCtx> {
+  let events = js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| match tag {
+      JsDocTag::Fires { name, doc } => Some(DocEntryCtx::new(
+        ctx,
+        &name_to_id("event", name),
+        name,
+        "",
+        HashSet::new(),
+        doc.as_deref(),
+        location,
+      )),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  if events.is_empty() {
+    return None;
+  }
+
+  Some(SectionCtx {
+    title: "Events",
+    content: SectionContentCtx::DocEntry(events),
+  })
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct ExampleCtx {
   anchor: AnchorCtx,
@@ -508,12 +546,20 @@ impl ExampleCtx {
   }
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct ReExportedByCtx {
+  pub name: String,
+  pub href: String,
+}
+
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct ModuleDocCtx {
   pub title: Option<String>,
   pub deprecated: Option<String>,
+  pub re_exported_by: Vec<ReExportedByCtx>,
   pub usages: Option<UsagesCtx>,
   pub toc: Option<String>,
+  pub class_diagram: Option<String>,
   pub sections: super::SymbolContentCtx,
 }
 
@@ -591,11 +637,22 @@ impl ModuleDocCtx {
       ));
     }
 
+    let class_diagram = if render_ctx.ctx.class_diagrams
+      && module_doc_nodes.iter().any(|node| {
+        matches!(node.kind, DocNodeKind::Class | DocNodeKind::Interface)
+      }) {
+      Some(crate::generate_mermaid_class_diagram(module_doc_nodes))
+    } else {
+      None
+    };
+
     Self {
       title,
       deprecated,
+      re_exported_by: re_exported_by(render_ctx, specifier, doc_nodes_by_url),
       usages: UsagesCtx::new(render_ctx, &[]),
       toc,
+      class_diagram,
       sections: super::SymbolContentCtx {
         id: "module_doc".to_string(),
         docs: html,
@@ -604,3 +661,45 @@ impl ModuleDocCtx {
     }
   }
 }
+
+/// The other documented files that re-export symbols of the file, ie. which
+/// document symbols defined in it.
+fn re_exported_by(
+  render_ctx: &RenderContext,
+  specifier: &ModuleSpecifier,
+  doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<DocNode>>,
+) -> Vec<ReExportedByCtx> {
+  fn defines_symbol_of(nodes: &[DocNode], specifier: &str) -> bool {
+    nodes.iter().any(|node| {
+      if let Some(namespace_def) = &node.namespace_def {
+        if defines_symbol_of(&namespace_def.elements, specifier) {
+          return true;
+        }
+      }
+      !matches!(node.kind, DocNodeKind::Import | DocNodeKind::ModuleDoc)
+        && node.location.filename == specifier
+    })
+  }
+
+  let ctx = render_ctx.ctx;
+  doc_nodes_by_url
+    .iter()
+    .filter(|(url, nodes)| {
+      *url != specifier && defines_symbol_of(nodes, specifier.as_str())
+    })
+    .map(|(url, _)| {
+      let short_path = ctx.url_to_short_path(url);
+      let target = if ctx.main_entrypoint.as_ref() == Some(url) {
+        UrlResolveKind::Root
+      } else {
+        UrlResolveKind::File(&short_path)
+      };
+      ReExportedByCtx {
+        name: short_path.to_name(),
+        href: ctx
+          .href_resolver
+          .resolve_path(render_ctx.get_current_resolve(), target),
+      }
+    })
+    .collect()
+}
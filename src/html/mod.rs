@@ -4,6 +4,7 @@ use handlebars::Handlebars;
 use indexmap::IndexMap;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -14,10 +15,13 @@ pub mod jsdoc;
 pub mod pages;
 mod parameters;
 pub mod partition;
+mod redirect;
 mod render_context;
 mod search;
 pub mod sidepanels;
+mod sitemap;
 mod symbols;
+mod syntax_theme;
 #[cfg(feature = "tree-sitter")]
 mod tree_sitter;
 mod types;
@@ -26,11 +30,14 @@ mod util;
 
 pub use pages::generate_symbol_page;
 pub use pages::generate_symbol_pages_for_module;
+pub use redirect::generate_redirect_map;
 pub use render_context::RenderContext;
 pub use search::generate_search_index;
+pub use sitemap::generate_sitemap_xml;
 pub use symbols::namespace;
 pub use symbols::SymbolContentCtx;
 pub use symbols::SymbolGroupCtx;
+pub use syntax_theme::SyntaxTheme;
 pub use usage::usage_to_md;
 pub use util::compute_namespaced_symbols;
 pub use util::DocNodeKindCtx;
@@ -38,6 +45,9 @@ pub use util::HrefResolver;
 pub use util::NamespacedGlobalSymbols;
 pub use util::UrlResolveKind;
 
+use util::BasePathHrefResolver;
+use util::ModulePageHrefResolver;
+
 pub const STYLESHEET: &str = include_str!("./templates/styles.gen.css");
 pub const STYLESHEET_FILENAME: &str = "styles.css";
 
@@ -72,6 +82,53 @@ pub struct GenerateOptions {
   pub rewrite_map: Option<IndexMap<ModuleSpecifier, String>>,
   pub hide_module_doc_title: bool,
   pub sidebar_flatten_namespaces: bool,
+  /// CSS that is injected in a `<style>` tag at the end of the `<head>` of
+  /// every generated page.
+  pub custom_css: Option<String>,
+  /// Raw HTML that is injected at the end of the `<head>` of every generated
+  /// page, eg. analytics scripts or font imports.
+  pub custom_head_html: Option<String>,
+  /// The color theme used for highlighted code blocks.
+  pub syntax_theme: SyntaxTheme,
+  /// The URL the documentation is deployed to. If set, Open Graph `<meta>`
+  /// tags are emitted on every symbol page for richer link previews.
+  pub og_base_url: Option<String>,
+  /// The URL of the image used in the Open Graph `<meta>` tags, eg. a logo.
+  pub og_image: Option<String>,
+  /// The URL the documentation is deployed to. If set, a `sitemap.xml`
+  /// listing every generated page is emitted.
+  pub sitemap_base_url: Option<String>,
+  /// The path the documentation is deployed to, eg. `/myproject/docs`. If
+  /// set, the links between the generated pages, as well as the ones to the
+  /// stylesheets and scripts, are absolute paths starting with it instead of
+  /// being relative to the current page.
+  pub base_path: Option<String>,
+  /// Whether module pages show a Mermaid class diagram of the classes and
+  /// interfaces of the module, in a `<pre class="mermaid">` block. The
+  /// Mermaid script needs to be loaded for it to be rendered, eg. through
+  /// `custom_head_html`.
+  pub class_diagrams: bool,
+  /// Whether the documented code is compiled with TypeScript's
+  /// `exactOptionalPropertyTypes` option, in which case optional properties
+  /// are noted as not accepting `undefined` when present.
+  pub exact_optional_property_types: bool,
+  /// The number of members above which union types are rendered with one
+  /// member per line. Unions with an object literal, function, conditional
+  /// or mapped type member always are.
+  pub union_break_threshold: usize,
+  /// Whether class pages show, next to their `implements` clause, whether
+  /// the class declares all the required members of the interfaces, when
+  /// those are declared by the same module.
+  pub check_implementations: bool,
+  /// Whether each symbol gets its own page, at [`symbol_page_url`].
+  /// Otherwise, the symbols are documented on the page of their module, and
+  /// linked to by their anchor on it.
+  pub per_symbol_pages: bool,
+  /// The [`TsTypeDef::complexity_score`](crate::ts_type::TsTypeDef::complexity_score)
+  /// above which the types of symbols and members are rendered as a
+  /// collapsed block, which can be expanded, instead of inline. Types are
+  /// always rendered inline if not set.
+  pub collapse_type_threshold: Option<u32>,
 }
 
 pub struct GenerateCtx<'ctx> {
@@ -90,6 +147,16 @@ pub struct GenerateCtx<'ctx> {
   pub single_file_mode: bool,
   pub sidebar_hide_all_symbols: bool,
   pub sidebar_flatten_namespaces: bool,
+  pub custom_css: Option<String>,
+  pub custom_head_html: Option<String>,
+  pub og_base_url: Option<String>,
+  pub og_image: Option<String>,
+  pub class_diagrams: bool,
+  pub exact_optional_property_types: bool,
+  pub union_break_threshold: usize,
+  pub check_implementations: bool,
+  pub per_symbol_pages: bool,
+  pub collapse_type_threshold: Option<u32>,
 }
 
 impl<'ctx> GenerateCtx<'ctx> {
@@ -102,29 +169,38 @@ impl<'ctx> GenerateCtx<'ctx> {
       return rewrite.to_owned().into();
     }
 
-    if url.scheme() != "file" {
-      return url.to_string().into();
-    }
+    url_to_short_path(self.common_ancestor.as_deref(), url)
+  }
+}
 
-    let url_file_path = url.to_file_path().unwrap();
+/// The path of the documentation of a module relative to the common ancestor
+/// of the documented modules, see [`find_common_ancestor`].
+pub(crate) fn url_to_short_path(
+  common_ancestor: Option<&Path>,
+  url: &ModuleSpecifier,
+) -> ShortPath {
+  if url.scheme() != "file" {
+    return url.to_string().into();
+  }
 
-    let Some(common_ancestor) = &self.common_ancestor else {
-      return url_file_path.to_string_lossy().to_string().into();
-    };
+  let url_file_path = url.to_file_path().unwrap();
 
-    let stripped_path = url_file_path
-      .strip_prefix(common_ancestor)
-      .unwrap_or(&url_file_path);
+  let Some(common_ancestor) = common_ancestor else {
+    return url_file_path.to_string_lossy().to_string().into();
+  };
 
-    let path = stripped_path.to_string_lossy().to_string();
+  let stripped_path = url_file_path
+    .strip_prefix(common_ancestor)
+    .unwrap_or(&url_file_path);
 
-    if path.is_empty() {
-      ".".to_string()
-    } else {
-      path
-    }
-    .into()
+  let path = stripped_path.to_string_lossy().to_string();
+
+  if path.is_empty() {
+    ".".to_string()
+  } else {
+    path
   }
+  .into()
 }
 
 #[derive(Clone, Debug)]
@@ -213,6 +289,10 @@ pub fn setup_hbs<'t>() -> Result<Handlebars<'t>, anyhow::Error> {
     "doc_block_subtitle_interface",
     include_str!("./templates/doc_block_subtitle_interface.hbs"),
   )?;
+  reg.register_template_string(
+    "doc_block_subtitle_namespace",
+    include_str!("./templates/doc_block_subtitle_namespace.hbs"),
+  )?;
   reg.register_template_string(
     "anchor",
     include_str!("./templates/anchor.hbs"),
@@ -229,6 +309,10 @@ pub fn setup_hbs<'t>() -> Result<Handlebars<'t>, anyhow::Error> {
     "example",
     include_str!("./templates/example.hbs"),
   )?;
+  reg.register_template_string(
+    "source",
+    include_str!("./templates/source.hbs"),
+  )?;
   reg.register_template_string(
     "function",
     include_str!("./templates/function.hbs"),
@@ -320,6 +404,7 @@ pub fn setup_highlighter(
     #[cfg(feature = "tree-sitter")]
     language_cb: tree_sitter::tree_sitter_language_cb,
     show_line_numbers,
+    syntax_theme: SyntaxTheme::Default,
   }
 }
 
@@ -333,22 +418,38 @@ pub fn generate(
   }
 
   let common_ancestor = find_common_ancestor(doc_nodes_by_url.keys(), true);
+  let mut highlight_adapter = setup_highlighter(false);
+  highlight_adapter.syntax_theme = options.syntax_theme;
   let ctx = GenerateCtx {
     package_name: options.package_name,
     common_ancestor,
     main_entrypoint: options.main_entrypoint,
     specifiers: doc_nodes_by_url.keys().cloned().collect(),
     hbs: setup_hbs()?,
-    highlight_adapter: setup_highlighter(false),
+    highlight_adapter,
     #[cfg(feature = "ammonia")]
     url_rewriter: None,
-    href_resolver: options.href_resolver,
+    href_resolver: page_href_resolver(
+      options.href_resolver,
+      options.per_symbol_pages,
+      options.base_path,
+    ),
     usage_composer: options.usage_composer,
     rewrite_map: options.rewrite_map,
     hide_module_doc_title: options.hide_module_doc_title,
     single_file_mode: doc_nodes_by_url.len() == 1,
     sidebar_hide_all_symbols: false,
     sidebar_flatten_namespaces: options.sidebar_flatten_namespaces,
+    custom_css: options.custom_css,
+    custom_head_html: options.custom_head_html,
+    og_base_url: options.og_base_url,
+    og_image: options.og_image,
+    class_diagrams: options.class_diagrams,
+    exact_optional_property_types: options.exact_optional_property_types,
+    union_break_threshold: options.union_break_threshold,
+    check_implementations: options.check_implementations,
+    per_symbol_pages: options.per_symbol_pages,
+    collapse_type_threshold: options.collapse_type_threshold,
   };
   let mut files = HashMap::new();
 
@@ -363,6 +464,7 @@ pub fn generate(
       doc_nodes_by_url,
       partitions_for_entrypoint_nodes,
       None,
+      vec![],
     );
     files.insert("./index.html".to_string(), index);
   }
@@ -407,38 +509,52 @@ pub fn generate(
         doc_nodes,
       );
 
-      files.extend(symbol_pages.into_iter().map(
-        |(breadcrumbs_ctx, sidepanel_ctx, symbol_group_ctx)| {
-          let root = ctx.href_resolver.resolve_path(
-            UrlResolveKind::Symbol {
-              file: &short_path,
-              symbol: &symbol_group_ctx.name,
-            },
-            UrlResolveKind::Root,
-          );
-
-          let html_head_ctx = pages::HtmlHeadCtx::new(
-            &root,
-            &symbol_group_ctx.name,
-            ctx.package_name.as_ref(),
-            Some(short_path.clone()),
-          );
-
-          let file_name =
-            format!("{}/~/{}.html", short_path.as_str(), symbol_group_ctx.name);
-
-          let page_ctx = pages::PageCtx {
-            html_head_ctx,
-            sidepanel_ctx,
-            symbol_group_ctx,
-            breadcrumbs_ctx,
-          };
-
-          let symbol_page = ctx.hbs.render("pages/symbol", &page_ctx).unwrap();
-
-          (file_name, symbol_page)
-        },
-      ));
+      let mut symbol_groups = vec![];
+
+      for (breadcrumbs_ctx, sidepanel_ctx, symbol_group_ctx) in symbol_pages {
+        if !ctx.per_symbol_pages {
+          symbol_groups.push(symbol_group_ctx);
+          continue;
+        }
+
+        let root = ctx.href_resolver.resolve_path(
+          UrlResolveKind::Symbol {
+            file: &short_path,
+            symbol: &symbol_group_ctx.name,
+          },
+          UrlResolveKind::Root,
+        );
+
+        let file_name = symbol_page_url(&short_path, &symbol_group_ctx.name)
+          .to_string_lossy()
+          .into_owned();
+
+        let open_graph = pages::OpenGraphCtx::new(
+          &ctx,
+          &file_name,
+          &symbol_group_ctx.name,
+          pages::symbol_description(doc_nodes, &symbol_group_ctx.name),
+        );
+
+        let html_head_ctx = pages::HtmlHeadCtx::new(
+          &ctx,
+          &root,
+          &symbol_group_ctx.name,
+          Some(short_path.clone()),
+        )
+        .with_open_graph(open_graph);
+
+        let page_ctx = pages::PageCtx {
+          html_head_ctx,
+          sidepanel_ctx,
+          symbol_group_ctx,
+          breadcrumbs_ctx,
+        };
+
+        let symbol_page = ctx.hbs.render("pages/symbol", &page_ctx).unwrap();
+
+        files.insert(file_name, symbol_page);
+      }
 
       let index = pages::render_index(
         &ctx,
@@ -446,13 +562,17 @@ pub fn generate(
         doc_nodes_by_url,
         partitions_for_nodes,
         Some(short_path.clone()),
+        symbol_groups,
       );
 
       files.insert(format!("{}/~/index.html", short_path.as_str()), index);
     }
   }
 
-  files.insert(STYLESHEET_FILENAME.into(), STYLESHEET.into());
+  files.insert(
+    STYLESHEET_FILENAME.into(),
+    format!("{STYLESHEET}{}", ctx.highlight_adapter.syntax_theme.css()),
+  );
   files.insert(PAGE_STYLESHEET_FILENAME.into(), PAGE_STYLESHEET.into());
   files.insert(
     SEARCH_INDEX_FILENAME.into(),
@@ -462,9 +582,51 @@ pub fn generate(
   files.insert(FUSE_FILENAME.into(), FUSE_JS.into());
   files.insert(SEARCH_FILENAME.into(), SEARCH_JS.into());
 
+  if let Some(sitemap_base_url) = &options.sitemap_base_url {
+    files.insert(
+      sitemap::SITEMAP_FILENAME.into(),
+      sitemap::generate_sitemap_xml_for_pages(
+        &ctx,
+        sitemap_base_url,
+        doc_nodes_by_url,
+        files.contains_key("./all_symbols.html"),
+      ),
+    );
+  }
+
   Ok(files)
 }
 
+/// The resolver of the links between the generated pages, which point to the
+/// symbols on the pages of their modules unless each symbol gets its own page.
+pub(crate) fn page_href_resolver(
+  href_resolver: Rc<dyn HrefResolver>,
+  per_symbol_pages: bool,
+  base_path: Option<String>,
+) -> Rc<dyn HrefResolver> {
+  let href_resolver = if per_symbol_pages {
+    href_resolver
+  } else {
+    Rc::new(ModulePageHrefResolver {
+      inner: href_resolver,
+    })
+  };
+  match base_path {
+    Some(base_path) => Rc::new(BasePathHrefResolver {
+      base_path,
+      inner: href_resolver,
+    }),
+    None => href_resolver,
+  }
+}
+
+/// The path of the page generated for a symbol, relative to the root of the
+/// generated files, where `symbol` is the name of the symbol qualified by the
+/// namespaces it is declared in, eg. `Deno.errors.NotFound`.
+pub fn symbol_page_url(file: &ShortPath, symbol: &str) -> PathBuf {
+  PathBuf::from(format!("{}/~/{symbol}.html", file.as_str()))
+}
+
 pub fn find_common_ancestor<'a>(
   urls: impl Iterator<Item = &'a ModuleSpecifier>,
   single_file_is_common_ancestor: bool,
@@ -514,6 +676,15 @@ pub fn find_common_ancestor<'a>(
 mod test {
   use super::*;
 
+  #[test]
+  fn symbol_page_url_of_namespaced_symbol() {
+    let file = ShortPath::from("./mod.ts".to_string());
+    assert_eq!(
+      symbol_page_url(&file, "Deno.errors.NotFound"),
+      PathBuf::from("./mod.ts/~/Deno.errors.NotFound.html")
+    );
+  }
+
   #[cfg(not(windows))]
   #[test]
   fn common_ancestor_root() {
@@ -26,25 +26,31 @@ use serde::Serialize;
 pub struct HtmlHeadCtx {
   title: String,
   current_file: String,
+  root: String,
   stylesheet_url: String,
   page_stylesheet_url: String,
   url_search_index: String,
   script_js: String,
   fuse_js: String,
   url_search: String,
+  custom_css: Option<String>,
+  custom_head_html: Option<String>,
+  open_graph: Option<OpenGraphCtx>,
 }
 
 impl HtmlHeadCtx {
   pub fn new(
+    ctx: &GenerateCtx,
     root: &str,
     page: &str,
-    package_name: Option<&String>,
     current_file: Option<ShortPath>,
   ) -> Self {
     Self {
       title: format!(
         "{page} - {}documentation",
-        package_name
+        ctx
+          .package_name
+          .as_ref()
           .map(|package_name| format!("{package_name} "))
           .unwrap_or_default()
       ),
@@ -53,14 +59,85 @@ impl HtmlHeadCtx {
         .map(|current_file| current_file.as_str())
         .unwrap_or_default()
         .to_string(),
+      root: root.to_string(),
       stylesheet_url: format!("{root}{STYLESHEET_FILENAME}"),
       page_stylesheet_url: format!("{root}{PAGE_STYLESHEET_FILENAME}"),
       url_search_index: format!("{root}{SEARCH_INDEX_FILENAME}"),
       script_js: format!("{root}{SCRIPT_FILENAME}"),
       fuse_js: format!("{root}{FUSE_FILENAME}"),
       url_search: format!("{root}{SEARCH_FILENAME}"),
+      custom_css: ctx.custom_css.as_deref().map(escape_style_content),
+      custom_head_html: ctx.custom_head_html.clone(),
+      open_graph: None,
     }
   }
+
+  pub fn with_open_graph(mut self, open_graph: Option<OpenGraphCtx>) -> Self {
+    self.open_graph = open_graph;
+    self
+  }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct OpenGraphCtx {
+  title: String,
+  description: Option<String>,
+  url: String,
+  image: Option<String>,
+}
+
+impl OpenGraphCtx {
+  /// Returns `None` if no `og_base_url` has been configured, as the
+  /// `og:url` property is required by the Open Graph protocol.
+  pub fn new(
+    ctx: &GenerateCtx,
+    file_name: &str,
+    title: &str,
+    description: Option<String>,
+  ) -> Option<Self> {
+    let base_url = ctx.og_base_url.as_ref()?;
+
+    Some(Self {
+      title: title.to_string(),
+      description,
+      url: super::util::absolute_page_url(base_url, file_name),
+      image: ctx.og_image.clone(),
+    })
+  }
+}
+
+/// Finds the plain text description of the symbol with the given
+/// (possibly namespaced) name, which is the first sentence of its JSDoc.
+pub fn symbol_description(doc_nodes: &[DocNode], name: &str) -> Option<String> {
+  let mut name_parts = name.split('.').peekable();
+  let mut doc_nodes = doc_nodes;
+
+  loop {
+    let next_part = name_parts.next()?;
+    let mut nodes = doc_nodes.iter().filter(|node| {
+      !matches!(node.kind, DocNodeKind::ModuleDoc | DocNodeKind::Import)
+        && node.get_name() == next_part
+    });
+
+    if name_parts.peek().is_none() {
+      return nodes.find_map(|node| {
+        node
+          .js_doc
+          .doc
+          .as_deref()
+          .and_then(crate::js_doc::first_sentence)
+      });
+    }
+
+    doc_nodes = &nodes.find_map(|node| node.namespace_def.as_ref())?.elements;
+  }
+}
+
+/// Makes user provided CSS safe to embed in a `<style>` element by preventing
+/// it from closing the element early. `\/` is a valid CSS escape for `/`, so
+/// the stylesheet itself is unaffected.
+fn escape_style_content(css: &str) -> String {
+  css.replace("</", "<\\/")
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -69,6 +146,7 @@ struct IndexCtx {
   sidepanel_ctx: sidepanels::IndexSidepanelCtx,
   module_doc: Option<super::jsdoc::ModuleDocCtx>,
   breadcrumbs_ctx: BreadcrumbsCtx,
+  symbol_groups: Vec<SymbolGroupCtx>,
 }
 
 pub fn render_index(
@@ -77,6 +155,7 @@ pub fn render_index(
   doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<DocNode>>,
   partitions: IndexMap<String, Vec<DocNodeWithContext>>,
   file: Option<ShortPath>,
+  symbol_groups: Vec<SymbolGroupCtx>,
 ) -> String {
   let sidepanel_ctx = sidepanels::IndexSidepanelCtx::new(
     ctx,
@@ -115,14 +194,14 @@ pub fn render_index(
     UrlResolveKind::Root,
   );
 
-  let html_head_ctx =
-    HtmlHeadCtx::new(&root, "Index", ctx.package_name.as_ref(), None);
+  let html_head_ctx = HtmlHeadCtx::new(ctx, &root, "Index", None);
 
   let index_ctx = IndexCtx {
     html_head_ctx,
     sidepanel_ctx,
     module_doc,
     breadcrumbs_ctx: render_ctx.get_breadcrumbs(),
+    symbol_groups,
   };
 
   render_ctx
@@ -149,8 +228,11 @@ pub(crate) fn render_all_symbols_page(
 
   let sections = super::namespace::render_namespace(&render_ctx, partitions);
 
-  let html_head_ctx =
-    HtmlHeadCtx::new("./", "All Symbols", ctx.package_name.as_ref(), None);
+  let root = ctx
+    .href_resolver
+    .resolve_path(UrlResolveKind::AllSymbols, UrlResolveKind::Root);
+
+  let html_head_ctx = HtmlHeadCtx::new(ctx, &root, "All Symbols", None);
   let all_symbols_ctx = AllSymbolsCtx {
     html_head_ctx,
     content: SymbolContentCtx {
@@ -216,10 +298,8 @@ pub fn generate_symbol_page(
       break nodes.cloned().collect::<Vec<_>>();
     }
     namespace_paths.push(next_part);
-    if let Some(namespace) =
-      nodes.find(|node| matches!(node.kind, DocNodeKind::Namespace))
+    if let Some(namespace) = nodes.find_map(|node| node.namespace_def.as_ref())
     {
-      let namespace = namespace.namespace_def.as_ref().unwrap();
       doc_nodes = &namespace.elements;
     } else {
       return None;
@@ -230,8 +310,18 @@ pub fn generate_symbol_page(
     return None;
   }
 
-  let sidepanel_ctx =
-    SidepanelCtx::new(ctx, partitions_for_nodes, short_path, name);
+  let related_nodes = if namespace_paths.is_empty() {
+    related_nodes(&doc_nodes, doc_nodes_for_module)
+  } else {
+    vec![]
+  };
+  let sidepanel_ctx = SidepanelCtx::new(
+    ctx,
+    partitions_for_nodes,
+    short_path,
+    name,
+    &related_nodes,
+  );
 
   let (breadcrumbs_ctx, symbol_group_ctx) = render_symbol_page(
     ctx,
@@ -265,11 +355,17 @@ fn generate_symbol_pages_inner(
       format!("{}.{name}", namespace_paths.join("."))
     };
 
+    let related_nodes = if namespace_paths.is_empty() {
+      related_nodes(doc_nodes, doc_nodes_for_module)
+    } else {
+      vec![]
+    };
     let sidepanel_ctx = SidepanelCtx::new(
       ctx,
       partitions_for_nodes,
       short_path,
       &namespaced_name,
+      &related_nodes,
     );
 
     let (breadcrumbs_ctx, symbol_group_ctx) = render_symbol_page(
@@ -284,12 +380,10 @@ fn generate_symbol_pages_inner(
 
     generated_pages.push((breadcrumbs_ctx, sidepanel_ctx, symbol_group_ctx));
 
-    if let Some(doc_node) = doc_nodes
+    if let Some(namespace) = doc_nodes
       .iter()
-      .find(|doc_node| doc_node.kind == DocNodeKind::Namespace)
+      .find_map(|doc_node| doc_node.namespace_def.as_ref())
     {
-      let namespace = doc_node.namespace_def.as_ref().unwrap();
-
       let namespace_name_partitions =
         super::partition::partition_nodes_by_name(&namespace.elements);
 
@@ -315,6 +409,25 @@ fn generate_symbol_pages_inner(
   generated_pages
 }
 
+/// The symbols of the module related to any of the given nodes, which are
+/// the declarations of a single top level symbol.
+fn related_nodes<'a>(
+  doc_nodes: &[DocNode],
+  doc_nodes_for_module: &'a [DocNode],
+) -> Vec<&'a DocNode> {
+  let mut related: Vec<&DocNode> = vec![];
+  for doc_node in doc_nodes {
+    for node in doc_node.related_symbols(doc_nodes_for_module) {
+      if node.declaration_kind != crate::node::DeclarationKind::Private
+        && !related.iter().any(|other| std::ptr::eq(*other, node))
+      {
+        related.push(node);
+      }
+    }
+  }
+  related
+}
+
 #[derive(Debug, Serialize)]
 pub struct PageCtx {
   pub html_head_ctx: HtmlHeadCtx,
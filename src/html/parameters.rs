@@ -44,6 +44,13 @@ fn render_param(ctx: &RenderContext, param: &ParamDef, i: usize) -> String {
     .map(|ts_type| render_type_def_colon(ctx, ts_type))
     .unwrap_or_default();
 
+  if let Some(default_value) = &param.default_value_text {
+    return format!(
+      "<span>{name}{ts_type} = {}</span>",
+      html_escape::encode_safe(default_value)
+    );
+  }
+
   let question_mark = match param.pattern {
     ParamPatternDef::Array { optional, .. } if optional => "?",
     ParamPatternDef::Assign { .. } => "?",
@@ -72,4 +79,15 @@ pub(crate) fn param_name(param: &ParamDef, i: usize) -> (String, String) {
   }
 }
 
+/// The name that documents the parameter in `@param` tags, eg. `items` for
+/// `...items`, or `None` for a destructured parameter.
+pub(crate) fn param_doc_name(param: &ParamDef) -> Option<&str> {
+  match &param.pattern {
+    ParamPatternDef::Identifier { name, .. } => Some(name),
+    ParamPatternDef::Assign { left, .. } => param_doc_name(left),
+    ParamPatternDef::Rest { arg } => param_doc_name(arg),
+    ParamPatternDef::Array { .. } | ParamPatternDef::Object { .. } => None,
+  }
+}
+
 // TODO: classes: italic
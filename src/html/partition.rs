@@ -56,8 +56,12 @@ pub fn partition_nodes_by_kind<'a>(
         continue;
       }
 
-      if flatten_namespaces && node.doc_node.kind == DocNodeKind::Namespace {
-        let namespace_def = node.doc_node.namespace_def.as_ref().unwrap();
+      if let Some(namespace_def) = node
+        .doc_node
+        .namespace_def
+        .as_ref()
+        .filter(|_| flatten_namespaces)
+      {
         let mut namespace = (*node.ns_qualifiers).clone();
         namespace.push(node.doc_node.get_name().to_string());
 
@@ -158,8 +162,12 @@ pub fn partition_nodes_by_category<'a>(
         continue;
       }
 
-      if flatten_namespaces && node.doc_node.kind == DocNodeKind::Namespace {
-        let namespace_def = node.doc_node.namespace_def.as_ref().unwrap();
+      if let Some(namespace_def) = node
+        .doc_node
+        .namespace_def
+        .as_ref()
+        .filter(|_| flatten_namespaces)
+      {
         let mut namespace = (*node.ns_qualifiers).clone();
         namespace.push(node.doc_node.get_name().to_string());
 
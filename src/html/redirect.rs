@@ -0,0 +1,248 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::find_common_ancestor;
+use super::page_href_resolver;
+use super::url_to_short_path;
+use super::GenerateOptions;
+use super::HrefResolver;
+use super::ShortPath;
+use super::UrlResolveKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+use deno_ast::ModuleSpecifier;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Maps the URLs of the symbols of an old documentation snapshot to the ones
+/// of the same symbols in a new snapshot, so that links to moved or renamed
+/// symbols can be redirected, eg. through a `_redirects` file or HTTP 301
+/// responses. The URLs are the ones [`super::generate`] links the symbols
+/// with when given the same `options`, where the nodes are documented as the
+/// modules of their location.
+///
+/// Symbols which are still declared by the same module under the same name
+/// are redirected only when the URL of their module page changed, eg. as
+/// another module got documented, and the removed ones are left out. A
+/// symbol that disappeared from its module is matched with a symbol of the
+/// same name that appeared in another module, otherwise with a symbol of the
+/// same kind that appeared under a similar name, by Levenshtein distance,
+/// preferring the ones with an identical signature.
+pub fn generate_redirect_map(
+  options: &GenerateOptions,
+  old_nodes: &[DocNode],
+  new_nodes: &[DocNode],
+) -> HashMap<String, String> {
+  let href_resolver = page_href_resolver(
+    options.href_resolver.clone(),
+    options.per_symbol_pages,
+    options.base_path.clone(),
+  );
+  let old_symbols = symbols(old_nodes);
+  let new_symbols = symbols(new_nodes);
+  let old_urls = SymbolUrls::new(options, &*href_resolver, &old_symbols);
+  let new_urls = SymbolUrls::new(options, &*href_resolver, &new_symbols);
+
+  let mut redirects = HashMap::new();
+  let mut redirect = |old_key: &SymbolKey, new_key: &SymbolKey| {
+    let old_url = old_urls.resolve(old_key);
+    let new_url = new_urls.resolve(new_key);
+    if old_url != new_url {
+      redirects.insert(old_url, new_url);
+    }
+  };
+
+  for key in old_symbols.keys() {
+    if new_symbols.contains_key(key) {
+      redirect(key, key);
+    }
+  }
+
+  let mut added = new_symbols
+    .iter()
+    .filter(|(key, _)| !old_symbols.contains_key(*key))
+    .collect::<Vec<_>>();
+  let removed = old_symbols
+    .iter()
+    .filter(|(key, _)| !new_symbols.contains_key(*key))
+    .collect::<Vec<_>>();
+
+  let mut unmatched = vec![];
+
+  // moved symbols, matched first so that a rename can't claim them
+  for (old_key, old_symbol) in removed {
+    let position = best_match(&added, |(new_key, new_symbol)| {
+      (new_key.1 == old_key.1 && new_symbol.kind == old_symbol.kind)
+        .then_some(new_symbol.fingerprint != old_symbol.fingerprint)
+    });
+    if let Some(position) = position {
+      let (new_key, _) = added.remove(position);
+      redirect(old_key, new_key);
+    } else {
+      unmatched.push((old_key, old_symbol));
+    }
+  }
+
+  // renamed symbols
+  for (old_key, old_symbol) in unmatched {
+    let position = best_match(&added, |(new_key, new_symbol)| {
+      if new_symbol.kind != old_symbol.kind {
+        return None;
+      }
+      let same_signature = new_symbol.fingerprint == old_symbol.fingerprint;
+      let distance = levenshtein(&old_key.1, &new_key.1);
+      let longest = old_key.1.chars().count().max(new_key.1.chars().count());
+      let max_distance = if same_signature {
+        longest / 2
+      } else {
+        longest / 3
+      };
+      (distance <= max_distance).then_some((!same_signature, distance))
+    });
+    if let Some(position) = position {
+      let (new_key, _) = added.remove(position);
+      redirect(old_key, new_key);
+    }
+  }
+
+  redirects
+}
+
+/// Resolves the URLs of the symbols of a snapshot, whose modules are named
+/// relative to their common ancestor like by [`super::generate`].
+struct SymbolUrls<'a> {
+  href_resolver: &'a dyn HrefResolver,
+  short_paths: HashMap<String, ShortPath>,
+}
+
+impl<'a> SymbolUrls<'a> {
+  fn new(
+    options: &GenerateOptions,
+    href_resolver: &'a dyn HrefResolver,
+    symbols: &IndexMap<SymbolKey, Symbol>,
+  ) -> Self {
+    let specifiers = symbols
+      .keys()
+      .filter_map(|(filename, _)| {
+        Some((filename.as_str(), ModuleSpecifier::parse(filename).ok()?))
+      })
+      .collect::<IndexMap<_, _>>();
+    let common_ancestor = find_common_ancestor(specifiers.values(), true);
+    let short_paths = specifiers
+      .into_iter()
+      .map(|(filename, specifier)| {
+        let short_path = options
+          .rewrite_map
+          .as_ref()
+          .and_then(|rewrite_map| rewrite_map.get(&specifier))
+          .map(|rewrite| rewrite.to_owned().into())
+          .unwrap_or_else(|| {
+            url_to_short_path(common_ancestor.as_deref(), &specifier)
+          });
+        (filename.to_string(), short_path)
+      })
+      .collect();
+    Self {
+      href_resolver,
+      short_paths,
+    }
+  }
+
+  fn resolve(&self, (filename, name): &SymbolKey) -> String {
+    let Some(file) = self.short_paths.get(filename) else {
+      return format!("{filename}#{name}");
+    };
+    let url = self.href_resolver.resolve_path(
+      UrlResolveKind::Root,
+      UrlResolveKind::Symbol { file, symbol: name },
+    );
+    // the main module is documented at the root, as `./~/<name>.html`
+    match url.strip_prefix("././") {
+      Some(url) => format!("./{url}"),
+      None => url,
+    }
+  }
+}
+
+type SymbolKey = (String, String);
+
+struct Symbol {
+  kind: DocNodeKind,
+  fingerprint: Vec<serde_json::Value>,
+}
+
+/// The documented symbols, by module specifier and name, in their order of
+/// declaration. Overloads and merged declarations make up a single symbol.
+fn symbols(doc_nodes: &[DocNode]) -> IndexMap<SymbolKey, Symbol> {
+  let mut symbols: IndexMap<SymbolKey, Symbol> = IndexMap::new();
+  for doc_node in doc_nodes {
+    if matches!(doc_node.kind, DocNodeKind::Import | DocNodeKind::ModuleDoc) {
+      continue;
+    }
+    let fingerprint = fingerprint(doc_node);
+    symbols
+      .entry((doc_node.location.filename.clone(), doc_node.name.clone()))
+      .or_insert_with(|| Symbol {
+        kind: doc_node.kind,
+        fingerprint: vec![],
+      })
+      .fingerprint
+      .push(fingerprint);
+  }
+  symbols
+}
+
+/// The declaration of the node without its name, documentation and
+/// locations, which are expected to change when the symbol moves.
+fn fingerprint(doc_node: &DocNode) -> serde_json::Value {
+  fn strip(value: &mut serde_json::Value) {
+    match value {
+      serde_json::Value::Object(map) => {
+        map.retain(|key, _| {
+          !matches!(key.as_str(), "location" | "bodySpan" | "jsDoc")
+        });
+        map.values_mut().for_each(strip);
+      }
+      serde_json::Value::Array(values) => values.iter_mut().for_each(strip),
+      _ => {}
+    }
+  }
+
+  let mut value = serde_json::to_value(doc_node).unwrap();
+  if let serde_json::Value::Object(map) = &mut value {
+    map.remove("name");
+  }
+  strip(&mut value);
+  value
+}
+
+/// The position of the candidate with the lowest score, ignoring the ones
+/// without a score. The first one wins ties.
+fn best_match<T, S: Ord>(
+  candidates: &[T],
+  score: impl Fn(&T) -> Option<S>,
+) -> Option<usize> {
+  candidates
+    .iter()
+    .enumerate()
+    .filter_map(|(position, candidate)| Some((score(candidate)?, position)))
+    .min()
+    .map(|(_, position)| position)
+}
+
+/// The number of single character insertions, deletions and substitutions
+/// needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut row = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a_char) in a.chars().enumerate() {
+    let mut previous = row[0];
+    row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = previous + usize::from(a_char != *b_char);
+      previous = row[j + 1];
+      row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+    }
+  }
+  row[b.len()]
+}
@@ -4,6 +4,8 @@ use crate::html::util::NamespacedSymbols;
 use crate::html::GenerateCtx;
 use crate::html::ShortPath;
 use crate::html::UrlResolveKind;
+use crate::interface::InterfaceDef;
+use crate::DocNode;
 use crate::DocNodeKind;
 use deno_graph::ModuleSpecifier;
 use std::collections::HashMap;
@@ -15,6 +17,9 @@ pub struct RenderContext<'ctx> {
   pub ctx: &'ctx GenerateCtx<'ctx>,
   current_exports: NamespacedSymbols,
   current_imports: Rc<HashMap<String, String>>,
+  /// The doc nodes of the current module, searched for the interfaces of
+  /// the `implements` clauses.
+  current_doc_nodes: &'ctx [DocNode],
   current_type_params: Rc<HashSet<&'ctx str>>,
   current_resolve: UrlResolveKind<'ctx>,
   current_specifier: Option<&'ctx ModuleSpecifier>,
@@ -25,7 +30,7 @@ pub struct RenderContext<'ctx> {
 impl<'ctx> RenderContext<'ctx> {
   pub fn new(
     ctx: &'ctx GenerateCtx<'ctx>,
-    doc_nodes: &[crate::DocNode],
+    doc_nodes: &'ctx [DocNode],
     current_resolve: UrlResolveKind<'ctx>,
     current_specifier: Option<&'ctx ModuleSpecifier>,
   ) -> Self {
@@ -33,6 +38,7 @@ impl<'ctx> RenderContext<'ctx> {
       ctx,
       current_exports: NamespacedSymbols::new(doc_nodes),
       current_imports: Rc::new(get_current_imports(doc_nodes)),
+      current_doc_nodes: doc_nodes,
       current_type_params: Default::default(),
       current_resolve,
       current_specifier,
@@ -73,7 +79,30 @@ impl<'ctx> RenderContext<'ctx> {
     self.current_specifier
   }
 
+  /// Looks up an interface declared by the current module, where `name` may
+  /// be qualified by namespaces, searching from the current namespace
+  /// outwards.
+  pub fn lookup_interface(&self, name: &str) -> Option<&'ctx InterfaceDef> {
+    let name_parts = name.split('.').collect::<Vec<_>>();
+    (0..=self.namespace_parts.len()).rev().find_map(|len| {
+      let path = self.namespace_parts[..len]
+        .iter()
+        .chain(&name_parts)
+        .copied()
+        .collect::<Vec<_>>();
+      find_interface(self.current_doc_nodes, &path)
+    })
+  }
+
   pub fn lookup_symbol_href(&self, target_symbol: &str) -> Option<String> {
+    if let Some(href) = self
+      .ctx
+      .href_resolver
+      .resolve_symbol_link(target_symbol, self.get_current_resolve().get_file())
+    {
+      return Some(href);
+    }
+
     let target_symbol_parts = target_symbol
       .split('.')
       .map(String::from)
@@ -281,6 +310,24 @@ fn get_current_imports(
   imports
 }
 
+fn find_interface<'a>(
+  doc_nodes: &'a [DocNode],
+  path: &[&str],
+) -> Option<&'a InterfaceDef> {
+  let (name, rest) = path.split_first()?;
+  doc_nodes
+    .iter()
+    .filter(|doc_node| doc_node.get_name() == *name)
+    .find_map(|doc_node| {
+      if rest.is_empty() {
+        doc_node.interface_def.as_ref()
+      } else {
+        let namespace_def = doc_node.namespace_def.as_ref()?;
+        find_interface(&namespace_def.elements, rest)
+      }
+    })
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -324,9 +371,8 @@ mod test {
     }
   }
 
-  #[test]
-  fn lookup_symbol_href() {
-    let ctx = GenerateCtx {
+  fn test_ctx() -> GenerateCtx<'static> {
+    GenerateCtx {
       package_name: None,
       common_ancestor: None,
       main_entrypoint: None,
@@ -341,7 +387,22 @@ mod test {
       single_file_mode: false,
       sidebar_hide_all_symbols: false,
       sidebar_flatten_namespaces: false,
-    };
+      custom_css: None,
+      custom_head_html: None,
+      og_base_url: None,
+      og_image: None,
+      class_diagrams: false,
+      exact_optional_property_types: false,
+      union_break_threshold: 4,
+      check_implementations: false,
+      per_symbol_pages: true,
+      collapse_type_threshold: None,
+    }
+  }
+
+  #[test]
+  fn lookup_symbol_href() {
+    let ctx = test_ctx();
 
     let doc_nodes: Vec<DocNode> = vec![DocNode {
       kind: DocNodeKind::Import,
@@ -353,7 +414,12 @@ mod test {
         byte_index: 0,
       },
       declaration_kind: DeclarationKind::Private,
+      export_kind: Default::default(),
       js_doc: Default::default(),
+      visibility: Default::default(),
+      stability: Default::default(),
+      stability_source: None,
+      re_export_source: None,
       function_def: None,
       variable_def: None,
       enum_def: None,
@@ -386,4 +452,40 @@ mod test {
     );
     assert_eq!(render_ctx.lookup_symbol_href("foo").unwrap(), "b/foo");
   }
+
+  #[test]
+  fn render_function_type() {
+    let ctx = test_ctx();
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::Root, None);
+
+    let function_def = crate::function::FunctionDef {
+      def_name: None,
+      params: vec![crate::ParamDef {
+        pattern: crate::params::ParamPatternDef::Identifier {
+          name: "value".to_string(),
+          optional: false,
+        },
+        decorators: vec![],
+        ts_type: Some(crate::ts_type::TsTypeDef::keyword("string")),
+        default_value_text: None,
+      }],
+      this_type: None,
+      return_type: Some(crate::ts_type::TsTypeDef::keyword("number")),
+      return_type_doc: None,
+      has_body: true,
+      body_span: None,
+      is_overload: false,
+      is_async: false,
+      is_generator: false,
+      type_params: vec![],
+      decorators: vec![],
+    };
+    let ts_type = crate::ts_type::TsTypeDef::from(&function_def);
+    let html = crate::html::types::render_type_def(&render_ctx, &ts_type);
+    let text = html_escape::decode_html_entities(
+      &regex::Regex::new("<[^>]*>").unwrap().replace_all(&html, ""),
+    )
+    .to_string();
+    assert_eq!(text, "(value: string) => number");
+  }
 }
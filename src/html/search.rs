@@ -45,7 +45,7 @@ fn doc_node_into_search_index_nodes(
     format!("{}.{}", doc_nodes[0].ns_qualifiers.join("."), name)
   };
 
-  if !matches!(doc_nodes[0].doc_node.kind, DocNodeKind::Namespace) {
+  let Some(ns_def) = doc_nodes[0].doc_node.namespace_def.as_ref() else {
     let mut location = doc_nodes[0].doc_node.location.clone();
     let location_url = ModuleSpecifier::parse(&location.filename).unwrap();
     location.filename = if ctx
@@ -71,9 +71,8 @@ fn doc_node_into_search_index_nodes(
       declaration_kind: doc_nodes[0].doc_node.declaration_kind,
       deprecated,
     }];
-  }
+  };
 
-  let ns_def = doc_nodes[0].doc_node.namespace_def.as_ref().unwrap();
   let mut nodes = Vec::with_capacity(1 + ns_def.elements.len());
   let ns_name = doc_nodes[0].doc_node.get_name().to_string();
 
@@ -154,7 +153,7 @@ fn doc_node_into_search_index_nodes(
       deprecated,
     });
 
-    if el_nodes[0].kind == DocNodeKind::Namespace {
+    if el_nodes[0].namespace_def.is_some() {
       nodes.extend_from_slice(&doc_node_into_search_index_nodes(
         ctx,
         el_nodes[0].get_name(),
@@ -217,6 +216,7 @@ pub fn generate_search_index(
   });
 
   let search_index = json!({
+    "perSymbolPages": ctx.per_symbol_pages,
     "nodes": doc_nodes
   });
 
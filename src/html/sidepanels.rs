@@ -46,6 +46,7 @@ struct SidepanelPartitionCtx {
 pub struct SidepanelCtx {
   package_name: Option<String>,
   partitions: Vec<SidepanelPartitionCtx>,
+  related: Vec<SidepanelPartitionSymbolCtx>,
 }
 
 impl SidepanelCtx {
@@ -54,6 +55,7 @@ impl SidepanelCtx {
     partitions: &IndexMap<String, Vec<DocNodeWithContext>>,
     file: &ShortPath,
     symbol: &str,
+    related_nodes: &[&DocNode],
   ) -> Self {
     let partitions = partitions
       .into_iter()
@@ -91,9 +93,35 @@ impl SidepanelCtx {
       })
       .collect();
 
+    let mut grouped_related_nodes = IndexMap::new();
+    for node in related_nodes {
+      let entry = grouped_related_nodes
+        .entry(node.get_name())
+        .or_insert(vec![]);
+      entry.push(*node);
+    }
+
+    let related = grouped_related_nodes
+      .into_iter()
+      .map(|(node_name, nodes)| SidepanelPartitionSymbolCtx {
+        kind: nodes.iter().map(|node| node.kind.into()).collect(),
+        name: node_name.to_string(),
+        href: ctx.href_resolver.resolve_path(
+          UrlResolveKind::Symbol { file, symbol },
+          UrlResolveKind::Symbol {
+            file,
+            symbol: node_name,
+          },
+        ),
+        active: false,
+        deprecated: super::util::all_deprecated(&nodes),
+      })
+      .collect();
+
     Self {
       package_name: ctx.package_name.clone(),
       partitions,
+      related,
     }
   }
 }
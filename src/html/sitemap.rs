@@ -0,0 +1,178 @@
+use super::partition::partition_nodes_by_name;
+use super::symbol_page_url;
+use super::util::absolute_page_url;
+use super::GenerateCtx;
+use super::ShortPath;
+use crate::DocNode;
+use deno_ast::ModuleSpecifier;
+use indexmap::IndexMap;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+pub const SITEMAP_FILENAME: &str = "sitemap.xml";
+
+/// Generates an XML sitemap listing the URLs of all pages that
+/// [`super::generate`] emits for the given doc nodes, so that search engines
+/// can index them. The nodes are documented as the modules of their location,
+/// so the ones re-exported by another module are listed under the module
+/// that declares them.
+pub fn generate_sitemap_xml(base_url: &str, nodes: &[DocNode]) -> String {
+  let mut doc_nodes_by_url = IndexMap::<ModuleSpecifier, Vec<DocNode>>::new();
+  for node in nodes {
+    if let Ok(specifier) = ModuleSpecifier::parse(&node.location.filename) {
+      doc_nodes_by_url
+        .entry(specifier)
+        .or_default()
+        .push(node.clone());
+    }
+  }
+
+  let common_ancestor =
+    super::find_common_ancestor(doc_nodes_by_url.keys(), true);
+  sitemap_xml(base_url, &doc_nodes_by_url, true, true, |specifier| {
+    super::url_to_short_path(common_ancestor.as_deref(), specifier)
+  })
+}
+
+/// The sitemap of the pages generated with `ctx`, where the all symbols page
+/// and the symbol pages are only listed when they were generated.
+pub(crate) fn generate_sitemap_xml_for_pages(
+  ctx: &GenerateCtx,
+  base_url: &str,
+  doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<DocNode>>,
+  has_all_symbols: bool,
+) -> String {
+  sitemap_xml(
+    base_url,
+    doc_nodes_by_url,
+    has_all_symbols,
+    ctx.per_symbol_pages,
+    |specifier| ctx.url_to_short_path(specifier),
+  )
+}
+
+fn sitemap_xml(
+  base_url: &str,
+  doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<DocNode>>,
+  has_all_symbols: bool,
+  has_symbol_pages: bool,
+  short_path: impl Fn(&ModuleSpecifier) -> ShortPath,
+) -> String {
+  let mut xml = String::from(concat!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    "\n",
+    r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+    "\n",
+  ));
+
+  push_url(&mut xml, base_url, "./index.html", None);
+  if has_all_symbols {
+    push_url(&mut xml, base_url, "./all_symbols.html", None);
+  }
+
+  for (specifier, doc_nodes) in doc_nodes_by_url {
+    let short_path = short_path(specifier);
+    let last_modified = get_last_modified(specifier);
+
+    push_url(
+      &mut xml,
+      base_url,
+      &format!("{}/~/index.html", short_path.as_str()),
+      last_modified.as_deref(),
+    );
+
+    if !has_symbol_pages {
+      continue;
+    }
+
+    let mut names = vec![];
+    collect_symbol_page_names(doc_nodes, None, &mut names);
+    for name in names {
+      push_url(
+        &mut xml,
+        base_url,
+        &symbol_page_url(&short_path, &name).to_string_lossy(),
+        last_modified.as_deref(),
+      );
+    }
+  }
+
+  xml.push_str("</urlset>\n");
+  xml
+}
+
+/// Collects the names of all symbols that get a page, including the ones
+/// nested in namespaces.
+fn collect_symbol_page_names(
+  doc_nodes: &[DocNode],
+  namespace: Option<&str>,
+  names: &mut Vec<String>,
+) {
+  for (name, doc_nodes) in partition_nodes_by_name(doc_nodes) {
+    let name = match namespace {
+      Some(namespace) => format!("{namespace}.{name}"),
+      None => name,
+    };
+    names.push(name.clone());
+
+    if let Some(namespace_def) = doc_nodes
+      .iter()
+      .find_map(|doc_node| doc_node.namespace_def.as_ref())
+    {
+      collect_symbol_page_names(&namespace_def.elements, Some(&name), names);
+    }
+  }
+}
+
+fn push_url(
+  xml: &mut String,
+  base_url: &str,
+  file_name: &str,
+  last_modified: Option<&str>,
+) {
+  let loc = absolute_page_url(base_url, file_name);
+  xml.push_str("  <url>\n");
+  xml.push_str(&format!(
+    "    <loc>{}</loc>\n",
+    html_escape::encode_text(&loc)
+  ));
+  if let Some(last_modified) = last_modified {
+    xml.push_str(&format!("    <lastmod>{last_modified}</lastmod>\n"));
+  }
+  xml.push_str("    <changefreq>monthly</changefreq>\n");
+  xml.push_str("  </url>\n");
+}
+
+/// Returns the modification date of a local source file in the `YYYY-MM-DD`
+/// format used by sitemaps.
+fn get_last_modified(specifier: &ModuleSpecifier) -> Option<String> {
+  if specifier.scheme() != "file" {
+    return None;
+  }
+
+  let modified = std::fs::metadata(specifier.to_file_path().ok()?)
+    .and_then(|metadata| metadata.modified())
+    .ok()?;
+
+  Some(format_date(modified))
+}
+
+fn format_date(time: SystemTime) -> String {
+  let days = time
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs() / 86400)
+    .unwrap_or_default() as i64;
+
+  // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+  let z = days + 719468;
+  let era = z.div_euclid(146097);
+  let doe = z.rem_euclid(146097);
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let day = doy - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = yoe + era * 400 + i64::from(month <= 2);
+
+  format!("{year:04}-{month:02}-{day:02}")
+}
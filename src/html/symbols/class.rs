@@ -1,3 +1,4 @@
+use crate::class::ClassDef;
 use crate::class::ClassMethodDef;
 use crate::class::ClassPropertyDef;
 use crate::html::parameters::render_params;
@@ -10,8 +11,6 @@ use serde::Serialize;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
-// TODO: overrides
-
 pub(crate) fn render_class(
   ctx: &RenderContext,
   doc_node: &crate::DocNode,
@@ -34,7 +33,7 @@ pub(crate) fn render_class(
   let mut sections = vec![];
 
   if let Some(constructors) =
-    render_constructors(ctx, &class_def.constructors, doc_node.get_name())
+    render_constructors(ctx, class_def, doc_node.get_name())
   {
     sections.push(constructors);
   }
@@ -93,20 +92,72 @@ pub(crate) fn render_class(
     })
   }
 
+  if let Some(body_source) = &class_def.body_source {
+    sections.push(SectionCtx {
+      title: "Source",
+      content: SectionContentCtx::Source(vec![SourceCtx::new(
+        ctx,
+        body_source,
+      )]),
+    });
+  }
+
   sections
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct SourceCtx {
+  id: String,
+  anchor: AnchorCtx,
+  html: String,
+}
+
+impl SourceCtx {
+  fn new(ctx: &RenderContext, source: &str) -> Self {
+    // the fence has to be longer than any backtick run in the source, eg. in
+    // template literals
+    let mut longest_run = 0;
+    let mut run = 0;
+    for char in source.chars() {
+      if char == '`' {
+        run += 1;
+        longest_run = longest_run.max(run);
+      } else {
+        run = 0;
+      }
+    }
+    let fence = "`".repeat((longest_run + 1).max(3));
+
+    SourceCtx {
+      id: "source".to_string(),
+      anchor: AnchorCtx {
+        id: "source".to_string(),
+      },
+      html: crate::html::jsdoc::render_markdown(
+        ctx,
+        &format!("{fence}ts\n{source}\n{fence}"),
+      ),
+    }
+  }
+}
+
+/// The overloads of the constructor, followed by its implementation.
 fn render_constructors(
   ctx: &RenderContext,
-  constructors: &[crate::class::ClassConstructorDef],
+  class_def: &ClassDef,
   name: &str,
 ) -> Option<SectionCtx> {
+  let constructors = class_def
+    .constructor_overloads
+    .iter()
+    .chain(&class_def.constructors)
+    .collect::<Vec<_>>();
   if constructors.is_empty() {
     return None;
   }
 
   let items = constructors
-    .iter()
+    .into_iter()
     .enumerate()
     .map(|(i, constructor)| {
       let id = name_to_id("constructor", &i.to_string());
@@ -119,12 +170,17 @@ fn render_constructors(
 
       let params = render_params(ctx, &params);
 
+      let mut tags = HashSet::from([Tag::New]);
+      if let Some(tag) = Tag::from_accessibility(constructor.accessibility) {
+        tags.insert(tag);
+      }
+
       DocEntryCtx::new(
         ctx,
         &id,
         name,
         &format!("({params})"),
-        HashSet::from([Tag::New]),
+        tags,
         constructor.js_doc.doc.as_deref(),
         &constructor.location,
       )
@@ -354,15 +410,27 @@ fn render_class_accessor(
       })
     })
     .map_or_else(String::new, |ts_type| render_type_def_colon(ctx, ts_type));
-  let js_doc = getter_or_setter.js_doc.doc.as_deref();
+  let js_doc = with_overrides_note(
+    ctx,
+    getter_or_setter.js_doc.doc.as_deref(),
+    getter_or_setter.overrides.as_deref(),
+    "accessor",
+  );
 
   let mut tags = Tag::from_js_doc(&getter_or_setter.js_doc);
   if let Some(tag) = Tag::from_accessibility(getter_or_setter.accessibility) {
     tags.insert(tag);
   }
-  if getter_or_setter.is_abstract {
+  if getter
+    .into_iter()
+    .chain(setter)
+    .any(|accessor| accessor.is_abstract)
+  {
     tags.insert(Tag::Abstract);
   }
+  if getter_or_setter.is_override {
+    tags.insert(Tag::Override);
+  }
   if getter.is_some() && setter.is_none() {
     tags.insert(Tag::Readonly);
   } else if getter.is_none() && setter.is_some() {
@@ -375,7 +443,7 @@ fn render_class_accessor(
     name,
     &ts_type,
     tags,
-    js_doc,
+    js_doc.as_deref(),
     &getter_or_setter.location,
   )
 }
@@ -398,21 +466,102 @@ fn render_class_method(
   if method.is_abstract {
     tags.insert(Tag::Abstract);
   }
+  if method.is_override {
+    tags.insert(Tag::Override);
+  }
   if method.optional {
     tags.insert(Tag::Abstract);
   }
 
+  let notes = method
+    .overrides
+    .iter()
+    .map(|overrides| render_overrides_note(ctx, overrides, "method"))
+    .chain(
+      (!method.overridden_by.is_empty())
+        .then(|| render_overridden_by_note(ctx, method)),
+    )
+    .collect::<Vec<_>>();
+  let js_doc = if notes.is_empty() {
+    method.js_doc.doc.clone()
+  } else {
+    let notes = notes.join("\n\n");
+    Some(match &method.js_doc.doc {
+      Some(doc) => format!("{doc}\n\n{notes}"),
+      None => notes,
+    })
+  };
+
   Some(DocEntryCtx::new(
     ctx,
     &id,
     &method.name,
     &super::function::render_function_summary(&method.function_def, ctx),
     tags,
-    method.js_doc.doc.as_deref(),
+    js_doc.as_deref(),
     &method.location,
   ))
 }
 
+/// Markdown for a note which links to the member of the parent class that a
+/// member overrides, which is assumed to be of the same kind as the
+/// overriding member, eg. a `"method"` or a `"property"`.
+fn render_overrides_note(
+  ctx: &RenderContext,
+  overrides: &str,
+  kind: &str,
+) -> String {
+  let href = overrides.rsplit_once('.').and_then(|(parent, member)| {
+    let href = ctx.lookup_symbol_href(parent)?;
+    let id = match kind {
+      "method" => name_to_id(kind, &format!("{member}_0")),
+      _ => name_to_id(kind, member),
+    };
+    Some(format!("{href}#{id}"))
+  });
+
+  match href {
+    Some(href) => format!("Overrides [`{overrides}`]({href})"),
+    None => format!("Overrides `{overrides}`"),
+  }
+}
+
+/// Appends the note of [`render_overrides_note`] to the doc of a member.
+fn with_overrides_note(
+  ctx: &RenderContext,
+  doc: Option<&str>,
+  overrides: Option<&str>,
+  kind: &str,
+) -> Option<String> {
+  let Some(overrides) = overrides else {
+    return doc.map(str::to_string);
+  };
+  let note = render_overrides_note(ctx, overrides, kind);
+  Some(match doc {
+    Some(doc) => format!("{doc}\n\n{note}"),
+    None => note,
+  })
+}
+
+/// Markdown for a note which links to the methods of the subclasses that
+/// override a method.
+fn render_overridden_by_note(
+  ctx: &RenderContext,
+  method: &ClassMethodDef,
+) -> String {
+  let id = name_to_id("method", &format!("{}_0", method.name));
+  let subclasses = method
+    .overridden_by
+    .iter()
+    .map(|subclass| match ctx.lookup_symbol_href(subclass) {
+      Some(href) => format!("[`{subclass}`]({href}#{id})"),
+      None => format!("`{subclass}`"),
+    })
+    .collect::<Vec<_>>();
+
+  format!("Overridden by: {}", subclasses.join(", "))
+}
+
 fn render_class_property(
   ctx: &RenderContext,
   property: &ClassPropertyDef,
@@ -426,11 +575,20 @@ fn render_class_property(
   if property.is_abstract {
     tags.insert(Tag::Abstract);
   }
+  if property.is_override {
+    tags.insert(Tag::Override);
+  }
+  if property.is_declare {
+    tags.insert(Tag::Other("declare".to_string()));
+  }
   if property.readonly {
     tags.insert(Tag::Readonly);
   }
   if property.optional {
-    tags.insert(Tag::Abstract);
+    tags.insert(Tag::Optional);
+    if ctx.ctx.exact_optional_property_types {
+      tags.insert(Tag::Other("exact optional".to_string()));
+    }
   }
 
   let ts_type = property
@@ -445,7 +603,13 @@ fn render_class_property(
     &property.name,
     &ts_type,
     tags,
-    property.js_doc.doc.as_deref(),
+    with_overrides_note(
+      ctx,
+      property.js_doc.doc.as_deref(),
+      property.overrides.as_deref(),
+      "property",
+    )
+    .as_deref(),
     &property.location,
   )
 }
@@ -2,7 +2,7 @@ use super::SymbolContentCtx;
 use crate::function::FunctionDef;
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
-use crate::html::types::render_type_def;
+use crate::html::types::render_type_def_collapsible;
 use crate::html::types::render_type_def_colon;
 use crate::html::types::render_type_params;
 use crate::html::types::type_params_summary;
@@ -135,7 +135,7 @@ pub(crate) fn render_function_summary(
   format!(
     "{}({}){return_type}",
     type_params_summary(render_ctx, &function_def.type_params),
-    render_params(render_ctx, &function_def.params)
+    render_params(render_ctx, &function_def.signature_params())
   )
 }
 
@@ -186,9 +186,11 @@ fn render_single_function(
     .map(|(i, param)| {
       let (name, str_name) = crate::html::parameters::param_name(param, i);
       let id = name_to_id(overload_id, &format!("parameters_{str_name}"));
+      let param_doc = crate::html::parameters::param_doc_name(param)
+        .and_then(|doc_name| param_docs.get(doc_name));
 
       let (mut default, optional) = if let Some((_doc, optional, default)) =
-        param_docs.get(name.as_str())
+        param_doc
       {
         ((**default).to_owned(), *optional)
       } else {
@@ -231,9 +233,7 @@ fn render_single_function(
         &name,
         &ts_type,
         tags,
-        param_docs
-          .get(name.as_str())
-          .and_then(|(doc, _, _)| doc.as_deref()),
+        param_doc.and_then(|(doc, _, _)| doc.as_deref()),
         &doc_node.location,
       )
     })
@@ -249,6 +249,12 @@ fn render_single_function(
     sections.push(examples);
   }
 
+  if let Some(events) =
+    crate::html::jsdoc::jsdoc_events(ctx, &doc_node.js_doc, &doc_node.location)
+  {
+    sections.push(events);
+  }
+
   if let Some(type_params) =
     render_type_params(ctx, &function_def.type_params, &doc_node.location)
   {
@@ -299,7 +305,7 @@ fn render_function_return_type(
     render_ctx,
     &id,
     "",
-    &render_type_def(render_ctx, return_type),
+    &render_type_def_collapsible(render_ctx, return_type),
     HashSet::new(),
     return_type_doc,
     &doc_node.location,
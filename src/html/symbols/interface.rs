@@ -172,16 +172,24 @@ fn render_properties(
       }
       if property.optional {
         tags.insert(Tag::Optional);
+        if ctx.ctx.exact_optional_property_types {
+          tags.insert(Tag::Other("exact optional".to_string()));
+        }
+      }
+
+      let mut name = if property.computed {
+        format!("[{}]", property.name)
+      } else {
+        property.name.clone()
+      };
+      if property.optional {
+        name.push('?');
       }
 
       DocEntryCtx::new(
         ctx,
         &id,
-        &if property.computed {
-          format!("[{}]", property.name)
-        } else {
-          property.name.clone()
-        },
+        &name,
         &format!("{ts_type}{default_value}"),
         tags,
         property.js_doc.doc.as_deref(),
@@ -204,13 +212,15 @@ fn render_methods(
     return None;
   }
 
+  // every overload of a method gets its own entry
   let items = methods
     .iter()
+    .flat_map(|method| method.signatures())
     .enumerate()
     .map(|(i, method)| {
       let id = name_to_id("call_signature", &format!("{}_{i}", method.name));
 
-      let name = if method.name == "new" {
+      let mut name = if method.name == "new" {
         "<span>new</span>".to_string()
       } else if method.computed {
         format!("[{}]", method.name)
@@ -230,6 +240,7 @@ fn render_methods(
       }*/
       if method.optional {
         tags.insert(Tag::Optional);
+        name.push('?');
       }
 
       DocEntryCtx::new(
@@ -239,7 +250,7 @@ fn render_methods(
         &format!(
           "{}({}){return_type}",
           type_params_summary(ctx, &method.type_params),
-          render_params(ctx, &method.params)
+          render_params(ctx, &method.signature_params())
         ),
         tags,
         method.js_doc.doc.as_deref(),
@@ -1,3 +1,4 @@
+use crate::class::ClassDef;
 use crate::html::types::render_type_def;
 use crate::html::usage::UsagesCtx;
 use crate::html::util::SectionCtx;
@@ -5,6 +6,7 @@ use crate::html::util::Tag;
 use crate::html::DocNodeWithContext;
 use crate::html::RenderContext;
 use crate::js_doc::JsDocTag;
+use crate::node::Visibility;
 use crate::DocNode;
 use crate::DocNodeKind;
 use indexmap::IndexMap;
@@ -22,6 +24,11 @@ mod variable;
 #[derive(Debug, Serialize, Clone)]
 struct SymbolCtx {
   kind: super::util::DocNodeKindCtx,
+  /// The type parameters shown after the name of generic classes.
+  type_params: Option<String>,
+  /// The decorators of classes, eg. `@Component({ selector: "app" })`,
+  /// shown above the name.
+  decorators: Vec<String>,
   tags: HashSet<Tag>,
   subtitle: Option<DocBlockSubtitleCtx>,
   content: Vec<SymbolInnerCtx>,
@@ -91,6 +98,37 @@ impl SymbolGroupCtx {
           tags.insert(Tag::Permissions(permissions));
         }
 
+        for doc_node in doc_nodes {
+          tags.extend(
+            doc_node
+              .tags()
+              .into_iter()
+              .map(|tag| Tag::Other(tag.to_string())),
+          );
+        }
+
+        if doc_nodes.iter().any(|node| {
+          node
+            .variable_def
+            .as_ref()
+            .is_some_and(|variable_def| variable_def.deploy_handler)
+        }) {
+          tags.insert(Tag::Other("deploy handler".to_string()));
+        }
+
+        match doc_nodes[0].visibility {
+          Visibility::Public => {}
+          Visibility::Protected => {
+            tags.insert(Tag::Protected);
+          }
+          Visibility::Private => {
+            tags.insert(Tag::Private);
+          }
+          Visibility::PackagePrivate => {
+            tags.insert(Tag::Other("package private".to_string()));
+          }
+        }
+
         let deprecated = if all_deprecated
           && !(doc_nodes[0].kind == DocNodeKind::Function
             && doc_nodes.len() == 1)
@@ -113,9 +151,46 @@ impl SymbolGroupCtx {
           None
         };
 
+        let type_params = doc_nodes[0]
+          .class_def
+          .as_ref()
+          .filter(|class_def| !class_def.type_params.is_empty())
+          .map(|class_def| {
+            let current_type_params = class_def
+              .type_params
+              .iter()
+              .map(|def| def.name.as_str())
+              .collect::<HashSet<&str>>();
+            super::types::type_params_summary(
+              &ctx.with_current_type_params(current_type_params),
+              &class_def.type_params,
+            )
+          });
+
+        let decorators = doc_nodes[0]
+          .class_def
+          .as_ref()
+          .map(|class_def| {
+            class_def
+              .decorators
+              .iter()
+              .map(|decorator| {
+                let args = if decorator.is_call {
+                  format!("({})", decorator.args.join(", "))
+                } else {
+                  String::new()
+                };
+                format!("@{}{args}", decorator.name)
+              })
+              .collect()
+          })
+          .unwrap_or_default();
+
         SymbolCtx {
           tags,
           kind: doc_nodes[0].kind.into(),
+          type_params,
+          decorators,
           subtitle: DocBlockSubtitleCtx::new(ctx, &doc_nodes[0]),
           content: SymbolInnerCtx::new(ctx, doc_nodes, name),
           source_href: ctx
@@ -137,26 +212,80 @@ impl SymbolGroupCtx {
 
 #[derive(Debug, Serialize, Clone)]
 struct DocBlockClassSubtitleExtendsCtx {
+  /// The rendered mixins applied to the extended class, outermost first.
+  mixins: Vec<String>,
   href: Option<String>,
   symbol: String,
   type_args: String,
 }
 
+#[derive(Debug, Serialize, Clone)]
+struct DocBlockClassSubtitleImplementsCheckCtx {
+  fully_implemented: bool,
+  title: String,
+}
+
+impl DocBlockClassSubtitleImplementsCheckCtx {
+  /// The check of the `implements` clause of the class, if all the
+  /// interfaces are declared by the current module.
+  fn new(ctx: &RenderContext, class_def: &ClassDef) -> Option<Self> {
+    let interfaces = class_def
+      .implements
+      .iter()
+      .map(|implements| {
+        let type_ref = implements.type_ref.as_ref()?;
+        ctx.lookup_interface(&type_ref.type_name)
+      })
+      .collect::<Option<Vec<_>>>()?;
+    let result = class_def.check_implements(&interfaces);
+
+    let title = if result.fully_implemented {
+      "Implements all the required members".to_string()
+    } else {
+      format!("Missing: {}", result.missing_members.join(", "))
+    };
+
+    Some(Self {
+      fully_implemented: result.fully_implemented,
+      title,
+    })
+  }
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "kind", content = "value")]
 enum DocBlockSubtitleCtx {
   Class {
     implements: Option<Vec<String>>,
+    implements_check: Option<DocBlockClassSubtitleImplementsCheckCtx>,
     extends: Option<DocBlockClassSubtitleExtendsCtx>,
   },
   Interface {
     extends: Vec<String>,
   },
+  Namespace {
+    /// The pattern of a wildcard module declaration, or `None` for a
+    /// `declare global` block.
+    wildcard_module: Option<String>,
+  },
 }
 
 impl DocBlockSubtitleCtx {
   fn new(ctx: &RenderContext, doc_node: &DocNode) -> Option<Self> {
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      if namespace_def.is_wildcard_module {
+        return Some(DocBlockSubtitleCtx::Namespace {
+          wildcard_module: Some(doc_node.name.clone()),
+        });
+      }
+      if namespace_def.is_global_augmentation {
+        return Some(DocBlockSubtitleCtx::Namespace {
+          wildcard_module: None,
+        });
+      }
+    }
+
     if matches!(
       doc_node.kind,
       DocNodeKind::Function
@@ -180,6 +309,7 @@ impl DocBlockSubtitleCtx {
       let ctx = &ctx.with_current_type_params(current_type_params);
 
       let mut class_implements = None;
+      let mut implements_check = None;
       let mut class_extends = None;
 
       if !class_def.implements.is_empty() {
@@ -190,10 +320,20 @@ impl DocBlockSubtitleCtx {
           .collect::<Vec<String>>();
 
         class_implements = Some(impls);
+
+        if ctx.ctx.check_implementations {
+          implements_check =
+            DocBlockClassSubtitleImplementsCheckCtx::new(ctx, class_def);
+        }
       }
 
       if let Some(extends) = class_def.extends.as_ref() {
         class_extends = Some(DocBlockClassSubtitleExtendsCtx {
+          mixins: class_def
+            .mixins
+            .iter()
+            .map(|mixin| render_type_def(ctx, mixin))
+            .collect(),
           href: ctx.lookup_symbol_href(extends),
           symbol: extends.to_owned(),
           type_args: super::types::type_arguments(
@@ -205,6 +345,7 @@ impl DocBlockSubtitleCtx {
 
       return Some(DocBlockSubtitleCtx::Class {
         implements: class_implements,
+        implements_check,
         extends: class_extends,
       });
     }
@@ -267,33 +408,45 @@ impl SymbolInnerCtx {
         DocNodeKind::Enum => r#enum::render_enum(ctx, doc_node),
         DocNodeKind::Interface => interface::render_interface(ctx, doc_node),
         DocNodeKind::TypeAlias => type_alias::render_type_alias(ctx, doc_node),
+        DocNodeKind::Namespace => vec![],
+        DocNodeKind::ModuleDoc | DocNodeKind::Import => unreachable!(),
+      };
 
-        DocNodeKind::Namespace => {
-          let namespace_def = doc_node.namespace_def.as_ref().unwrap();
-          let namespace_nodes = namespace_def
-            .elements
-            .iter()
-            .map(|node| DocNodeWithContext {
-              doc_node: node,
-              ns_qualifiers: std::rc::Rc::new(vec![]),
-              origin: None,
-            })
-            .collect::<Vec<_>>();
+      // namespaces, including the ones merged with an enum or an interface
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        let namespace_nodes = namespace_def
+          .elements
+          .iter()
+          .map(|node| DocNodeWithContext {
+            doc_node: node,
+            ns_qualifiers: std::rc::Rc::new(vec![]),
+            origin: None,
+          })
+          .collect::<Vec<_>>();
 
-          let partitions =
-            super::partition::partition_nodes_by_kind(&namespace_nodes, false);
+        let partitions =
+          super::partition::partition_nodes_by_kind(&namespace_nodes, false);
 
-          let ns_parts = name.split('.').collect::<Vec<&str>>();
+        let ns_parts = name.split('.').collect::<Vec<&str>>();
 
-          namespace::render_namespace(&ctx.with_namespace(ns_parts), partitions)
-        }
-        DocNodeKind::ModuleDoc | DocNodeKind::Import => unreachable!(),
-      };
+        sections.extend(namespace::render_namespace(
+          &ctx.with_namespace(ns_parts),
+          partitions,
+        ));
+      }
 
       let docs =
         crate::html::jsdoc::jsdoc_body_to_html(ctx, &doc_node.js_doc, false);
       let examples = crate::html::jsdoc::jsdoc_examples(ctx, &doc_node.js_doc);
 
+      if let Some(events) = crate::html::jsdoc::jsdoc_events(
+        ctx,
+        &doc_node.js_doc,
+        &doc_node.location,
+      ) {
+        sections.insert(0, events);
+      }
+
       if let Some(examples) = examples {
         sections.insert(0, examples);
       }
@@ -1,5 +1,5 @@
 use crate::html::render_context::RenderContext;
-use crate::html::types::render_type_def;
+use crate::html::types::render_type_def_collapsible;
 use crate::html::util::*;
 use std::collections::HashSet;
 
@@ -36,7 +36,7 @@ pub(crate) fn render_type_alias(
       ctx,
       &id,
       "",
-      &render_type_def(ctx, &type_alias_def.ts_type),
+      &render_type_def_collapsible(ctx, &type_alias_def.ts_type),
       HashSet::new(),
       None,
       &doc_node.location,
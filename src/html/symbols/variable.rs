@@ -1,5 +1,5 @@
 use crate::html::render_context::RenderContext;
-use crate::html::types::render_type_def;
+use crate::html::types::render_type_def_collapsible;
 use crate::html::util::*;
 use std::collections::HashSet;
 
@@ -21,7 +21,7 @@ pub(crate) fn render_variable(
       ctx,
       &id,
       "",
-      &render_type_def(ctx, variable_def.ts_type.as_ref().unwrap()),
+      &render_type_def_collapsible(ctx, variable_def.ts_type.as_ref().unwrap()),
       HashSet::new(),
       None,
       &doc_node.location,
@@ -0,0 +1,43 @@
+/// The color theme used for syntax highlighted code blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxTheme {
+  /// The theme that is part of the default stylesheet.
+  #[default]
+  Default,
+  GithubDark,
+  GithubLight,
+  Dracula,
+  Nord,
+}
+
+impl SyntaxTheme {
+  /// CSS that overrides the highlighting colors of the default stylesheet.
+  /// It is appended to the generated stylesheet by [`super::generate`], but
+  /// needs to be served manually when the stylesheet is served separately.
+  pub fn css(&self) -> &'static str {
+    match self {
+      SyntaxTheme::Default => "",
+      SyntaxTheme::GithubDark => {
+        include_str!("./templates/syntax_themes/github_dark.css")
+      }
+      SyntaxTheme::GithubLight => {
+        include_str!("./templates/syntax_themes/github_light.css")
+      }
+      SyntaxTheme::Dracula => {
+        include_str!("./templates/syntax_themes/dracula.css")
+      }
+      SyntaxTheme::Nord => include_str!("./templates/syntax_themes/nord.css"),
+    }
+  }
+
+  /// The closest theme bundled with syntect, as syntect inlines the colors
+  /// instead of relying on the stylesheet.
+  #[cfg(feature = "syntect")]
+  pub(crate) fn syntect_theme_name(&self) -> &'static str {
+    match self {
+      SyntaxTheme::Default | SyntaxTheme::GithubLight => "InspiredGitHub",
+      SyntaxTheme::GithubDark | SyntaxTheme::Nord => "base16-ocean.dark",
+      SyntaxTheme::Dracula => "base16-eighties.dark",
+    }
+  }
+}
@@ -3,12 +3,9 @@ const Fuse = window.Fuse;
 const searchInput = document.querySelector("#searchbar");
 const mainContentTags = document.getElementsByTagName("main");
 const searchResultsDiv = document.querySelector("#searchResults");
-const currentFile =
-  document.querySelector("meta[name='doc-current-file']").attributes
+const pathToRoot =
+  document.querySelector("meta[name='doc-root']").attributes
     .getNamedItem("content").value;
-const pathToRoot = "../".repeat(
-  currentFile ? (currentFile.split("/").length + 1) : 0,
-);
 searchInput.removeAttribute("style");
 
 const SEARCH_INDEX = window.DENO_DOC_SEARCH_INDEX;
@@ -111,6 +108,14 @@ function showSearchResults() {
   searchResultsDiv.style.display = "block";
 }
 
+function symbolHref(result) {
+  if (SEARCH_INDEX.perSymbolPages) {
+    return `${result.file}/~/${result.name}.html`;
+  } else {
+    return `${result.file}/~/index.html#symbol_${result.name}`;
+  }
+}
+
 function renderResults(results) {
   if (results.length === 0) {
     searchResultsDiv.innerHTML = `<span>No result</span>`;
@@ -128,7 +133,7 @@ function renderResults(results) {
     }).join("");
 
     html += `<li class="block">
-<a href="${pathToRoot}${result.file}/~/${result.name}.html" class="flex rounded-lg gap-4 items-center justify-between py-2 px-3 hover:bg-stone-100">
+<a href="${pathToRoot}${symbolHref(result)}" class="flex rounded-lg gap-4 items-center justify-between py-2 px-3 hover:bg-stone-100">
     <div class="flex items-center gap-2.5">
       <div class="flex justify-end compound_types w-[2.125rem] shrink-0">
         ${kind}
@@ -13,9 +13,29 @@ pub(crate) fn render_type_def_colon(
   ctx: &RenderContext,
   def: &crate::ts_type::TsTypeDef,
 ) -> String {
-  format!("<span>: {}</span>", render_type_def(ctx, def))
+  format!("<span>: {}</span>", render_type_def_collapsible(ctx, def))
 }
 
+/// Renders a type inline, or as a collapsed block if it is more complex than
+/// the `collapse_type_threshold` option allows.
+pub(crate) fn render_type_def_collapsible(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+) -> String {
+  let html = render_type_def(ctx, def);
+  match ctx.ctx.collapse_type_threshold {
+    Some(threshold) if def.complexity_score() > threshold => format!(
+      r#"<details class="inline-block"><summary class="list-none inline cursor-pointer" title="Show the type">…</summary>{html}</details>"#
+    ),
+    _ => html,
+  }
+}
+
+/// The documentation of the utility types of TypeScript, which the references
+/// to them link to, unless they resolve to a symbol.
+const UTILITY_TYPES_HREF: &str =
+  "https://www.typescriptlang.org/docs/handbook/utility-types.html";
+
 pub(crate) fn render_type_def(
   ctx: &RenderContext,
   def: &crate::ts_type::TsTypeDef,
@@ -68,7 +88,11 @@ pub(crate) fn render_type_def(
           name_to_id("type_param", &type_ref.type_name)
         ))
       } else {
-        ctx.lookup_symbol_href(&type_ref.type_name)
+        ctx.lookup_symbol_href(&type_ref.type_name).or_else(|| {
+          type_ref
+            .is_utility_type()
+            .then(|| UTILITY_TYPES_HREF.to_string())
+        })
       };
 
       let name = if let Some(href) = href {
@@ -95,10 +119,24 @@ pub(crate) fn render_type_def(
       )
     }
     TsTypeDefKind::Union => {
-      type_def_join(ctx, def.union.as_ref().unwrap(), "|")
+      let union = def.union.as_ref().unwrap();
+      let multiline = union.len() > ctx.ctx.union_break_threshold
+        || union.iter().any(|member| {
+          matches!(
+            member.kind,
+            Some(
+              TsTypeDefKind::TypeLiteral
+                | TsTypeDefKind::FnOrConstructor
+                | TsTypeDefKind::Conditional
+                | TsTypeDefKind::Mapped
+            )
+          )
+        });
+      type_def_join(ctx, union, "|", multiline)
     }
     TsTypeDefKind::Intersection => {
-      type_def_join(ctx, def.intersection.as_ref().unwrap(), "&")
+      let intersection = def.intersection.as_ref().unwrap();
+      type_def_join(ctx, intersection, "&", intersection.len() > 3)
     }
     TsTypeDefKind::Array => {
       format!("{}[]", render_type_def(ctx, def.array.as_ref().unwrap()))
@@ -137,7 +175,7 @@ pub(crate) fn render_type_def(
       format!(
         "{new}{}({}) =&gt; {}",
         type_params_summary(ctx, &fn_or_constructor.type_params),
-        render_params(ctx, &fn_or_constructor.params),
+        render_params(ctx, &fn_or_constructor.signature_params()),
         render_type_def(ctx, &fn_or_constructor.ts_type),
       )
     }
@@ -188,7 +226,7 @@ pub(crate) fn render_type_def(
         .name_type
         .as_ref()
         .map(|name_type| {
-          format!("<span> in keyof </span>{}", render_type_def(ctx, name_type))
+          format!("<span> as </span>{}", render_type_def(ctx, name_type))
         })
         .unwrap_or_default();
 
@@ -215,6 +253,9 @@ pub(crate) fn render_type_def(
     }
     TsTypeDefKind::TypeLiteral => {
       let type_literal = def.type_literal.as_ref().unwrap();
+      if type_literal.is_empty() {
+        return "{}".to_string();
+      }
 
       let mut index_signatures =
         Vec::with_capacity(type_literal.index_signatures.len());
@@ -371,12 +412,15 @@ pub(crate) fn render_type_def(
   }
 }
 
+/// Joins the members of a union or intersection, either inline or with one
+/// member per line, preceded by the `join` operator.
 fn type_def_join(
   ctx: &RenderContext,
   union: &[crate::ts_type::TsTypeDef],
   join: &str,
+  multiline: bool,
 ) -> String {
-  if union.len() <= 3 {
+  if !multiline {
     let items = union
       .iter()
       .map(|element| render_type_def(ctx, element))
@@ -472,11 +516,27 @@ fn type_param_summary(
     .unwrap_or_default();
 
   format!(
-    "<span><span>{}</span>{constraint}{default}</span>",
+    "<span>{}<span>{}</span>{constraint}{default}</span>",
+    type_param_modifiers(type_param),
     type_param.name,
   )
 }
 
+/// The `const`, `in` and `out` modifiers of a type parameter.
+fn type_param_modifiers(type_param: &TsTypeParamDef) -> String {
+  let mut modifiers = String::new();
+  if type_param.is_const {
+    modifiers.push_str("<span>const </span>");
+  }
+  if type_param.is_in {
+    modifiers.push_str("<span>in </span>");
+  }
+  if type_param.is_out {
+    modifiers.push_str("<span>out </span>");
+  }
+  modifiers
+}
+
 pub(crate) fn type_arguments(
   ctx: &RenderContext,
   defs: &[crate::ts_type::TsTypeDef],
@@ -530,13 +590,16 @@ pub(crate) fn render_type_params(
       })
       .unwrap_or_default();
 
+    let name =
+      format!("{}{}", type_param_modifiers(type_param), type_param.name);
+
     let content = DocEntryCtx::new(
       ctx,
       &id,
-      &type_param.name,
+      &name,
       &format!("{constraint}{default}"),
       HashSet::new(),
-      None,
+      type_param.description.as_deref(),
       location,
     );
 
@@ -56,8 +56,7 @@ pub fn compute_namespaced_symbols(
 
     namespaced_symbols.insert(name_path.clone());
 
-    if doc_node.kind == DocNodeKind::Namespace {
-      let namespace_def = doc_node.namespace_def.as_ref().unwrap();
+    if let Some(namespace_def) = &doc_node.namespace_def {
       namespaced_symbols.extend(compute_namespaced_symbols(
         &namespace_def.elements,
         &name_path,
@@ -137,6 +136,19 @@ pub trait HrefResolver {
     }
   }
 
+  /// Resolver for the links of the symbols referred to by the documentation,
+  /// given the name they are referred to by and the file of the current
+  /// page, which replaces the links to the symbols of the documented modules,
+  /// imported symbols and global symbols. Returning `None`, as by default,
+  /// keeps the built-in resolution.
+  fn resolve_symbol_link(
+    &self,
+    _symbol: &str,
+    _current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    None
+  }
+
   /// Resolver for global symbols, like the Deno namespace or other built-ins
   fn resolve_global_symbol(&self, symbol: &[String]) -> Option<String>;
 
@@ -155,6 +167,110 @@ pub trait HrefResolver {
   fn resolve_source(&self, location: &crate::Location) -> Option<String>;
 }
 
+/// Wraps a resolver to make the paths between the generated pages absolute,
+/// by prefixing them with the path the documentation is deployed to.
+pub(crate) struct BasePathHrefResolver {
+  pub base_path: String,
+  pub inner: Rc<dyn HrefResolver>,
+}
+
+impl HrefResolver for BasePathHrefResolver {
+  fn resolve_path(
+    &self,
+    _current: UrlResolveKind,
+    target: UrlResolveKind,
+  ) -> String {
+    let path = self.inner.resolve_path(UrlResolveKind::Root, target);
+    absolute_page_url(&self.base_path, &path)
+  }
+
+  fn resolve_symbol_link(
+    &self,
+    symbol: &str,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    self.inner.resolve_symbol_link(symbol, current_file)
+  }
+
+  fn resolve_global_symbol(&self, symbol: &[String]) -> Option<String> {
+    self.inner.resolve_global_symbol(symbol)
+  }
+
+  fn resolve_import_href(
+    &self,
+    symbol: &[String],
+    src: &str,
+  ) -> Option<String> {
+    self.inner.resolve_import_href(symbol, src)
+  }
+
+  fn resolve_usage(
+    &self,
+    current_specifier: &ModuleSpecifier,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    self.inner.resolve_usage(current_specifier, current_file)
+  }
+
+  fn resolve_source(&self, location: &crate::Location) -> Option<String> {
+    self.inner.resolve_source(location)
+  }
+}
+
+/// Wraps a resolver to link the symbols to their anchor on the page of their
+/// module, for when no page is generated per symbol.
+pub(crate) struct ModulePageHrefResolver {
+  pub inner: Rc<dyn HrefResolver>,
+}
+
+impl HrefResolver for ModulePageHrefResolver {
+  fn resolve_path(
+    &self,
+    current: UrlResolveKind,
+    target: UrlResolveKind,
+  ) -> String {
+    match target {
+      UrlResolveKind::Symbol { file, symbol } => format!(
+        "{}#symbol_{symbol}",
+        self.inner.resolve_path(current, UrlResolveKind::File(file))
+      ),
+      target => self.inner.resolve_path(current, target),
+    }
+  }
+
+  fn resolve_symbol_link(
+    &self,
+    symbol: &str,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    self.inner.resolve_symbol_link(symbol, current_file)
+  }
+
+  fn resolve_global_symbol(&self, symbol: &[String]) -> Option<String> {
+    self.inner.resolve_global_symbol(symbol)
+  }
+
+  fn resolve_import_href(
+    &self,
+    symbol: &[String],
+    src: &str,
+  ) -> Option<String> {
+    self.inner.resolve_import_href(symbol, src)
+  }
+
+  fn resolve_usage(
+    &self,
+    current_specifier: &ModuleSpecifier,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    self.inner.resolve_usage(current_specifier, current_file)
+  }
+
+  fn resolve_source(&self, location: &crate::Location) -> Option<String> {
+    self.inner.resolve_source(location)
+  }
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct BreadcrumbCtx {
   pub name: String,
@@ -215,6 +331,7 @@ pub enum SectionContentCtx {
   Example(Vec<super::jsdoc::ExampleCtx>),
   IndexSignature(Vec<super::symbols::class::IndexSignatureCtx>),
   NamespaceSection(Vec<super::namespace::NamespaceNodeCtx>),
+  Source(Vec<super::symbols::class::SourceCtx>),
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -234,6 +351,7 @@ pub enum Tag {
   Protected,
   Private,
   Optional,
+  Override,
   Permissions(Vec<String>),
   Other(String),
 }
@@ -307,3 +425,13 @@ pub(crate) fn all_deprecated(nodes: &[&crate::DocNode]) -> bool {
       .any(|tag| matches!(tag, JsDocTag::Deprecated { .. }))
   })
 }
+
+/// Joins the URL the documentation is deployed to with the name of a
+/// generated file, eg. `./~/Foo.html`.
+pub(crate) fn absolute_page_url(base_url: &str, file_name: &str) -> String {
+  format!(
+    "{}/{}",
+    base_url.trim_end_matches('/'),
+    file_name.trim_start_matches("./")
+  )
+}
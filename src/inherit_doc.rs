@@ -0,0 +1,204 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::ts_type::TsTypeDef;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+/// Completes the JSDoc of the class and interface members that have an
+/// `@inheritDoc` tag with the description, `@param` and `@returns` tags of
+/// the member of the same name of a parent, which are looked up along the
+/// classes the class extends, then along the interfaces that they implement
+/// and that those extend. Only the parents declared by the same nodes, or
+/// the same namespace, are found.
+pub(crate) fn inherit_docs(doc_nodes: &mut [DocNode]) {
+  let parents = doc_nodes
+    .iter()
+    .filter(|doc_node| {
+      matches!(doc_node.kind, DocNodeKind::Class | DocNodeKind::Interface)
+    })
+    .map(|doc_node| (doc_node.name.clone(), doc_node.clone()))
+    .collect::<HashMap<_, _>>();
+
+  for doc_node in doc_nodes.iter_mut() {
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      inherit_docs(&mut namespace_def.elements);
+      continue;
+    }
+    let ancestors = ancestors(&parents, doc_node);
+    if ancestors.is_empty() {
+      continue;
+    }
+    if let Some(class_def) = &mut doc_node.class_def {
+      for method in &mut class_def.methods {
+        inherit_doc(
+          &mut method.js_doc,
+          &ancestors,
+          &method.name,
+          method.is_static,
+        );
+        method.function_def.apply_returns_tag(&method.js_doc);
+      }
+      for property in &mut class_def.properties {
+        inherit_doc(
+          &mut property.js_doc,
+          &ancestors,
+          &property.name,
+          property.is_static,
+        );
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for method in &mut interface_def.methods {
+        inherit_doc(&mut method.js_doc, &ancestors, &method.name, false);
+      }
+      for property in &mut interface_def.properties {
+        inherit_doc(&mut property.js_doc, &ancestors, &property.name, false);
+      }
+    }
+  }
+}
+
+/// The classes the node extends, closest first, followed by the interfaces
+/// that the node and those classes implement or extend, breadth first.
+fn ancestors<'a>(
+  parents: &'a HashMap<String, DocNode>,
+  doc_node: &DocNode,
+) -> Vec<&'a DocNode> {
+  let mut ancestors = vec![];
+  let mut visited = HashSet::from([doc_node.name.as_str()]);
+  let mut interfaces = VecDeque::new();
+
+  let mut class_def = doc_node.class_def.as_ref();
+  while let Some(current) = class_def {
+    interfaces.extend(current.implements.iter().filter_map(type_name));
+    class_def = None;
+    if let Some(parent) = current
+      .extends
+      .as_deref()
+      .and_then(|name| parents.get(name))
+      .filter(|parent| parent.kind == DocNodeKind::Class)
+    {
+      if visited.insert(&parent.name) {
+        ancestors.push(parent);
+        class_def = parent.class_def.as_ref();
+      }
+    }
+  }
+
+  if let Some(interface_def) = &doc_node.interface_def {
+    interfaces.extend(interface_def.extends.iter().filter_map(type_name));
+  }
+  while let Some(name) = interfaces.pop_front() {
+    let Some(parent) = parents
+      .get(name)
+      .filter(|parent| parent.kind == DocNodeKind::Interface)
+    else {
+      continue;
+    };
+    if visited.insert(&parent.name) {
+      ancestors.push(parent);
+      let interface_def = parent.interface_def.as_ref().unwrap();
+      interfaces.extend(interface_def.extends.iter().filter_map(type_name));
+    }
+  }
+
+  ancestors
+}
+
+fn type_name(ts_type: &TsTypeDef) -> Option<&str> {
+  ts_type
+    .type_ref
+    .as_ref()
+    .map(|type_ref| type_ref.type_name.as_str())
+}
+
+fn inherit_doc(
+  js_doc: &mut JsDoc,
+  ancestors: &[&DocNode],
+  name: &str,
+  is_static: bool,
+) {
+  if !js_doc.tags.contains(&JsDocTag::InheritDoc) {
+    return;
+  }
+  for ancestor in ancestors {
+    let Some(parent_js_doc) = member_js_doc(ancestor, name, is_static) else {
+      continue;
+    };
+    fill_js_doc(js_doc, parent_js_doc);
+    // the parent member may itself inherit its documentation
+    if !parent_js_doc.tags.contains(&JsDocTag::InheritDoc) {
+      break;
+    }
+  }
+}
+
+/// The JSDoc of the documented member of the class or interface.
+fn member_js_doc<'a>(
+  doc_node: &'a DocNode,
+  name: &str,
+  is_static: bool,
+) -> Option<&'a JsDoc> {
+  let js_docs: Vec<&JsDoc> = if let Some(class_def) = &doc_node.class_def {
+    let methods = class_def
+      .methods
+      .iter()
+      .filter(|method| method.name == name && method.is_static == is_static)
+      .map(|method| &method.js_doc);
+    let properties = class_def
+      .properties
+      .iter()
+      .filter(|property| {
+        property.name == name && property.is_static == is_static
+      })
+      .map(|property| &property.js_doc);
+    methods.chain(properties).collect()
+  } else if let Some(interface_def) = doc_node.interface_def.as_ref() {
+    if is_static {
+      return None;
+    }
+    let methods = interface_def
+      .methods
+      .iter()
+      .filter(|method| method.name == name)
+      .map(|method| &method.js_doc);
+    let properties = interface_def
+      .properties
+      .iter()
+      .filter(|property| property.name == name)
+      .map(|property| &property.js_doc);
+    methods.chain(properties).collect()
+  } else {
+    vec![]
+  };
+  js_docs.into_iter().find(|js_doc| !js_doc.is_empty())
+}
+
+/// Copies the description, `@param` and `@returns` tags of the parent that
+/// the JSDoc does not have.
+fn fill_js_doc(js_doc: &mut JsDoc, parent: &JsDoc) {
+  if js_doc.doc.is_none() {
+    js_doc.doc = parent.doc.clone();
+  }
+  for tag in &parent.tags {
+    let is_missing = match tag {
+      JsDocTag::Param { name, .. } => !js_doc.tags.iter().any(|own| {
+        matches!(own, JsDocTag::Param { name: own_name, .. } if own_name == name)
+      }),
+      JsDocTag::Return { .. } => !js_doc
+        .tags
+        .iter()
+        .any(|own| matches!(own, JsDocTag::Return { .. })),
+      _ => false,
+    };
+    if is_missing {
+      js_doc.tags.push(tag.clone());
+    }
+  }
+}
@@ -4,12 +4,18 @@ use deno_ast::ParsedSource;
 use deno_ast::SourceRangedForSpanned;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 
 use crate::function::FunctionDef;
 use crate::js_doc::JsDoc;
 use crate::node::DeclarationKind;
+use crate::params::signature_params;
 use crate::params::ts_fn_param_to_param_def;
+use crate::params::ts_fn_params_to_param_defs;
+use crate::ts_type::LiteralMethodDef;
+use crate::ts_type::LiteralPropertyDef;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefOptions;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::util::swc::get_location;
@@ -45,6 +51,59 @@ pub struct InterfaceMethodDef {
   pub computed: bool,
   pub optional: bool,
   pub params: Vec<ParamDef>,
+  /// The type of the `this` pseudo-parameter, eg. `MyClass` for
+  /// `method(this: MyClass)`, which is not included in `params`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub this_type: Option<TsTypeDef>,
+  pub return_type: Option<TsTypeDef>,
+  pub type_params: Vec<TsTypeParamDef>,
+  /// Further signatures of an overloaded method, in declaration order.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub overloads: Vec<FunctionSignatureDef>,
+}
+
+impl InterfaceMethodDef {
+  /// The parameters as written in the signature, ie. preceded by the `this`
+  /// pseudo-parameter when the method declares its type.
+  pub fn signature_params(&self) -> Vec<ParamDef> {
+    signature_params(self.this_type.as_ref(), &self.params)
+  }
+
+  /// Returns every signature of the method as a separate method def, starting
+  /// with the first declared one, followed by its overloads.
+  pub fn signatures(&self) -> Vec<InterfaceMethodDef> {
+    let mut first = self.clone();
+    first.overloads = vec![];
+    let mut signatures = vec![first];
+    for overload in &self.overloads {
+      signatures.push(InterfaceMethodDef {
+        name: self.name.clone(),
+        kind: self.kind,
+        location: overload.location.clone(),
+        js_doc: overload.js_doc.clone(),
+        computed: self.computed,
+        optional: self.optional,
+        params: overload.params.clone(),
+        this_type: overload.this_type.clone(),
+        return_type: overload.return_type.clone(),
+        type_params: overload.type_params.clone(),
+        overloads: vec![],
+      });
+    }
+    signatures
+  }
+}
+
+/// A single signature of an overloaded interface method.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionSignatureDef {
+  pub location: Location,
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
+  pub params: Vec<ParamDef>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub this_type: Option<TsTypeDef>,
   pub return_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
 }
@@ -59,8 +118,12 @@ impl From<InterfaceMethodDef> for DocNode {
       FunctionDef {
         def_name: None,
         params: def.params,
+        this_type: def.this_type,
         return_type: def.return_type,
+        return_type_doc: None,
         has_body: false,
+        body_span: None,
+        is_overload: false,
         is_async: false,
         is_generator: false,
         type_params: def.type_params,
@@ -78,7 +141,7 @@ impl Display for InterfaceMethodDef {
       "{}{}({})",
       display_computed(self.computed, &self.name),
       display_optional(self.optional),
-      SliceDisplayer::new(&self.params, ", ", false),
+      SliceDisplayer::new(&self.signature_params(), ", ", false),
     )?;
     if let Some(return_type) = &self.return_type {
       write!(f, ": {}", return_type)?;
@@ -113,6 +176,8 @@ impl From<InterfacePropertyDef> for DocNode {
       VariableDef {
         ts_type: def.ts_type,
         kind: deno_ast::swc::ast::VarDeclKind::Const,
+        using: None,
+        deploy_handler: false,
       },
     )
   }
@@ -173,6 +238,24 @@ pub struct InterfaceCallSignatureDef {
   pub type_params: Vec<TsTypeParamDef>,
 }
 
+#[cfg(feature = "rust")]
+impl Display for InterfaceCallSignatureDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if !self.type_params.is_empty() {
+      write!(
+        f,
+        "<{}>",
+        SliceDisplayer::new(&self.type_params, ", ", false)
+      )?;
+    }
+    write!(f, "({})", SliceDisplayer::new(&self.params, ", ", false))?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceDef {
@@ -187,6 +270,52 @@ pub struct InterfaceDef {
   pub type_params: Vec<TsTypeParamDef>,
 }
 
+impl InterfaceDef {
+  /// The object type literal describing the same shape as the interface, for
+  /// consumers that don't distinguish the two, eg. `{ x: number }` for
+  /// `interface Foo { x: number }`. Only interfaces made of property and
+  /// method signatures have one, so there's none if the interface extends
+  /// others or has call or index signatures. Type parameters of the interface
+  /// are referenced as is.
+  pub fn to_type_literal(&self) -> Option<TsTypeDef> {
+    if !self.extends.is_empty()
+      || !self.call_signatures.is_empty()
+      || !self.index_signatures.is_empty()
+    {
+      return None;
+    }
+
+    let methods = self
+      .methods
+      .iter()
+      .flat_map(|method| method.signatures())
+      .map(|method| LiteralMethodDef {
+        params: method.signature_params(),
+        name: method.name,
+        kind: method.kind,
+        computed: method.computed,
+        optional: method.optional,
+        return_type: method.return_type,
+        type_params: method.type_params,
+      })
+      .collect();
+    let properties = self
+      .properties
+      .iter()
+      .map(|property| LiteralPropertyDef {
+        name: property.name.clone(),
+        params: property.params.clone(),
+        readonly: property.readonly,
+        computed: property.computed,
+        optional: property.optional,
+        ts_type: property.ts_type.clone(),
+        type_params: property.type_params.clone(),
+      })
+      .collect();
+    Some(TsTypeDef::object(methods, properties))
+  }
+}
+
 pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
   use deno_ast::swc::ast::Expr::*;
   use deno_ast::swc::ast::MemberProp;
@@ -230,12 +359,15 @@ pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
 
 pub fn get_doc_for_ts_interface_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   interface_decl: &deno_ast::swc::ast::TsInterfaceDecl,
   def_name: Option<String>,
 ) -> (String, InterfaceDef) {
   let interface_name = interface_decl.id.sym.to_string();
 
-  let mut methods = vec![];
+  let mut methods: Vec<InterfaceMethodDef> = vec![];
+  // overloaded method signatures are grouped under the first one declared
+  let mut method_indexes: HashMap<(String, bool), usize> = HashMap::new();
   let mut properties = vec![];
   let mut call_signatures = vec![];
   let mut index_signatures = vec![];
@@ -248,36 +380,54 @@ pub fn get_doc_for_ts_interface_decl(
         if let Some(method_js_doc) =
           js_doc_for_range(parsed_source, &ts_method_sig.range())
         {
-          let mut params = vec![];
-
-          for param in &ts_method_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
-            params.push(param_def);
-          }
+          let (this_type, params) = ts_fn_params_to_param_defs(
+            parsed_source,
+            options,
+            &ts_method_sig.params,
+          );
 
           let name = expr_to_name(&ts_method_sig.key);
 
-          let maybe_return_type = ts_method_sig
-            .type_ann
-            .as_deref()
-            .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+          let maybe_return_type =
+            ts_method_sig.type_ann.as_deref().map(|type_ann| {
+              TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
+            });
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_method_sig.type_params.as_deref(),
           );
 
+          let location = get_location(parsed_source, ts_method_sig.start());
+
+          let key = (name.clone(), ts_method_sig.computed);
+          if let Some(index) = method_indexes.get(&key) {
+            methods[*index].overloads.push(FunctionSignatureDef {
+              location,
+              js_doc: method_js_doc,
+              params,
+              this_type,
+              return_type: maybe_return_type,
+              type_params,
+            });
+            continue;
+          }
+
           let method_def = InterfaceMethodDef {
             name,
             kind: deno_ast::swc::ast::MethodKind::Method,
             js_doc: method_js_doc,
-            location: get_location(parsed_source, ts_method_sig.start()),
+            location,
             computed: ts_method_sig.computed,
             optional: ts_method_sig.optional,
             params,
+            this_type,
             return_type: maybe_return_type,
             type_params,
+            overloads: vec![],
           };
+          method_indexes.insert(key, methods.len());
           methods.push(method_def);
         }
       }
@@ -287,10 +437,10 @@ pub fn get_doc_for_ts_interface_decl(
         {
           let name = expr_to_name(&ts_getter_sig.key);
 
-          let maybe_return_type = ts_getter_sig
-            .type_ann
-            .as_deref()
-            .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+          let maybe_return_type =
+            ts_getter_sig.type_ann.as_deref().map(|type_ann| {
+              TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
+            });
 
           let method_def = InterfaceMethodDef {
             name,
@@ -300,8 +450,10 @@ pub fn get_doc_for_ts_interface_decl(
             computed: ts_getter_sig.computed,
             optional: ts_getter_sig.optional,
             params: vec![],
+            this_type: None,
             return_type: maybe_return_type,
             type_params: vec![],
+            overloads: vec![],
           };
           methods.push(method_def);
         }
@@ -312,8 +464,11 @@ pub fn get_doc_for_ts_interface_decl(
         {
           let name = expr_to_name(&ts_setter_sig.key);
 
-          let param_def =
-            ts_fn_param_to_param_def(parsed_source, &ts_setter_sig.param);
+          let param_def = ts_fn_param_to_param_def(
+            parsed_source,
+            options,
+            &ts_setter_sig.param,
+          );
           let params = vec![param_def];
 
           let method_def = InterfaceMethodDef {
@@ -324,8 +479,10 @@ pub fn get_doc_for_ts_interface_decl(
             computed: ts_setter_sig.computed,
             optional: ts_setter_sig.optional,
             params,
+            this_type: None,
             return_type: None,
             type_params: vec![],
+            overloads: vec![],
           };
           methods.push(method_def);
         }
@@ -339,17 +496,18 @@ pub fn get_doc_for_ts_interface_decl(
           let mut params = vec![];
 
           for param in &ts_prop_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
-          let ts_type = ts_prop_sig
-            .type_ann
-            .as_deref()
-            .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+          let ts_type = ts_prop_sig.type_ann.as_deref().map(|type_ann| {
+            TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
+          });
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_prop_sig.type_params.as_deref(),
           );
 
@@ -373,17 +531,18 @@ pub fn get_doc_for_ts_interface_decl(
         {
           let mut params = vec![];
           for param in &ts_call_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
-          let ts_type = ts_call_sig
-            .type_ann
-            .as_deref()
-            .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+          let ts_type = ts_call_sig.type_ann.as_deref().map(|type_ann| {
+            TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
+          });
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_call_sig.type_params.as_deref(),
           );
 
@@ -403,14 +562,15 @@ pub fn get_doc_for_ts_interface_decl(
         {
           let mut params = vec![];
           for param in &ts_index_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let ts_type = ts_index_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let index_sig_def = InterfaceIndexSignatureDef {
             location: get_location(parsed_source, ts_index_sig.start()),
@@ -429,19 +589,21 @@ pub fn get_doc_for_ts_interface_decl(
           let mut params = vec![];
 
           for param in &ts_construct_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_construct_sig.type_params.as_deref(),
           );
 
           let maybe_return_type = ts_construct_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let construct_sig_def = InterfaceMethodDef {
             name: "new".to_string(),
@@ -451,8 +613,10 @@ pub fn get_doc_for_ts_interface_decl(
             computed: false,
             optional: false,
             params,
+            this_type: None,
             return_type: maybe_return_type,
             type_params,
+            overloads: vec![],
           };
 
           methods.push(construct_sig_def);
@@ -463,13 +627,14 @@ pub fn get_doc_for_ts_interface_decl(
 
   let type_params = maybe_type_param_decl_to_type_param_defs(
     parsed_source,
+    options,
     interface_decl.type_params.as_deref(),
   );
 
   let extends = interface_decl
     .extends
     .iter()
-    .map(|expr| TsTypeDef::ts_expr_with_type_args(parsed_source, expr))
+    .map(|expr| TsTypeDef::ts_expr_with_type_args(parsed_source, options, expr))
     .collect::<Vec<TsTypeDef>>();
 
   let interface_def = InterfaceDef {
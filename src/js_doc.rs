@@ -6,16 +6,22 @@ use serde::Serialize;
 
 lazy_static! {
   static ref JS_DOC_TAG_MAYBE_DOC_RE: Regex = Regex::new(r"(?s)^\s*@(deprecated)(?:\s+(.+))?").unwrap();
-  static ref JS_DOC_TAG_DOC_RE: Regex = Regex::new(r"(?s)^\s*@(category|see|example|tags)(?:\s+(.+))").unwrap();
-  static ref JS_DOC_TAG_NAMED_RE: Regex = Regex::new(r"(?s)^\s*@(callback|template)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_DOC_RE: Regex = Regex::new(r"(?s)^\s*@(category|see|example|tags|tag)(?:\s+(.+))").unwrap();
+  static ref JS_DOC_TAG_NAMED_RE: Regex = Regex::new(r"(?s)^\s*@(callback|emits|fires)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
   static ref JS_DOC_TAG_NAMED_TYPED_RE: Regex = Regex::new(r"(?s)^\s*@(prop(?:erty)?|typedef)\s+\{([^}]+)\}\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
-  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r"^\s*@(constructor|class|ignore|module|public|private|protected|readonly)").unwrap();
+  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r"^\s*@(constructor|class|deployHandler|experimental|ignore|inheritDoc|module|override|packageDocumentation|package|public|private|protected|readonly)").unwrap();
   static ref JS_DOC_TAG_PARAM_RE: Regex = Regex::new(
-    r"(?s)^\s*@(?:param|arg(?:ument)?)(?:\s+\{(?P<type>[^}]+)\})?\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$]\S*?)(?:\s*=\s*(?P<default>[^]]+))?\])|(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
+    r"(?s)^\s*@(?:param|arg(?:ument)?)(?:\s+\{(?P<type>[^}]+)\})?\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$]\S*?)(?:\s*=\s*(?P<default>[^]]+))?\])|(?:\.\.\.)?(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
   )
   .unwrap();
   static ref JS_DOC_TAG_RE: Regex = Regex::new(r"(?s)^\s*@(\S+)").unwrap();
+  static ref JS_DOC_TAG_SATISFIES_RE: Regex = Regex::new(r"(?s)^\s*@satisfies\s+(?:\{([^}]+)\}|(\S+))(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_PROXY_RE: Regex = Regex::new(r"(?s)^\s*@proxy\s+(\{.*\})(?:\s+([^}]+))?$").unwrap();
   static ref JS_DOC_TAG_RETURN_RE: Regex = Regex::new(r"(?s)^\s*@returns?(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
+  static ref INLINE_TYPE_RE: Regex = Regex::new(r"^[A-Z][\w$]*(?:\.[A-Za-z_$][\w$]*)*$").unwrap();
+  static ref JS_DOC_LINK_RE: Regex = Regex::new(r"\{\s*@link(?:code|plain)?\s+([^}|\s]+)").unwrap();
+  static ref JS_DOC_TAG_THROWS_RE: Regex = Regex::new(r"(?s)^\s*@(?:throws|exception)(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_TEMPLATE_RE: Regex = Regex::new(r"(?s)^\s*@template(?:\s+\{([^}]+)\})?\s+([a-zA-Z_$][\w$]*(?:\s*,\s*[a-zA-Z_$][\w$]*)*)(?:\s+(.+))?").unwrap();
   static ref JS_DOC_TAG_TYPED_RE: Regex = Regex::new(r"(?s)^\s*@(enum|extends|augments|this|type|default)\s+\{([^}]+)\}(?:\s+(.+))?").unwrap();
 }
 
@@ -31,6 +37,169 @@ impl JsDoc {
   pub fn is_empty(&self) -> bool {
     self.doc.is_none() && self.tags.is_empty()
   }
+
+  /// The contents of the `@example` tags, in declaration order.
+  pub fn examples(&self) -> Vec<&str> {
+    self
+      .tags
+      .iter()
+      .filter_map(|tag| match tag {
+        JsDocTag::Example { doc } => Some(doc.as_str()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// The targets of the `{@link}`, `{@linkcode}` and `{@linkplain}` inline
+  /// tags in the description and in the comments of the tags. Examples are
+  /// code, and so are not searched.
+  #[cfg(feature = "rust")]
+  pub(crate) fn links(&self) -> Vec<&str> {
+    let mut links = self.doc.as_deref().map(js_doc_links).unwrap_or_default();
+    for tag in &self.tags {
+      if let Some(doc) = tag.comment() {
+        links.extend(js_doc_links(doc));
+      }
+    }
+    links
+  }
+}
+
+/// The targets of the `{@link}`, `{@linkcode}` and `{@linkplain}` inline tags
+/// in the text, eg. `Foo.bar` for `{@link Foo.bar | the bar method}`.
+pub(crate) fn js_doc_links(text: &str) -> Vec<&str> {
+  JS_DOC_LINK_RE
+    .captures_iter(text)
+    .map(|captures| captures.get(1).unwrap().as_str())
+    .collect()
+}
+
+/// A reference to a type in the text of a JSDoc description, see
+/// [`extract_inline_types`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineTypeRef {
+  /// The name of the type, eg. `Foo` or `Deno.Reader`.
+  pub text: String,
+  /// The byte offset of the name in the description.
+  pub start: usize,
+  /// The byte offset of the end of the name in the description.
+  pub end: usize,
+}
+
+/// The references to types in the text of a JSDoc description: the names in
+/// code spans, eg. `` `Foo` ``, in braces, eg. `{Foo}`, and the bare names
+/// that can't be ordinary words, ie. the ones in camel case such as
+/// `ReadableStream`, in the order they appear.
+///
+/// Only names that start with an uppercase letter, optionally followed by
+/// member accesses such as `Deno.Reader`, are types, so `` `foo()` `` or
+/// `{a: 1}` are not. Fenced code blocks, escaped characters and inline tags
+/// such as `{@link Foo}` are skipped.
+pub fn extract_inline_types(doc: &str) -> Vec<InlineTypeRef> {
+  let bytes = doc.as_bytes();
+  let mut refs = vec![];
+  let mut push_if_type = |start: usize, end: usize| {
+    let text = &doc[start..end];
+    let trimmed_start = start + (text.len() - text.trim_start().len());
+    let text = text.trim();
+    if INLINE_TYPE_RE.is_match(text) {
+      refs.push(InlineTypeRef {
+        text: text.to_string(),
+        start: trimmed_start,
+        end: trimmed_start + text.len(),
+      });
+    }
+  };
+  let is_ident_byte = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+
+  let mut in_code_block = false;
+  let mut line_start = true;
+  let mut i = 0;
+  while i < bytes.len() {
+    if line_start && doc[i..].trim_start_matches([' ', '\t']).starts_with("```")
+    {
+      in_code_block = !in_code_block;
+    }
+    if in_code_block {
+      let line_end = doc[i..].find('\n').map_or(bytes.len(), |end| i + end);
+      i = line_end + 1;
+      line_start = true;
+      continue;
+    }
+    line_start = bytes[i] == b'\n';
+
+    match bytes[i] {
+      b'\\' => i += 2,
+      b'`' => match doc[i + 1..].find(['`', '\n']) {
+        Some(len) if bytes[i + 1 + len] == b'`' => {
+          push_if_type(i + 1, i + 1 + len);
+          i += len + 2;
+        }
+        _ => i += 1,
+      },
+      b'{' => match doc[i + 1..].find(['}', '\n']) {
+        Some(len) if bytes[i + 1 + len] == b'}' => {
+          if !doc[i + 1..].trim_start().starts_with('@') {
+            push_if_type(i + 1, i + 1 + len);
+          }
+          i += len + 2;
+        }
+        _ => i += 1,
+      },
+      byte
+        if byte.is_ascii_uppercase()
+          && (i == 0 || !is_ident_byte(bytes[i - 1])) =>
+      {
+        let len = doc[i..]
+          .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+          .unwrap_or(bytes.len() - i);
+        let word = &doc[i..i + len];
+        let is_camel_case = word.bytes().any(|byte| byte.is_ascii_lowercase())
+          && word.bytes().skip(1).any(|byte| byte.is_ascii_uppercase());
+        if is_camel_case {
+          push_if_type(i, i + len);
+        }
+        i += len;
+      }
+      _ => i += 1,
+    }
+  }
+  refs
+}
+
+/// Returns the first sentence of the first paragraph of the markdown that is
+/// not part of a code block, with its whitespace collapsed.
+pub(crate) fn first_sentence(md: &str) -> Option<String> {
+  let mut in_code_block = false;
+  let mut words = vec![];
+
+  for line in md.lines() {
+    let line = line.trim();
+    if line.starts_with("```") {
+      in_code_block = !in_code_block;
+      if !words.is_empty() {
+        break;
+      }
+    } else if in_code_block {
+      continue;
+    } else if line.is_empty() {
+      if !words.is_empty() {
+        break;
+      }
+    } else {
+      words.extend(line.split_whitespace());
+    }
+  }
+
+  if words.is_empty() {
+    return None;
+  }
+
+  let paragraph = words.join(" ");
+  match paragraph.find(". ") {
+    Some(index) => Some(paragraph[..=index].to_string()),
+    None => Some(paragraph),
+  }
 }
 
 impl From<String> for JsDoc {
@@ -48,7 +217,7 @@ impl From<String> for JsDoc {
           assert!(current_tag.is_empty());
         }
         if caps.is_some() && !current_tag.is_empty() {
-          tags.push(current_tag.join("\n").into());
+          tags.extend(tags_from_text(current_tag.join("\n")));
           current_tag.clear();
         }
         if let Some(caps) = caps {
@@ -65,7 +234,7 @@ impl From<String> for JsDoc {
       }
     }
     if !current_tag.is_empty() {
-      tags.push(current_tag.join("\n").into());
+      tags.extend(tags_from_text(current_tag.join("\n")));
     }
     let doc = if doc_lines.is_empty() {
       None
@@ -76,6 +245,36 @@ impl From<String> for JsDoc {
   }
 }
 
+/// The tags of the text of a single tag, which is usually one tag except for
+/// `@template` tags declaring several type parameters, eg. `@template K, V`.
+fn tags_from_text(value: String) -> Vec<JsDocTag> {
+  match template_tags(&value) {
+    Some(tags) => tags,
+    None => vec![value.into()],
+  }
+}
+
+/// Parses a `@template {constraint} K, V comment` tag into a tag for each type
+/// parameter. As in TypeScript, the constraint only applies to the first type
+/// parameter, and so does the comment.
+fn template_tags(value: &str) -> Option<Vec<JsDocTag>> {
+  let caps = JS_DOC_TAG_TEMPLATE_RE.captures(value)?;
+  let mut type_ref = caps.get(1).map(|m| m.as_str().trim().to_string());
+  let mut doc = caps.get(3).map(|m| m.as_str().to_string());
+  let tags = caps
+    .get(2)
+    .unwrap()
+    .as_str()
+    .split(',')
+    .map(|name| JsDocTag::Template {
+      name: name.trim().to_string(),
+      type_ref: type_ref.take(),
+      doc: doc.take(),
+    })
+    .collect();
+  Some(tags)
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 pub enum JsDocTag {
@@ -98,6 +297,8 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     doc: Option<String>,
   },
+  /// `@deployHandler`
+  DeployHandler,
   /// `@deprecated comment`
   Deprecated {
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -122,13 +323,34 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     doc: Option<String>,
   },
+  /// `@fires EventName comment` or `@emits EventName comment`, where the
+  /// comment can be separated from the event name by a hyphen
+  Fires {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<String>,
+  },
+  /// `@experimental`, which marks the symbol, or all the members of a
+  /// namespace or module, as unstable, see [`crate::node::Stability`]
+  Experimental,
   /// `@ignore`
   Ignore,
+  /// `@inheritDoc`, which completes the documentation of a class or
+  /// interface member with the one of the same member of a parent
+  InheritDoc,
   /// `@module`
   Module,
+  /// `@override`
+  Override,
+  /// `@package`
+  Package,
+  /// `@packageDocumentation`, which marks the comment as the documentation of
+  /// the whole package, see [`crate::node::PackageDoc`]
+  PackageDocumentation,
   /// `@param`, `@arg` or `argument`, in format of `@param {type} name comment`
   /// or `@param {type} [name=default] comment`
-  /// or `@param {type} [name] comment`
+  /// or `@param {type} [name] comment`, where the name of a rest parameter
+  /// can be prefixed with `...`, which is not part of `name`
   Param {
     name: String,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
@@ -154,6 +376,14 @@ pub enum JsDocTag {
   },
   /// `@protected`
   Protected,
+  /// `@proxy {type literal} comment`, the hand-written type of a variable
+  /// holding a `Proxy`, overriding the one inferred for the variable
+  Proxy {
+    #[serde(rename = "type")]
+    type_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<String>,
+  },
   /// `@readonly`
   ReadOnly,
   /// `@return {type} comment` or `@returns {type} comment`
@@ -163,13 +393,28 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     doc: Option<String>,
   },
+  /// `@satisfies {type} comment` or `@satisfies type comment`
+  Satisfies {
+    #[serde(rename = "type")]
+    type_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<String>,
+  },
+  /// `@tag networking`, a user-defined label of the symbol, eg. its feature
+  /// area
+  Tag {
+    #[serde(default)]
+    doc: String,
+  },
   /// `@tags allow-read, allow-write`
   Tags {
     tags: Vec<String>,
   },
-  /// `@template T comment`
+  /// `@template T comment` or `@template {constraint} T comment`
   Template {
     name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    type_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     doc: Option<String>,
   },
@@ -180,6 +425,13 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     doc: Option<String>,
   },
+  /// `@throws {type} comment` or `@exception {type} comment`
+  Throws {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    type_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<String>,
+  },
   /// `@typedef {type} name comment`
   TypeDef {
     name: String,
@@ -205,14 +457,64 @@ pub enum JsDocTag {
   },
 }
 
+impl JsDocTag {
+  /// The free text comment of the tag, if it has one.
+  #[cfg(feature = "rust")]
+  fn comment(&self) -> Option<&str> {
+    match self {
+      Self::Callback { doc, .. }
+      | Self::Default { doc, .. }
+      | Self::Deprecated { doc }
+      | Self::Enum { doc, .. }
+      | Self::Extends { doc, .. }
+      | Self::Fires { doc, .. }
+      | Self::Param { doc, .. }
+      | Self::Property { doc, .. }
+      | Self::Proxy { doc, .. }
+      | Self::Return { doc, .. }
+      | Self::Satisfies { doc, .. }
+      | Self::Template { doc, .. }
+      | Self::This { doc, .. }
+      | Self::Throws { doc, .. }
+      | Self::TypeDef { doc, .. }
+      | Self::TypeRef { doc, .. } => doc.as_deref(),
+      Self::See { doc } => Some(doc),
+      Self::Category { .. }
+      | Self::Constructor
+      | Self::DeployHandler
+      | Self::Example { .. }
+      | Self::Experimental
+      | Self::Ignore
+      | Self::InheritDoc
+      | Self::Module
+      | Self::Override
+      | Self::Package
+      | Self::PackageDocumentation
+      | Self::Public
+      | Self::Private
+      | Self::Protected
+      | Self::ReadOnly
+      | Self::Tag { .. }
+      | Self::Tags { .. }
+      | Self::Unsupported { .. } => None,
+    }
+  }
+}
+
 impl From<String> for JsDocTag {
   fn from(value: String) -> Self {
     if let Some(caps) = JS_DOC_TAG_ONLY_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       match kind {
         "constructor" | "class" => Self::Constructor,
+        "deployHandler" => Self::DeployHandler,
+        "experimental" => Self::Experimental,
         "ignore" => Self::Ignore,
+        "inheritDoc" => Self::InheritDoc,
         "module" => Self::Module,
+        "override" => Self::Override,
+        "package" => Self::Package,
+        "packageDocumentation" => Self::PackageDocumentation,
         "public" => Self::Public,
         "private" => Self::Private,
         "protected" => Self::Protected,
@@ -225,9 +527,15 @@ impl From<String> for JsDocTag {
       let doc = caps.get(3).map(|m| m.as_str().to_string());
       match kind {
         "callback" => Self::Callback { name, doc },
-        "template" => Self::Template { name, doc },
+        "emits" | "fires" => Self::Fires {
+          name,
+          doc: doc
+            .map(|doc| doc.strip_prefix("- ").map(String::from).unwrap_or(doc)),
+        },
         _ => unreachable!("kind unexpected: {}", kind),
       }
+    } else if let Some(mut tags) = template_tags(&value) {
+      tags.remove(0)
     } else if let Some(caps) = JS_DOC_TAG_TYPED_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       let type_ref = caps.get(2).unwrap().as_str().to_string();
@@ -274,6 +582,9 @@ impl From<String> for JsDocTag {
       match kind {
         "category" => Self::Category { doc },
         "example" => Self::Example { doc },
+        "tag" => Self::Tag {
+          doc: doc.trim().to_string(),
+        },
         "tags" => Self::Tags {
           tags: doc.split(',').map(|i| i.trim().to_string()).collect(),
         },
@@ -298,10 +609,22 @@ impl From<String> for JsDocTag {
         default,
         doc,
       }
+    } else if let Some(caps) = JS_DOC_TAG_SATISFIES_RE.captures(&value) {
+      let type_ref = caps.get(1).or(caps.get(2)).unwrap().as_str().to_string();
+      let doc = caps.get(3).map(|m| m.as_str().to_string());
+      Self::Satisfies { type_ref, doc }
+    } else if let Some(caps) = JS_DOC_TAG_PROXY_RE.captures(&value) {
+      let type_ref = caps.get(1).unwrap().as_str().to_string();
+      let doc = caps.get(2).map(|m| m.as_str().to_string());
+      Self::Proxy { type_ref, doc }
     } else if let Some(caps) = JS_DOC_TAG_RETURN_RE.captures(&value) {
       let type_ref = caps.get(1).map(|m| m.as_str().to_string());
       let doc = caps.get(2).map(|m| m.as_str().to_string());
       Self::Return { type_ref, doc }
+    } else if let Some(caps) = JS_DOC_TAG_THROWS_RE.captures(&value) {
+      let type_ref = caps.get(1).map(|m| m.as_str().to_string());
+      let doc = caps.get(2).map(|m| m.as_str().to_string());
+      Self::Throws { type_ref, doc }
     } else {
       Self::Unsupported { value }
     }
@@ -312,6 +635,54 @@ impl From<String> for JsDocTag {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_extract_inline_types() {
+    let texts = |doc: &str| {
+      extract_inline_types(doc)
+        .into_iter()
+        .map(|inline_type| {
+          assert_eq!(
+            &doc[inline_type.start..inline_type.end],
+            inline_type.text
+          );
+          inline_type.text
+        })
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      texts(
+        "Reads from a `Deno.Reader` into a {Uint8Array}, see ReadableStream."
+      ),
+      ["Deno.Reader", "Uint8Array", "ReadableStream"]
+    );
+    assert_eq!(
+      extract_inline_types("Returns a `Foo`."),
+      [InlineTypeRef {
+        text: "Foo".to_string(),
+        start: 11,
+        end: 14,
+      }]
+    );
+    assert_eq!(texts("Use `{ Foo }` or { Bar }."), ["Bar"]);
+    // escaped backticks don't delimit code spans
+    assert_eq!(texts(r"A \`Foo\` and \{Bar} and `Baz`."), ["Baz"]);
+    // the names of values, expressions, sentence starts and acronyms
+    assert!(texts(
+      "Calls `foo` with `Foo()` and `a + b` and {a: 1}. The URL is Fine."
+    )
+    .is_empty());
+    // inline tags and code blocks
+    assert!(texts(
+      "See {@link Foo | SomeThing}.\n\n```ts\nconst x: MyType = `Foo`;\n```\n"
+    )
+    .is_empty());
+    assert_eq!(
+      texts("```\nNotThis\n```\nBut `This` and\nThatOne"),
+      ["This", "ThatOne"]
+    );
+  }
+
   #[test]
   fn test_js_doc_tag_only() {
     assert_eq!(
@@ -323,14 +694,30 @@ mod tests {
       serde_json::to_value(JsDoc::from("@class more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "constructor" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@deployHandler".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "deployhandler" } ] }),
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@ignore more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "ignore" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@inheritDoc".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "inheritdoc" } ] }),
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@module more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "module" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@override more".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "override" } ] }),
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@package more".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "package" } ] }),
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@public more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "public" } ] }),
@@ -411,6 +798,26 @@ if (true) {
         ]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@fires MyEvent\n@emits OtherEvent - When something happens"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [
+          {
+            "kind": "fires",
+            "name": "MyEvent",
+          },
+          {
+            "kind": "fires",
+            "name": "OtherEvent",
+            "doc": "When something happens",
+          }
+        ]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from(
         "@template T more docs\n\nnew paragraph".to_string()
@@ -426,6 +833,31 @@ if (true) {
         ]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@template {string} K, V comment\n@template {number} N".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [
+          {
+            "kind": "template",
+            "name": "K",
+            "type": "string",
+            "doc": "comment",
+          },
+          {
+            "kind": "template",
+            "name": "V",
+          },
+          {
+            "kind": "template",
+            "name": "N",
+            "type": "number",
+          }
+        ]
+      })
+    );
   }
 
   #[test]
@@ -618,6 +1050,21 @@ if (true) {
         }]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@tag database\n@tag machine learning ".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "tag",
+          "doc": "database",
+        }, {
+          "kind": "tag",
+          "doc": "machine learning",
+        }]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@see foo".to_string())).unwrap(),
       json!({
@@ -754,6 +1201,35 @@ const a = "a";
     );
   }
 
+  #[test]
+  fn test_js_doc_tag_throws() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@throws {TypeError} if the input is invalid".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "throws",
+          "type": "TypeError",
+          "doc": "if the input is invalid",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@exception if the input is invalid".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "throws",
+          "doc": "if the input is invalid",
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_tag_returns() {
     assert_eq!(
@@ -796,6 +1272,51 @@ const a = "a";
     );
   }
 
+  #[test]
+  fn test_js_doc_tag_satisfies() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@satisfies {Record<string, number>} maybe doc".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "satisfies",
+          "type": "Record<string, number>",
+          "doc": "maybe doc",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@satisfies SomeInterface".to_string()))
+        .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "satisfies",
+          "type": "SomeInterface",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_proxy() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@proxy { get(key: string): Value; set(key: string, value: Value): boolean } maybe doc"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "proxy",
+          "type": "{ get(key: string): Value; set(key: string, value: Value): boolean }",
+          "doc": "maybe doc",
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_from_str() {
     assert_eq!(
@@ -971,9 +1492,22 @@ multi-line
         "doc": "comment",
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDocTag::Throws {
+        type_ref: Some("TypeError".to_string()),
+        doc: Some("comment".to_string()),
+      })
+      .unwrap(),
+      json!({
+        "kind": "throws",
+        "type": "TypeError",
+        "doc": "comment",
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDocTag::Template {
         name: "T".to_string(),
+        type_ref: None,
         doc: None,
       })
       .unwrap(),
@@ -1028,4 +1562,13 @@ multi-line
       })
     );
   }
+
+  #[test]
+  fn test_js_doc_examples() {
+    let js_doc = JsDoc::from(
+      "Docs.\n\n@example\nfoo();\n@param a\n@example Title\nbar();".to_string(),
+    );
+    assert_eq!(js_doc.examples(), vec!["foo();", "Title\nbar();"]);
+    assert!(JsDoc::from("no examples".to_string()).examples().is_empty());
+  }
 }
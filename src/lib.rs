@@ -20,12 +20,18 @@ mod decorators;
 mod diagnostics;
 mod display;
 mod r#enum;
+mod filter;
 mod function;
+mod inherit_doc;
 mod interface;
 pub mod js_doc;
+mod merge;
 pub mod node;
+mod output;
 mod params;
 mod parser;
+mod related;
+mod report;
 mod ts_type;
 mod ts_type_param;
 mod type_alias;
@@ -33,9 +39,18 @@ mod util;
 mod variable;
 mod visibility;
 
+pub use class::compute_overrides;
+pub use display::DisplayOptions;
+pub use filter::DocFilter;
+pub use node::write_doc_nodes_compact;
+pub use node::DocBuildError;
 pub use node::DocNode;
 pub use node::DocNodeKind;
 pub use node::Location;
+pub use node::DOC_NODE_KINDS;
+pub use output::generate_mermaid_class_diagram;
+pub use report::generate_api_report;
+pub use report::ApiReport;
 
 use node::ImportDef;
 use node::ReexportKind;
@@ -43,16 +58,34 @@ use params::ParamDef;
 
 cfg_if! {
   if #[cfg(feature = "rust")] {
+    mod broken_links;
+    mod lint;
     mod printer;
+    mod stubs;
+    pub use broken_links::broken_links_report;
+    pub use broken_links::broken_links_report_with_known_types;
+    pub use broken_links::BrokenLink;
+    pub use broken_links::DEFAULT_KNOWN_TYPES;
     pub use diagnostics::DocDiagnostic;
     pub use diagnostics::DocDiagnosticKind;
+    pub use lint::lint_jsdoc_links;
+    pub use lint::lint_jsdoc_links_with_known_symbols;
+    pub use lint::lint_shadow_builtins;
+    pub use lint::lint_shadow_builtins_with_builtins;
+    pub use lint::DocLintDiagnostic;
+    pub use lint::DocLintDiagnosticKind;
+    pub use lint::DEFAULT_BUILTINS;
     pub use printer::DocPrinter;
+    pub use printer::TreePrinter;
+    pub use stubs::generate_type_stubs;
   }
 }
 
 pub use parser::DocError;
+pub use parser::DocMode;
 pub use parser::DocParser;
 pub use parser::DocParserOptions;
+pub use ts_type::SimplifyOptions;
 
 cfg_if! {
   if #[cfg(feature = "html")] {
@@ -107,33 +140,65 @@ fn find_nodes_by_name(doc_nodes: Vec<DocNode>, name: String) -> Vec<DocNode> {
 
 #[cfg(feature = "rust")]
 fn get_children_of_node(node: DocNode) -> Vec<DocNode> {
+  // the elements of namespaces, including the ones merged with an enum or an
+  // interface
+  let mut doc_nodes = node
+    .namespace_def
+    .map(|namespace_def| namespace_def.elements)
+    .unwrap_or_default();
   match node.kind {
-    DocNodeKind::Namespace => {
-      let namespace_def = node.namespace_def.unwrap();
-      namespace_def.elements
-    }
     DocNodeKind::Interface => {
       let interface_def = node.interface_def.unwrap();
-      let mut doc_nodes: Vec<DocNode> = vec![];
-      for method in interface_def.methods {
+      for method in interface_def.methods.iter().flat_map(|m| m.signatures()) {
         doc_nodes.push(method.into());
       }
       for property in interface_def.properties {
         doc_nodes.push(property.into());
       }
-      doc_nodes
     }
     DocNodeKind::Class => {
       let class_def = node.class_def.unwrap();
-      let mut doc_nodes: Vec<DocNode> = vec![];
       for method in class_def.methods {
         doc_nodes.push(method.into());
       }
       for property in class_def.properties {
         doc_nodes.push(property.into());
       }
-      doc_nodes
     }
-    _ => vec![],
+    _ => {}
+  }
+  doc_nodes
+}
+
+/// Copies the JSDoc of the symbols of a declaration file to the symbols of
+/// the same name and kind that have none, for sources that are shipped along
+/// with a more thoroughly documented `.d.ts` file. The fields derived from
+/// the JSDoc, like the stability or the `@returns` description, follow it.
+pub fn merge_jsdoc_from_declaration_file(
+  nodes: &mut [DocNode],
+  dts_nodes: &[DocNode],
+) {
+  merge_jsdoc(nodes, dts_nodes);
+  node::propagate_stability(nodes);
+}
+
+fn merge_jsdoc(nodes: &mut [DocNode], dts_nodes: &[DocNode]) {
+  for node in nodes {
+    let Some(dts_node) = dts_nodes.iter().find(|dts_node| {
+      dts_node.name == node.name && dts_node.kind == node.kind
+    }) else {
+      continue;
+    };
+
+    if node.js_doc.is_empty() && !dts_node.js_doc.is_empty() {
+      node.js_doc = dts_node.js_doc.clone();
+      node.apply_js_doc_tags();
+    }
+
+    if let (Some(namespace_def), Some(dts_namespace_def)) =
+      (&mut node.namespace_def, &dts_node.namespace_def)
+    {
+      merge_jsdoc(&mut namespace_def.elements, &dts_namespace_def.elements);
+    }
   }
 }
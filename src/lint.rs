@@ -0,0 +1,323 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::broken_links::qualified_name;
+use crate::broken_links::DEFAULT_KNOWN_TYPES;
+use crate::js_doc::JsDoc;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+
+use std::collections::HashSet;
+
+lazy_static! {
+  static ref SYMBOL_PATH_RE: regex::Regex =
+    regex::Regex::new(r"^[A-Za-z_$][\w$]*(?:[.#][A-Za-z_$][\w$]*)*$").unwrap();
+}
+
+/// The names that [`lint_shadow_builtins`] checks in addition to the
+/// [`DEFAULT_KNOWN_TYPES`]: the primitive types of TypeScript and the globals
+/// of Deno and of the web platform that are values rather than types.
+pub const DEFAULT_BUILTINS: &[&str] = &[
+  // TypeScript
+  "any",
+  "bigint",
+  "boolean",
+  "never",
+  "number",
+  "object",
+  "string",
+  "symbol",
+  "undefined",
+  "unknown",
+  "void",
+  "globalThis",
+  // Deno and the web platform
+  "Deno",
+  "atob",
+  "btoa",
+  "clearInterval",
+  "clearTimeout",
+  "console",
+  "crypto",
+  "fetch",
+  "navigator",
+  "performance",
+  "queueMicrotask",
+  "setInterval",
+  "setTimeout",
+  "structuredClone",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocLintDiagnosticKind {
+  /// An exported symbol named after a built-in, see
+  /// [`lint_shadow_builtins`].
+  ShadowedBuiltin { node_kind: DocNodeKind },
+  /// A `{@link}` whose target is neither documented nor known, see
+  /// [`lint_jsdoc_links`].
+  UnresolvedLink {
+    /// The target of the link, eg. `Bar.baz` for `{@linkcode Bar.baz}`.
+    link: String,
+  },
+}
+
+/// A problem with the declarations of a module, reported by the linters of
+/// this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocLintDiagnostic {
+  /// The name of the symbol or member the diagnostic is about, qualified by
+  /// the namespaces and the class, interface or enum it is declared in, eg.
+  /// `Foo.bar`.
+  pub node_name: String,
+  /// The location of the symbol or member.
+  pub location: Location,
+  pub kind: DocLintDiagnosticKind,
+}
+
+impl DocLintDiagnostic {
+  pub fn code(&self) -> &'static str {
+    match self.kind {
+      DocLintDiagnosticKind::ShadowedBuiltin { .. } => "shadowed-builtin",
+      DocLintDiagnosticKind::UnresolvedLink { .. } => "unresolved-link",
+    }
+  }
+
+  pub fn message(&self) -> String {
+    match &self.kind {
+      DocLintDiagnosticKind::ShadowedBuiltin { node_kind } => format!(
+        "exported {} \"{}\" shadows a built-in of the same name",
+        node_kind.as_str(),
+        self.node_name
+      ),
+      DocLintDiagnosticKind::UnresolvedLink { link } => format!(
+        "link to \"{link}\" in the JSDoc of \"{}\" can't be resolved",
+        self.node_name
+      ),
+    }
+  }
+}
+
+/// Finds the exported symbols whose name shadows a built-in, one of the
+/// [`DEFAULT_KNOWN_TYPES`] or of the [`DEFAULT_BUILTINS`].
+pub fn lint_shadow_builtins(nodes: &[DocNode]) -> Vec<DocLintDiagnostic> {
+  let builtins = DEFAULT_KNOWN_TYPES
+    .iter()
+    .chain(DEFAULT_BUILTINS)
+    .map(|name| name.to_string())
+    .collect();
+  lint_shadow_builtins_with_builtins(nodes, &builtins)
+}
+
+/// Finds the symbols exported by the module whose name is one of the
+/// built-ins, which is usually a mistake as importing them hides the
+/// built-in from the importing module. Overloads and merged declarations are
+/// reported once.
+pub fn lint_shadow_builtins_with_builtins(
+  nodes: &[DocNode],
+  builtins: &HashSet<String>,
+) -> Vec<DocLintDiagnostic> {
+  let mut reported = HashSet::new();
+  nodes
+    .iter()
+    .filter(|node| {
+      node.declaration_kind == DeclarationKind::Export
+        && !matches!(node.kind, DocNodeKind::ModuleDoc | DocNodeKind::Import)
+        && builtins.contains(&node.name)
+        && reported.insert(node.name.as_str())
+    })
+    .map(|node| DocLintDiagnostic {
+      node_name: node.name.clone(),
+      location: node.location.clone(),
+      kind: DocLintDiagnosticKind::ShadowedBuiltin {
+        node_kind: node.kind,
+      },
+    })
+    .collect()
+}
+
+/// Finds the `{@link}` inline tags of the JSDoc of the nodes and of their
+/// members whose target can't be resolved, assuming the
+/// [`DEFAULT_KNOWN_TYPES`] are known.
+pub fn lint_jsdoc_links(nodes: &[DocNode]) -> Vec<DocLintDiagnostic> {
+  let known_symbols = DEFAULT_KNOWN_TYPES
+    .iter()
+    .map(|name| name.to_string())
+    .collect();
+  lint_jsdoc_links_with_known_symbols(nodes, &known_symbols)
+}
+
+/// Finds the `{@link}` inline tags of the JSDoc of the nodes and of their
+/// members whose target is neither one of the nodes, one of their members
+/// nor one of the known symbols. Targets that aren't symbol names, such as
+/// URLs, are not checked.
+pub fn lint_jsdoc_links_with_known_symbols(
+  nodes: &[DocNode],
+  known_symbols: &HashSet<String>,
+) -> Vec<DocLintDiagnostic> {
+  let mut linter = Linter {
+    nodes,
+    known_symbols,
+    diagnostics: vec![],
+  };
+  linter.lint_nodes(None, nodes);
+  linter.diagnostics
+}
+
+struct Linter<'a> {
+  nodes: &'a [DocNode],
+  known_symbols: &'a HashSet<String>,
+  diagnostics: Vec<DocLintDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+  fn lint_nodes(&mut self, namespace: Option<&str>, nodes: &[DocNode]) {
+    for node in nodes {
+      let node_name = qualified_name(namespace, &node.name);
+      self.lint_js_doc(&node_name, &node.location, &node.js_doc);
+      match node.kind {
+        DocNodeKind::Class => {
+          let class_def = node.class_def.as_ref().unwrap();
+          for overload in &class_def.constructor_overloads {
+            self.lint_js_doc(&node_name, &overload.location, &overload.js_doc);
+          }
+          for constructor in &class_def.constructors {
+            self.lint_js_doc(
+              &node_name,
+              &constructor.location,
+              &constructor.js_doc,
+            );
+          }
+          for property in &class_def.properties {
+            let name = qualified_name(Some(&node_name), &property.name);
+            self.lint_js_doc(&name, &property.location, &property.js_doc);
+          }
+          for method in &class_def.methods {
+            let name = qualified_name(Some(&node_name), &method.name);
+            self.lint_js_doc(&name, &method.location, &method.js_doc);
+          }
+        }
+        DocNodeKind::Enum => {
+          for member in &node.enum_def.as_ref().unwrap().members {
+            let name = qualified_name(Some(&node_name), &member.name);
+            self.lint_js_doc(&name, &member.location, &member.js_doc);
+          }
+        }
+        DocNodeKind::Interface => {
+          let interface_def = node.interface_def.as_ref().unwrap();
+          for property in &interface_def.properties {
+            let name = qualified_name(Some(&node_name), &property.name);
+            self.lint_js_doc(&name, &property.location, &property.js_doc);
+          }
+          for method in
+            interface_def.methods.iter().flat_map(|m| m.signatures())
+          {
+            let name = qualified_name(Some(&node_name), &method.name);
+            self.lint_js_doc(&name, &method.location, &method.js_doc);
+          }
+        }
+        DocNodeKind::Function
+        | DocNodeKind::Import
+        | DocNodeKind::ModuleDoc
+        | DocNodeKind::Namespace
+        | DocNodeKind::TypeAlias
+        | DocNodeKind::Variable => {}
+      }
+      if let Some(namespace_def) = &node.namespace_def {
+        self.lint_nodes(Some(&node_name), &namespace_def.elements);
+      }
+    }
+  }
+
+  fn lint_js_doc(
+    &mut self,
+    node_name: &str,
+    location: &Location,
+    js_doc: &JsDoc,
+  ) {
+    for link in js_doc.links() {
+      if !self.is_resolved(link) {
+        self.diagnostics.push(DocLintDiagnostic {
+          node_name: node_name.to_string(),
+          location: location.clone(),
+          kind: DocLintDiagnosticKind::UnresolvedLink {
+            link: link.to_string(),
+          },
+        });
+      }
+    }
+  }
+
+  /// Whether the link target is a known symbol, or a path to one of the
+  /// nodes or to one of their members, eg. `Foo.bar`, `Foo#bar` or
+  /// `Foo.prototype.bar`. Targets that aren't paths are always resolved.
+  fn is_resolved(&self, link: &str) -> bool {
+    if !SYMBOL_PATH_RE.is_match(link) {
+      return true;
+    }
+    let mut parts = link.split(['.', '#']).filter(|part| *part != "prototype");
+    let root = parts.next().unwrap();
+    if root == "Deno" || self.known_symbols.contains(root) {
+      return true;
+    }
+
+    let mut nodes = self.nodes;
+    let mut name = root;
+    loop {
+      let matching = nodes
+        .iter()
+        .filter(|node| {
+          node.name == name
+            && !matches!(
+              node.kind,
+              DocNodeKind::Import | DocNodeKind::ModuleDoc
+            )
+        })
+        .collect::<Vec<_>>();
+      if matching.is_empty() {
+        return false;
+      }
+      let Some(member) = parts.next() else {
+        return true;
+      };
+      if let Some(namespace) =
+        matching.iter().find_map(|node| node.namespace_def.as_ref())
+      {
+        nodes = &namespace.elements;
+        name = member;
+        continue;
+      }
+      // members of members, eg. the fields of the type of a property, are
+      // not followed
+      return matching.iter().any(|node| has_member(node, member));
+    }
+  }
+}
+
+fn has_member(node: &DocNode, member: &str) -> bool {
+  match node.kind {
+    DocNodeKind::Class => {
+      let class_def = node.class_def.as_ref().unwrap();
+      class_def.properties.iter().any(|p| p.name == member)
+        || class_def.methods.iter().any(|m| m.name == member)
+    }
+    DocNodeKind::Enum => node
+      .enum_def
+      .as_ref()
+      .unwrap()
+      .members
+      .iter()
+      .any(|m| m.name == member),
+    DocNodeKind::Interface => {
+      let interface_def = node.interface_def.as_ref().unwrap();
+      interface_def.properties.iter().any(|p| p.name == member)
+        || interface_def.methods.iter().any(|m| m.name == member)
+    }
+    DocNodeKind::Function
+    | DocNodeKind::Import
+    | DocNodeKind::ModuleDoc
+    | DocNodeKind::Namespace
+    | DocNodeKind::TypeAlias
+    | DocNodeKind::Variable => false,
+  }
+}
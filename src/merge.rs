@@ -0,0 +1,228 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::NamespaceDef;
+use crate::r#enum::EnumDef;
+use crate::variable::VariableDef;
+
+use deno_ast::swc::ast::MethodKind;
+use deno_ast::swc::ast::VarDeclKind;
+
+/// See [`DocNode::merge`].
+pub(crate) fn merge(a: &DocNode, b: &DocNode) -> Option<DocNode> {
+  if a.name != b.name {
+    return None;
+  }
+
+  match (a.kind, b.kind) {
+    (DocNodeKind::Interface, DocNodeKind::Interface) => {
+      let interface_def = merge_interfaces(
+        a.interface_def.as_ref().unwrap(),
+        b.interface_def.as_ref().unwrap(),
+      );
+      Some(DocNode {
+        js_doc: merge_js_docs(&a.js_doc, &b.js_doc),
+        interface_def: Some(interface_def),
+        ..a.clone()
+      })
+    }
+    (DocNodeKind::Enum, DocNodeKind::Enum) => {
+      let mut members = a.enum_def.as_ref().unwrap().members.clone();
+      members.extend(b.enum_def.as_ref().unwrap().members.iter().cloned());
+      Some(DocNode {
+        js_doc: merge_js_docs(&a.js_doc, &b.js_doc),
+        enum_def: Some(EnumDef { members }),
+        ..a.clone()
+      })
+    }
+    (DocNodeKind::Namespace, DocNodeKind::Namespace) => {
+      let a_namespace_def = a.namespace_def.as_ref().unwrap();
+      let b_namespace_def = b.namespace_def.as_ref().unwrap();
+      if a_namespace_def.is_wildcard_module
+        != b_namespace_def.is_wildcard_module
+        || a_namespace_def.is_global_augmentation
+          != b_namespace_def.is_global_augmentation
+      {
+        return None;
+      }
+      let mut elements = a_namespace_def.elements.clone();
+      elements.extend(b_namespace_def.elements.iter().cloned());
+      merge_declarations(&mut elements);
+      Some(DocNode {
+        js_doc: merge_js_docs(&a.js_doc, &b.js_doc),
+        namespace_def: Some(NamespaceDef {
+          elements,
+          ..a_namespace_def.clone()
+        }),
+        ..a.clone()
+      })
+    }
+    (DocNodeKind::Class, DocNodeKind::Namespace) => merge_class_namespace(a, b),
+    (DocNodeKind::Namespace, DocNodeKind::Class) => merge_class_namespace(b, a),
+    (DocNodeKind::Enum | DocNodeKind::Interface, DocNodeKind::Namespace) => {
+      merge_namespace_into(a, b)
+    }
+    (DocNodeKind::Namespace, DocNodeKind::Enum | DocNodeKind::Interface) => {
+      merge_namespace_into(b, a)
+    }
+    _ => None,
+  }
+}
+
+/// The JSDoc of the first declaration that has one, as TypeScript does for
+/// the description of merged declarations.
+fn merge_js_docs(a: &JsDoc, b: &JsDoc) -> JsDoc {
+  if a.is_empty() {
+    b.clone()
+  } else {
+    a.clone()
+  }
+}
+
+fn merge_interfaces(a: &InterfaceDef, b: &InterfaceDef) -> InterfaceDef {
+  let mut interface_def = a.clone();
+  for extends in &b.extends {
+    if !interface_def.extends.contains(extends) {
+      interface_def.extends.push(extends.clone());
+    }
+  }
+  interface_def.methods.extend(b.methods.iter().cloned());
+  interface_def
+    .properties
+    .extend(b.properties.iter().cloned());
+  interface_def
+    .call_signatures
+    .extend(b.call_signatures.iter().cloned());
+  interface_def
+    .index_signatures
+    .extend(b.index_signatures.iter().cloned());
+  // all declarations must have identical type parameters, so those of the
+  // first one are kept
+  interface_def
+}
+
+/// The exported functions and variables of a namespace merged with a class
+/// are its static members. Namespaces which also declare types, or other
+/// kinds of values, can't be represented by the class alone, so they are
+/// kept as a separate node.
+fn merge_class_namespace(
+  class: &DocNode,
+  namespace: &DocNode,
+) -> Option<DocNode> {
+  let namespace_def = namespace.namespace_def.as_ref().unwrap();
+  if namespace_def.is_wildcard_module || namespace_def.is_global_augmentation {
+    return None;
+  }
+  let mut class_def = class.class_def.clone().unwrap();
+
+  for element in &namespace_def.elements {
+    if element.declaration_kind == DeclarationKind::Private {
+      continue;
+    }
+    match element.kind {
+      DocNodeKind::Function => {
+        class_def.methods.push(ClassMethodDef {
+          js_doc: element.js_doc.clone(),
+          accessibility: None,
+          optional: false,
+          is_abstract: false,
+          is_static: true,
+          is_override: false,
+          overrides: None,
+          overridden_by: vec![],
+          name: element.name.clone(),
+          kind: MethodKind::Method,
+          function_def: element.function_def.clone().unwrap(),
+          location: element.location.clone(),
+        });
+      }
+      DocNodeKind::Variable => {
+        let VariableDef { ts_type, kind, .. } =
+          element.variable_def.clone().unwrap();
+        class_def.properties.push(ClassPropertyDef {
+          js_doc: element.js_doc.clone(),
+          ts_type,
+          readonly: kind == VarDeclKind::Const,
+          accessibility: None,
+          decorators: vec![],
+          optional: false,
+          is_abstract: false,
+          is_static: true,
+          is_override: false,
+          is_declare: false,
+          has_initializer: false,
+          initializer_text: None,
+          from_constructor: false,
+          overrides: None,
+          name: element.name.clone(),
+          location: element.location.clone(),
+        });
+      }
+      _ => return None,
+    }
+  }
+
+  Some(DocNode {
+    js_doc: merge_js_docs(&class.js_doc, &namespace.js_doc),
+    class_def: Some(class_def),
+    ..class.clone()
+  })
+}
+
+/// The elements of a namespace merged with an enum or an interface are kept
+/// in the [`DocNode::namespace_def`] of the merged node, whose kind is the
+/// one of the enum or interface, eg. for the functions operating on the
+/// members of an enum.
+fn merge_namespace_into(
+  node: &DocNode,
+  namespace: &DocNode,
+) -> Option<DocNode> {
+  let namespace_def = namespace.namespace_def.as_ref().unwrap();
+  if namespace_def.is_wildcard_module || namespace_def.is_global_augmentation {
+    return None;
+  }
+  let namespace_def = match &node.namespace_def {
+    Some(existing) => {
+      let mut elements = existing.elements.clone();
+      elements.extend(namespace_def.elements.iter().cloned());
+      merge_declarations(&mut elements);
+      NamespaceDef {
+        elements,
+        ..existing.clone()
+      }
+    }
+    None => namespace_def.clone(),
+  };
+
+  Some(DocNode {
+    js_doc: merge_js_docs(&node.js_doc, &namespace.js_doc),
+    namespace_def: Some(namespace_def),
+    ..node.clone()
+  })
+}
+
+/// Replaces the declarations that merge into a single entity, see
+/// [`DocNode::merge`], by the merged node, at the position of the first one.
+/// Namespaces are merged recursively.
+pub(crate) fn merge_declarations(doc_nodes: &mut Vec<DocNode>) {
+  let mut merged: Vec<DocNode> = Vec::with_capacity(doc_nodes.len());
+  'nodes: for mut doc_node in doc_nodes.drain(..) {
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      merge_declarations(&mut namespace_def.elements);
+    }
+    for existing in merged.iter_mut() {
+      if let Some(merged_node) = merge(existing, &doc_node) {
+        *existing = merged_node;
+        continue 'nodes;
+      }
+    }
+    merged.push(doc_node);
+  }
+  *doc_nodes = merged;
+}
@@ -4,10 +4,40 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::ts_type_param::apply_template_tags;
+use crate::util::swc::is_false;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct NamespaceDef {
   pub elements: Vec<DocNode>,
+  /// Whether this is an ambient module declaration with a wildcard pattern,
+  /// eg. `declare module "*.svg"`, in which case the name is the pattern.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_wildcard_module: bool,
+  /// Whether this is a `declare global` block, which augments global
+  /// declarations like the `Window` interface, in which case the name is
+  /// `global`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_global_augmentation: bool,
+  /// The qualified name of the namespace that declares this one, eg.
+  /// `Outer.Middle` for `Inner` in
+  /// `namespace Outer { namespace Middle { namespace Inner {} } }`, which is
+  /// not set for top level namespaces.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub parent: Option<String>,
+}
+
+impl NamespaceDef {
+  /// The name of the namespace qualified with the names of the namespaces
+  /// that declare it, eg. `Outer.Middle.Inner`.
+  pub fn qualified_name(&self, name: &str) -> String {
+    match &self.parent {
+      Some(parent) => format!("{parent}.{name}"),
+      None => name.to_string(),
+    }
+  }
 }
 
 #[derive(
@@ -37,6 +67,36 @@ pub enum DocNodeKind {
   Variable,
 }
 
+/// Every kind of node along with its label, see [`DocNodeKind::as_str`].
+pub const DOC_NODE_KINDS: &[(&str, DocNodeKind)] = &[
+  ("class", DocNodeKind::Class),
+  ("enum", DocNodeKind::Enum),
+  ("function", DocNodeKind::Function),
+  ("import", DocNodeKind::Import),
+  ("interface", DocNodeKind::Interface),
+  ("moduleDoc", DocNodeKind::ModuleDoc),
+  ("namespace", DocNodeKind::Namespace),
+  ("typeAlias", DocNodeKind::TypeAlias),
+  ("variable", DocNodeKind::Variable),
+];
+
+impl DocNodeKind {
+  /// The label of the kind, as it is serialized, eg. `typeAlias`.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DocNodeKind::Class => "class",
+      DocNodeKind::Enum => "enum",
+      DocNodeKind::Function => "function",
+      DocNodeKind::Import => "import",
+      DocNodeKind::Interface => "interface",
+      DocNodeKind::ModuleDoc => "moduleDoc",
+      DocNodeKind::Namespace => "namespace",
+      DocNodeKind::TypeAlias => "typeAlias",
+      DocNodeKind::Variable => "variable",
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
@@ -87,13 +147,50 @@ pub enum ReexportKind {
 pub struct Reexport {
   pub kind: ReexportKind,
   pub src: String,
+  /// Whether only the type meaning is re-exported, as with
+  /// `export type * from "./mod.ts"` or `export { type Foo } from "./mod.ts"`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_type_only: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ModuleDoc {
+  /// The specifier of the module, after following redirects.
+  #[serde(default)]
+  pub filename: String,
+  /// The hex encoded SHA-256 hash of the source text of the module, which
+  /// changes whenever the module does.
+  #[serde(default)]
+  pub checksum: String,
   pub definitions: Vec<DocNode>,
   pub reexports: Vec<Reexport>,
+  /// Whether the file is a module by declaration, ie. it contains at least one
+  /// `import` or `export` declaration, even if it exports nothing, as is the
+  /// case for `export {}`.
+  #[serde(default)]
+  pub is_module: bool,
+  /// The packages and libraries referenced by the triple-slash directives of
+  /// the file, eg. `@types/node` for `/// <reference types="@types/node" />`
+  /// or `dom` for `/// <reference lib="dom" />`, which bring global types in
+  /// scope.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub references: Vec<String>,
+  /// The specifiers of the other modules of the graph that re-export symbols
+  /// of this one, the inverse of their `reexports`.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub re_exported_by: Vec<String>,
+  /// The first JSDoc comment of the module with a `@packageDocumentation`
+  /// tag, which documents the package as a whole rather than any symbol.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub package_doc: Option<PackageDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageDoc {
+  pub description: String,
+  pub tags: JsDoc,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -111,6 +208,156 @@ pub enum DeclarationKind {
   Export,
 }
 
+/// Whether a node is exported with its value, or only with its type, eg.
+/// through `export type * from "./mod.ts"` or `export { type Foo }`.
+#[derive(
+  Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportKind {
+  #[default]
+  Value,
+  Type,
+}
+
+impl ExportKind {
+  pub fn is_value(&self) -> bool {
+    *self == ExportKind::Value
+  }
+}
+
+/// The visibility of a symbol as declared by a `@public`, `@protected`,
+/// `@private` or `@package` JSDoc tag, which is public by default.
+#[derive(
+  Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Visibility {
+  #[default]
+  Public,
+  Protected,
+  Private,
+  PackagePrivate,
+}
+
+impl Visibility {
+  pub fn from_js_doc(js_doc: &JsDoc) -> Self {
+    js_doc
+      .tags
+      .iter()
+      .find_map(|tag| match tag {
+        JsDocTag::Public => Some(Visibility::Public),
+        JsDocTag::Protected => Some(Visibility::Protected),
+        JsDocTag::Private => Some(Visibility::Private),
+        JsDocTag::Package => Some(Visibility::PackagePrivate),
+        _ => None,
+      })
+      .unwrap_or_default()
+  }
+
+  pub fn is_public(&self) -> bool {
+    *self == Visibility::Public
+  }
+}
+
+/// Whether a symbol is stable or experimental, as declared by an
+/// `@experimental` JSDoc tag on the symbol or on the namespace or module
+/// that declares it, see [`StabilitySource`].
+#[derive(
+  Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Stability {
+  #[default]
+  Stable,
+  Experimental,
+}
+
+impl Stability {
+  pub fn from_js_doc(js_doc: &JsDoc) -> Self {
+    if js_doc.tags.contains(&JsDocTag::Experimental) {
+      Stability::Experimental
+    } else {
+      Stability::Stable
+    }
+  }
+
+  pub fn is_stable(&self) -> bool {
+    *self == Stability::Stable
+  }
+}
+
+/// Where the stability of an experimental symbol was declared.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum StabilitySource {
+  /// The JSDoc of the symbol itself.
+  Direct,
+  /// The JSDoc of a namespace that contains the symbol.
+  InheritedFromNamespace,
+  /// The module documentation of the module that declares the symbol.
+  InheritedFromModule,
+}
+
+/// Sets the stability of the nodes declared by a module, and of the
+/// elements of their namespaces at any depth, from their own `@experimental`
+/// tags or from the closest namespace or module documentation that has one.
+pub(crate) fn propagate_stability(doc_nodes: &mut [DocNode]) {
+  fn propagate(doc_node: &mut DocNode, inherited: Option<StabilitySource>) {
+    if Stability::from_js_doc(&doc_node.js_doc) == Stability::Experimental {
+      doc_node.stability = Stability::Experimental;
+      doc_node.stability_source = Some(StabilitySource::Direct);
+    } else if let Some(source) = inherited {
+      if doc_node.stability.is_stable() {
+        doc_node.stability = Stability::Experimental;
+        doc_node.stability_source = Some(source);
+      }
+    }
+
+    let inherited = match doc_node.stability_source {
+      Some(
+        StabilitySource::Direct | StabilitySource::InheritedFromNamespace,
+      ) => Some(StabilitySource::InheritedFromNamespace),
+      Some(StabilitySource::InheritedFromModule) | None => inherited,
+    };
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      for element in &mut namespace_def.elements {
+        propagate(element, inherited);
+      }
+    }
+  }
+
+  let module_is_experimental = doc_nodes.iter().any(|doc_node| {
+    doc_node.kind == DocNodeKind::ModuleDoc
+      && Stability::from_js_doc(&doc_node.js_doc) == Stability::Experimental
+  });
+  for doc_node in doc_nodes {
+    if doc_node.kind == DocNodeKind::ModuleDoc {
+      continue;
+    }
+    propagate(
+      doc_node,
+      module_is_experimental.then_some(StabilitySource::InheritedFromModule),
+    );
+  }
+}
+
+/// Sets the [`NamespaceDef::parent`] of the namespaces among the nodes and
+/// the elements of their namespaces at any depth, `parent` being the
+/// qualified name of the namespace that declares the nodes, if any.
+pub(crate) fn set_namespace_parents(
+  doc_nodes: &mut [DocNode],
+  parent: Option<&str>,
+) {
+  for doc_node in doc_nodes {
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      namespace_def.parent = parent.map(ToString::to_string);
+      let qualified_name = namespace_def.qualified_name(&doc_node.name);
+      set_namespace_parents(&mut namespace_def.elements, Some(&qualified_name));
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DocNode {
@@ -118,8 +365,21 @@ pub struct DocNode {
   pub name: String,
   pub location: Location,
   pub declaration_kind: DeclarationKind,
+  #[serde(skip_serializing_if = "ExportKind::is_value", default)]
+  pub export_kind: ExportKind,
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  #[serde(skip_serializing_if = "Visibility::is_public", default)]
+  pub visibility: Visibility,
+  #[serde(skip_serializing_if = "Stability::is_stable", default)]
+  pub stability: Stability,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub stability_source: Option<StabilitySource>,
+  /// The specifier of the module that declares the node, when it is
+  /// re-exported from another module, eg. through `export * from "./b.ts"`,
+  /// see [`DocParser::parse_with_reexports`](crate::DocParser::parse_with_reexports).
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub re_export_source: Option<String>,
 
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub function_def: Option<super::function::FunctionDef>,
@@ -136,6 +396,8 @@ pub struct DocNode {
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub type_alias_def: Option<super::type_alias::TypeAliasDef>,
 
+  /// Also set on enums and interfaces merged with a namespace, see
+  /// [`DocNode::merge`].
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub namespace_def: Option<NamespaceDef>,
 
@@ -146,12 +408,37 @@ pub struct DocNode {
   pub import_def: Option<ImportDef>,
 }
 
+/// The reason a node can't be built, see [`DocNode::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocBuildError {
+  MissingName,
+  MissingLocation,
+  /// The definition matching the kind of the node, eg. `function_def` for a
+  /// function, is not set.
+  MissingDef(DocNodeKind),
+}
+
+impl std::error::Error for DocBuildError {}
+
+impl std::fmt::Display for DocBuildError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::MissingName => write!(f, "the node has no name"),
+      Self::MissingLocation => write!(f, "the node has no location"),
+      Self::MissingDef(kind) => {
+        write!(f, "the {} node has no definition", kind.as_str())
+      }
+    }
+  }
+}
+
 impl Default for DocNode {
   fn default() -> Self {
     Self {
       kind: DocNodeKind::ModuleDoc,
       name: "".to_string(),
       declaration_kind: DeclarationKind::Private,
+      export_kind: ExportKind::Value,
       location: Location {
         filename: "".to_string(),
         line: 0,
@@ -159,6 +446,10 @@ impl Default for DocNode {
         byte_index: 0,
       },
       js_doc: JsDoc::default(),
+      visibility: Visibility::Public,
+      stability: Stability::Stable,
+      stability_source: None,
+      re_export_source: None,
       function_def: None,
       variable_def: None,
       enum_def: None,
@@ -188,13 +479,16 @@ impl DocNode {
     location: Location,
     declaration_kind: DeclarationKind,
     js_doc: JsDoc,
-    fn_def: super::function::FunctionDef,
+    mut fn_def: super::function::FunctionDef,
   ) -> Self {
+    apply_template_tags(&js_doc, &mut fn_def.type_params);
+    fn_def.apply_returns_tag(&js_doc);
     Self {
       kind: DocNodeKind::Function,
       name,
       location,
       declaration_kind,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       function_def: Some(fn_def),
       ..Default::default()
@@ -206,13 +500,17 @@ impl DocNode {
     location: Location,
     declaration_kind: DeclarationKind,
     js_doc: JsDoc,
-    var_def: super::variable::VariableDef,
+    mut var_def: super::variable::VariableDef,
   ) -> Self {
+    if let Some(ts_type) = super::variable::ts_type_from_proxy_tag(&js_doc) {
+      var_def.ts_type = Some(ts_type);
+    }
     Self {
       kind: DocNodeKind::Variable,
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       variable_def: Some(var_def),
       ..Default::default()
@@ -231,6 +529,7 @@ impl DocNode {
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       enum_def: Some(enum_def),
       ..Default::default()
@@ -242,13 +541,15 @@ impl DocNode {
     location: Location,
     declaration_kind: DeclarationKind,
     js_doc: JsDoc,
-    class_def: super::class::ClassDef,
+    mut class_def: super::class::ClassDef,
   ) -> Self {
+    apply_template_tags(&js_doc, &mut class_def.type_params);
     Self {
       kind: DocNodeKind::Class,
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       class_def: Some(class_def),
       ..Default::default()
@@ -260,13 +561,15 @@ impl DocNode {
     location: Location,
     declaration_kind: DeclarationKind,
     js_doc: JsDoc,
-    type_alias_def: super::type_alias::TypeAliasDef,
+    mut type_alias_def: super::type_alias::TypeAliasDef,
   ) -> Self {
+    apply_template_tags(&js_doc, &mut type_alias_def.type_params);
     Self {
       kind: DocNodeKind::TypeAlias,
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       type_alias_def: Some(type_alias_def),
       ..Default::default()
@@ -285,6 +588,7 @@ impl DocNode {
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       namespace_def: Some(namespace_def),
       ..Default::default()
@@ -296,13 +600,15 @@ impl DocNode {
     location: Location,
     declaration_kind: DeclarationKind,
     js_doc: JsDoc,
-    interface_def: super::interface::InterfaceDef,
+    mut interface_def: super::interface::InterfaceDef,
   ) -> Self {
+    apply_template_tags(&js_doc, &mut interface_def.type_params);
     Self {
       kind: DocNodeKind::Interface,
       name,
       declaration_kind,
       location,
+      visibility: Visibility::from_js_doc(&js_doc),
       js_doc,
       interface_def: Some(interface_def),
       ..Default::default()
@@ -326,6 +632,111 @@ impl DocNode {
     }
   }
 
+  /// Derives again the fields that the constructors derive from the JSDoc
+  /// of the node, ie. its visibility, the descriptions of its `@template`
+  /// and `@returns` tags and the type of its `@proxy` tag, after its JSDoc
+  /// is replaced. The stability is derived by [`propagate_stability`].
+  pub(crate) fn apply_js_doc_tags(&mut self) {
+    let js_doc = &self.js_doc;
+    self.visibility = Visibility::from_js_doc(js_doc);
+    if let Some(fn_def) = &mut self.function_def {
+      apply_template_tags(js_doc, &mut fn_def.type_params);
+      fn_def.apply_returns_tag(js_doc);
+    }
+    if let Some(var_def) = &mut self.variable_def {
+      if let Some(ts_type) = super::variable::ts_type_from_proxy_tag(js_doc) {
+        var_def.ts_type = Some(ts_type);
+      }
+    }
+    if let Some(class_def) = &mut self.class_def {
+      apply_template_tags(js_doc, &mut class_def.type_params);
+    }
+    if let Some(type_alias_def) = &mut self.type_alias_def {
+      apply_template_tags(js_doc, &mut type_alias_def.type_params);
+    }
+    if let Some(interface_def) = &mut self.interface_def {
+      apply_template_tags(js_doc, &mut interface_def.type_params);
+    }
+  }
+
+  /// Sets the name of the node, see [`DocNode::build`].
+  pub fn with_name(self, name: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      ..self
+    }
+  }
+
+  /// Sets the location of the node, see [`DocNode::build`].
+  pub fn with_location(self, location: Location) -> Self {
+    Self { location, ..self }
+  }
+
+  /// Sets the declaration kind of the node, see [`DocNode::build`].
+  pub fn with_declaration_kind(
+    self,
+    declaration_kind: DeclarationKind,
+  ) -> Self {
+    Self {
+      declaration_kind,
+      ..self
+    }
+  }
+
+  /// Sets the JSDoc of the node, and the visibility it implies, see
+  /// [`DocNode::build`].
+  pub fn with_js_doc(self, js_doc: JsDoc) -> Self {
+    Self {
+      visibility: Visibility::from_js_doc(&js_doc),
+      js_doc,
+      ..self
+    }
+  }
+
+  /// Checks that a node constructed programmatically rather than parsed, eg.
+  ///
+  /// ```ignore
+  /// DocNode {
+  ///   kind: DocNodeKind::Function,
+  ///   function_def: Some(function_def),
+  ///   ..Default::default()
+  /// }
+  /// .with_name("add")
+  /// .with_location(location)
+  /// .build()?
+  /// ```
+  ///
+  /// has the fields that parsed nodes of its kind always have: a name, a
+  /// location, and the definition matching its kind.
+  pub fn build(self) -> Result<DocNode, DocBuildError> {
+    if self.name.is_empty() && self.kind != DocNodeKind::ModuleDoc {
+      return Err(DocBuildError::MissingName);
+    }
+    if self.location.filename.is_empty() {
+      return Err(DocBuildError::MissingLocation);
+    }
+    let has_def = match self.kind {
+      DocNodeKind::ModuleDoc => true,
+      DocNodeKind::Function => self.function_def.is_some(),
+      DocNodeKind::Variable => self.variable_def.is_some(),
+      DocNodeKind::Enum => self.enum_def.is_some(),
+      DocNodeKind::Class => self.class_def.is_some(),
+      DocNodeKind::TypeAlias => self.type_alias_def.is_some(),
+      DocNodeKind::Namespace => self.namespace_def.is_some(),
+      DocNodeKind::Interface => self.interface_def.is_some(),
+      DocNodeKind::Import => self.import_def.is_some(),
+    };
+    if !has_def {
+      return Err(DocBuildError::MissingDef(self.kind));
+    }
+    Ok(self)
+  }
+
+  /// The label of the kind of the node, see [`DocNodeKind::as_str`].
+  pub fn kind_str(&self) -> &'static str {
+    self.kind.as_str()
+  }
+
   pub fn get_name(&self) -> &str {
     let default_name = match self.kind {
       DocNodeKind::Class => self.class_def.as_ref().unwrap().def_name.as_ref(),
@@ -345,4 +756,219 @@ impl DocNode {
 
     default_name.unwrap_or(&self.name)
   }
+
+  /// The type given by a `@satisfies` JSDoc tag, which documents that the
+  /// symbol conforms to a type without it being declared as that type.
+  pub fn js_doc_satisfies(&self) -> Option<&str> {
+    self.js_doc.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Satisfies { type_ref, .. } => Some(type_ref.as_str()),
+      _ => None,
+    })
+  }
+
+  /// The main description of the JSDoc of the node, without its tags.
+  pub fn description(&self) -> Option<&str> {
+    self.js_doc.doc.as_deref()
+  }
+
+  /// The user-defined labels of the node, from the `@tag` tags of its JSDoc,
+  /// eg. `["database", "networking"]`.
+  pub fn tags(&self) -> Vec<&str> {
+    self
+      .js_doc
+      .tags
+      .iter()
+      .filter_map(|tag| match tag {
+        JsDocTag::Tag { doc } => Some(doc.as_str()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// The contents of the `@example` tags of the JSDoc of the node.
+  pub fn examples(&self) -> Vec<&str> {
+    self.js_doc.examples()
+  }
+
+  /// The specifier of the module that contains the symbol.
+  pub fn module_path(&self) -> &str {
+    &self.location.filename
+  }
+
+  /// The node as JSON without whitespace and without the fields that are
+  /// `null`, which are restored as `None` when deserializing.
+  pub fn to_json_compact(&self) -> String {
+    let mut value = serde_json::to_value(self).unwrap();
+    strip_null_fields(&mut value);
+    value.to_string()
+  }
+
+  /// The symbols among `all_nodes` that are worth suggesting alongside this
+  /// one, in order: those referenced by its `@see` tags, those declared in
+  /// the same file that share a type reference with it or reference each
+  /// other, and those whose names start with the same camelCase word. Nodes
+  /// with the same name as this one are not included.
+  pub fn related_symbols<'a>(
+    &self,
+    all_nodes: &'a [DocNode],
+  ) -> Vec<&'a DocNode> {
+    crate::related::related_symbols(self, all_nodes)
+  }
+
+  /// Merges two declarations of the same name into the single entity they
+  /// form through TypeScript's declaration merging: the members of merged
+  /// interfaces, of merged enums and of merged namespaces are concatenated,
+  /// the exported functions and variables of a namespace merged with a class
+  /// become static members of the class, and a namespace merged with an enum
+  /// or an interface is kept as the [`DocNode::namespace_def`] of the enum or
+  /// interface. Returns `None` for any other pair, including namespaces
+  /// merged with a class which also declare types, as the result can't be
+  /// represented by a single node.
+  pub fn merge(a: &DocNode, b: &DocNode) -> Option<DocNode> {
+    crate::merge::merge(a, b)
+  }
+
+  /// Whether the two nodes can be overloads of the same function, ie. they
+  /// are functions of the same name with compatible signatures, see
+  /// [`FunctionDef::has_compatible_signature`].
+  ///
+  /// [`FunctionDef::has_compatible_signature`]: crate::function::FunctionDef::has_compatible_signature
+  pub fn matches_overload(&self, other: &DocNode) -> bool {
+    if self.name != other.name {
+      return false;
+    }
+    match (&self.function_def, &other.function_def) {
+      (Some(a), Some(b)) => a.has_compatible_signature(b),
+      _ => false,
+    }
+  }
+
+  /// The names of all the types referenced by the node's definition, eg. in
+  /// its parameters, return types, properties, type parameter constraints
+  /// and `extends` clauses, once each and in the order they first appear.
+  /// References to type parameters are included.
+  pub fn all_type_refs(&self) -> Vec<String> {
+    crate::related::all_type_refs(self)
+  }
+
+  /// Strips the node down to its name, kind, location, declaration kind,
+  /// stability and visibility, the first sentence of its JSDoc description
+  /// and the signature of its definition, without the members of classes,
+  /// interfaces and enums, see [`DocMode::Summary`]. The elements of
+  /// namespaces are summaries too.
+  ///
+  /// [`DocMode::Summary`]: crate::DocMode::Summary
+  pub fn into_summary(self) -> DocNode {
+    DocNode {
+      kind: self.kind,
+      name: self.name,
+      location: self.location,
+      declaration_kind: self.declaration_kind,
+      export_kind: self.export_kind,
+      js_doc: JsDoc {
+        doc: self
+          .js_doc
+          .doc
+          .as_deref()
+          .and_then(crate::js_doc::first_sentence),
+        tags: vec![],
+      },
+      visibility: self.visibility,
+      stability: self.stability,
+      stability_source: self.stability_source,
+      re_export_source: self.re_export_source,
+      function_def: self.function_def.map(|function_def| {
+        super::function::FunctionDef {
+          return_type_doc: None,
+          body_span: None,
+          decorators: vec![],
+          ..function_def
+        }
+      }),
+      variable_def: self.variable_def,
+      enum_def: self
+        .enum_def
+        .map(|_| super::r#enum::EnumDef { members: vec![] }),
+      class_def: self.class_def.map(|class_def| super::class::ClassDef {
+        constructors: vec![],
+        constructor_overloads: vec![],
+        properties: vec![],
+        index_signatures: vec![],
+        methods: vec![],
+        decorators: vec![],
+        body_source: None,
+        ..class_def
+      }),
+      type_alias_def: self.type_alias_def,
+      namespace_def: self.namespace_def.map(|namespace_def| NamespaceDef {
+        elements: namespace_def
+          .elements
+          .into_iter()
+          .map(DocNode::into_summary)
+          .collect(),
+        ..namespace_def
+      }),
+      interface_def: self.interface_def.map(|interface_def| {
+        super::interface::InterfaceDef {
+          methods: vec![],
+          properties: vec![],
+          call_signatures: vec![],
+          index_signatures: vec![],
+          ..interface_def
+        }
+      }),
+      import_def: self.import_def,
+    }
+  }
+
+  /// Whether the node is callable, ie. a function.
+  pub fn is_function_like(&self) -> bool {
+    matches!(self.kind, DocNodeKind::Function)
+  }
+
+  /// Whether the node declares a type. Enums declare both a type and a value.
+  pub fn is_type_like(&self) -> bool {
+    matches!(
+      self.kind,
+      DocNodeKind::Enum | DocNodeKind::Interface | DocNodeKind::TypeAlias
+    )
+  }
+
+  /// Whether the node declares a value that exists at runtime.
+  pub fn is_value_like(&self) -> bool {
+    matches!(
+      self.kind,
+      DocNodeKind::Class
+        | DocNodeKind::Enum
+        | DocNodeKind::Function
+        | DocNodeKind::Namespace
+        | DocNodeKind::Variable
+    )
+  }
+}
+
+/// Writes the nodes as a JSON array in the format of
+/// [`DocNode::to_json_compact`].
+pub fn write_doc_nodes_compact(
+  nodes: &[DocNode],
+  writer: impl std::io::Write,
+) -> serde_json::Result<()> {
+  let mut value = serde_json::to_value(nodes)?;
+  strip_null_fields(&mut value);
+  serde_json::to_writer(writer, &value)
+}
+
+fn strip_null_fields(value: &mut serde_json::Value) {
+  match value {
+    serde_json::Value::Object(map) => {
+      map.retain(|_, value| !value.is_null());
+      map.values_mut().for_each(strip_null_fields);
+    }
+    // nulls in arrays are kept, as they are positional, eg. the holes of
+    // array destructuring patterns
+    serde_json::Value::Array(values) => {
+      values.iter_mut().for_each(strip_null_fields);
+    }
+    _ => {}
+  }
 }
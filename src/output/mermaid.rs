@@ -0,0 +1,212 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::display::with_display_options;
+use crate::display::SliceDisplayer;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::params::ParamDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type_param::TsTypeParamDef;
+use crate::DisplayOptions;
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use std::fmt::Write;
+
+/// Renders the classes and interfaces among `nodes` as a Mermaid
+/// `classDiagram`, listing their public properties and methods, with arrows
+/// from the classes and interfaces they extend and from the interfaces they
+/// implement.
+pub fn generate_mermaid_class_diagram(nodes: &[DocNode]) -> String {
+  with_display_options(DisplayOptions::default(), || {
+    let mut diagram = "classDiagram\n".to_string();
+    let mut relations = vec![];
+
+    for node in nodes {
+      match node.kind {
+        DocNodeKind::Class => {
+          let class_def = node.class_def.as_ref().unwrap();
+          writeln!(
+            diagram,
+            "  class {}{} {{",
+            node.name,
+            generics(&class_def.type_params)
+          )
+          .unwrap();
+          if class_def.is_abstract {
+            writeln!(diagram, "    <<abstract>>").unwrap();
+          }
+          for property in &class_def.properties {
+            if !is_public(property.accessibility, &property.name) {
+              continue;
+            }
+            writeln!(
+              diagram,
+              "    {}{}",
+              attribute(&property.name, property.ts_type.as_ref()),
+              if property.is_static { "$" } else { "" }
+            )
+            .unwrap();
+          }
+          for method in &class_def.methods {
+            if !is_public(method.accessibility, &method.name) {
+              continue;
+            }
+            let function_def = &method.function_def;
+            let line = match method.kind {
+              MethodKind::Method => method_signature(
+                &method.name,
+                &function_def.signature_params(),
+                function_def.return_type.as_ref(),
+              ),
+              MethodKind::Getter => {
+                attribute(&method.name, function_def.return_type.as_ref())
+              }
+              MethodKind::Setter => {
+                let has_getter = class_def.methods.iter().any(|other| {
+                  other.name == method.name && other.kind == MethodKind::Getter
+                });
+                if has_getter {
+                  continue;
+                }
+                attribute(
+                  &method.name,
+                  function_def
+                    .params
+                    .first()
+                    .and_then(|param| param.ts_type.as_ref()),
+                )
+              }
+            };
+            let classifier = if method.is_abstract {
+              "*"
+            } else if method.is_static {
+              "$"
+            } else {
+              ""
+            };
+            writeln!(diagram, "    {line}{classifier}").unwrap();
+          }
+          writeln!(diagram, "  }}").unwrap();
+
+          if let Some(extends) = &class_def.extends {
+            relations.push(format!("  {extends} <|-- {}", node.name));
+          }
+          for implements in &class_def.implements {
+            relations.push(format!(
+              "  {} <|.. {}",
+              type_name(implements),
+              node.name
+            ));
+          }
+        }
+        DocNodeKind::Interface => {
+          let interface_def = node.interface_def.as_ref().unwrap();
+          writeln!(
+            diagram,
+            "  class {}{} {{",
+            node.name,
+            generics(&interface_def.type_params)
+          )
+          .unwrap();
+          writeln!(diagram, "    <<interface>>").unwrap();
+          for property in &interface_def.properties {
+            writeln!(
+              diagram,
+              "    {}",
+              attribute(&property.name, property.ts_type.as_ref())
+            )
+            .unwrap();
+          }
+          for method in &interface_def.methods {
+            writeln!(
+              diagram,
+              "    {}",
+              method_signature(
+                &method.name,
+                &method.signature_params(),
+                method.return_type.as_ref()
+              )
+            )
+            .unwrap();
+          }
+          writeln!(diagram, "  }}").unwrap();
+
+          for extends in &interface_def.extends {
+            relations.push(format!(
+              "  {} <|-- {}",
+              type_name(extends),
+              node.name
+            ));
+          }
+        }
+        _ => {}
+      }
+    }
+
+    for relation in relations {
+      writeln!(diagram, "{relation}").unwrap();
+    }
+    diagram
+  })
+}
+
+fn is_public(accessibility: Option<Accessibility>, name: &str) -> bool {
+  !matches!(
+    accessibility,
+    Some(Accessibility::Private | Accessibility::Protected)
+  ) && !name.starts_with('#')
+}
+
+/// The type parameters in Mermaid's generic notation, eg. `~T~`.
+fn generics(type_params: &[TsTypeParamDef]) -> String {
+  if type_params.is_empty() {
+    return String::new();
+  }
+  let names = type_params
+    .iter()
+    .map(|type_param| type_param.name.as_str())
+    .collect::<Vec<_>>();
+  format!("~{}~", names.join(", "))
+}
+
+fn attribute(name: &str, ts_type: Option<&TsTypeDef>) -> String {
+  match ts_type {
+    Some(ts_type) => format!("+{name}: {}", escape(&ts_type.to_string())),
+    None => format!("+{name}"),
+  }
+}
+
+fn method_signature(
+  name: &str,
+  params: &[ParamDef],
+  return_type: Option<&TsTypeDef>,
+) -> String {
+  let params = escape(&SliceDisplayer::new(params, ", ", false).to_string());
+  match return_type {
+    Some(return_type) => {
+      format!("+{name}({params}) {}", escape(&return_type.to_string()))
+    }
+    None => format!("+{name}({params})"),
+  }
+}
+
+/// The name of the class or interface referenced by a heritage clause,
+/// without its type arguments.
+fn type_name(ts_type: &TsTypeDef) -> &str {
+  ts_type
+    .type_ref
+    .as_ref()
+    .map_or(&ts_type.repr, |type_ref| &type_ref.type_name)
+}
+
+/// Replaces the characters that are significant to Mermaid within a class
+/// body: type arguments use its `~T~` notation and braces are written as
+/// entity codes.
+fn escape(text: &str) -> String {
+  text
+    .replace(['<', '>'], "~")
+    .replace('{', "#123;")
+    .replace('}', "#125;")
+    .replace('\n', " ")
+}
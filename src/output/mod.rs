@@ -0,0 +1,5 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+mod mermaid;
+
+pub use mermaid::generate_mermaid_class_diagram;
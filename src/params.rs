@@ -5,7 +5,9 @@ use crate::decorators::DecoratorDef;
 use crate::display::display_optional;
 use crate::display::SliceDisplayer;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefOptions;
 
+use deno_ast::swc::ast::BindingIdent;
 use deno_ast::swc::ast::ObjectPatProp;
 use deno_ast::swc::ast::Pat;
 use deno_ast::swc::ast::TsFnParam;
@@ -50,6 +52,11 @@ pub struct ParamDef {
   #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub decorators: Vec<DecoratorDef>,
   pub ts_type: Option<TsTypeDef>,
+  /// The source text of the default value of the parameter, eg. `0` for
+  /// `x = 0` or `{ verbose: false }` for `options = { verbose: false }`,
+  /// which is only set for [`ParamPatternDef::Assign`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub default_value_text: Option<String>,
 }
 
 impl Display for ParamDef {
@@ -144,12 +151,13 @@ impl Display for ObjectPatPropDef {
 
 pub fn ident_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   ident: &deno_ast::swc::ast::BindingIdent,
 ) -> ParamDef {
   let ts_type = ident
     .type_ann
     .as_deref()
-    .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+    .map(|type_ann| TsTypeDef::new(parsed_source, options, &type_ann.type_ann));
 
   ParamDef {
     pattern: ParamPatternDef::Identifier {
@@ -158,29 +166,33 @@ pub fn ident_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    default_value_text: None,
   }
 }
 
 fn rest_pat_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   rest_pat: &deno_ast::swc::ast::RestPat,
 ) -> ParamDef {
   let ts_type = rest_pat
     .type_ann
     .as_deref()
-    .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+    .map(|type_ann| TsTypeDef::new(parsed_source, options, &type_ann.type_ann));
 
   ParamDef {
     pattern: ParamPatternDef::Rest {
-      arg: Box::new(pat_to_param_def(parsed_source, &rest_pat.arg)),
+      arg: Box::new(pat_to_param_def(parsed_source, options, &rest_pat.arg)),
     },
     decorators: Vec::new(),
     ts_type,
+    default_value_text: None,
   }
 }
 
 fn object_pat_prop_to_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   object_pat_prop: &ObjectPatProp,
 ) -> ObjectPatPropDef {
   match object_pat_prop {
@@ -190,27 +202,32 @@ fn object_pat_prop_to_def(
     },
     ObjectPatProp::KeyValue(keyvalue) => ObjectPatPropDef::KeyValue {
       key: prop_name_to_string(parsed_source, &keyvalue.key),
-      value: Box::new(pat_to_param_def(parsed_source, &keyvalue.value)),
+      value: Box::new(pat_to_param_def(
+        parsed_source,
+        options,
+        &keyvalue.value,
+      )),
     },
     ObjectPatProp::Rest(rest) => ObjectPatPropDef::Rest {
-      arg: Box::new(pat_to_param_def(parsed_source, &rest.arg)),
+      arg: Box::new(pat_to_param_def(parsed_source, options, &rest.arg)),
     },
   }
 }
 
 fn object_pat_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   object_pat: &deno_ast::swc::ast::ObjectPat,
 ) -> ParamDef {
   let props = object_pat
     .props
     .iter()
-    .map(|prop| object_pat_prop_to_def(parsed_source, prop))
+    .map(|prop| object_pat_prop_to_def(parsed_source, options, prop))
     .collect::<Vec<_>>();
   let ts_type = object_pat
     .type_ann
     .as_deref()
-    .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+    .map(|type_ann| TsTypeDef::new(parsed_source, options, &type_ann.type_ann));
 
   ParamDef {
     pattern: ParamPatternDef::Object {
@@ -219,22 +236,28 @@ fn object_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    default_value_text: None,
   }
 }
 
 fn array_pat_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   array_pat: &deno_ast::swc::ast::ArrayPat,
 ) -> ParamDef {
   let elements = array_pat
     .elems
     .iter()
-    .map(|elem| elem.as_ref().map(|e| pat_to_param_def(parsed_source, e)))
+    .map(|elem| {
+      elem
+        .as_ref()
+        .map(|e| pat_to_param_def(parsed_source, options, e))
+    })
     .collect::<Vec<Option<_>>>();
   let ts_type = array_pat
     .type_ann
     .as_deref()
-    .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+    .map(|type_ann| TsTypeDef::new(parsed_source, options, &type_ann.type_ann));
 
   ParamDef {
     pattern: ParamPatternDef::Array {
@@ -243,18 +266,21 @@ fn array_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    default_value_text: None,
   }
 }
 
 pub fn assign_pat_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   assign_pat: &deno_ast::swc::ast::AssignPat,
 ) -> ParamDef {
-  let mut left = pat_to_param_def(parsed_source, &assign_pat.left);
+  let mut left = pat_to_param_def(parsed_source, options, &assign_pat.left);
 
-  if left.ts_type.is_none() {
+  if left.ts_type.is_none() && !options.isolated_declarations {
     left.ts_type = crate::ts_type::infer_ts_type_from_expr(
       parsed_source,
+      options,
       &assign_pat.right,
       false,
     );
@@ -267,31 +293,127 @@ pub fn assign_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type: None,
+    default_value_text: Some(
+      assign_pat
+        .right
+        .text_fast(parsed_source.text_info())
+        .to_string(),
+    ),
   }
 }
 
+/// The type of the `this` pseudo-parameter, if the first parameter is one
+/// with a type, and the definitions of the other parameters. An untyped
+/// `this` is kept with the parameters.
+pub fn params_to_param_defs(
+  parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
+  params: &[deno_ast::swc::ast::Param],
+) -> (Option<TsTypeDef>, Vec<ParamDef>) {
+  let mut this_type = None;
+  let mut params = params;
+  if let Some((first, rest)) = params.split_first() {
+    if let Pat::Ident(ident) = &first.pat {
+      this_type = this_param_type(parsed_source, options, ident);
+      if this_type.is_some() {
+        params = rest;
+      }
+    }
+  }
+
+  let params = params
+    .iter()
+    .map(|param| param_to_param_def(parsed_source, options, param))
+    .collect();
+  (this_type, params)
+}
+
+/// Like [`params_to_param_defs`], for the parameters of signatures without a
+/// body, eg. interface methods and function types.
+pub fn ts_fn_params_to_param_defs(
+  parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
+  params: &[TsFnParam],
+) -> (Option<TsTypeDef>, Vec<ParamDef>) {
+  let mut this_type = None;
+  let mut params = params;
+  if let Some((TsFnParam::Ident(ident), rest)) = params.split_first() {
+    this_type = this_param_type(parsed_source, options, ident);
+    if this_type.is_some() {
+      params = rest;
+    }
+  }
+
+  let params = params
+    .iter()
+    .map(|param| ts_fn_param_to_param_def(parsed_source, options, param))
+    .collect();
+  (this_type, params)
+}
+
+fn this_param_type(
+  parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
+  ident: &BindingIdent,
+) -> Option<TsTypeDef> {
+  if ident.id.sym != "this" {
+    return None;
+  }
+  ident
+    .type_ann
+    .as_deref()
+    .map(|type_ann| TsTypeDef::new(parsed_source, options, &type_ann.type_ann))
+}
+
+/// The parameters as written in a signature, ie. preceded by the `this`
+/// pseudo-parameter when its type is declared.
+pub fn signature_params(
+  this_type: Option<&TsTypeDef>,
+  params: &[ParamDef],
+) -> Vec<ParamDef> {
+  let this_param = this_type.map(|this_type| ParamDef {
+    pattern: ParamPatternDef::Identifier {
+      name: "this".to_string(),
+      optional: false,
+    },
+    decorators: vec![],
+    ts_type: Some(this_type.clone()),
+    default_value_text: None,
+  });
+  this_param
+    .into_iter()
+    .chain(params.iter().cloned())
+    .collect()
+}
+
 pub fn param_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   param: &deno_ast::swc::ast::Param,
 ) -> ParamDef {
-  let mut def = pat_to_param_def(parsed_source, &param.pat);
+  let mut def = pat_to_param_def(parsed_source, options, &param.pat);
   def.decorators = decorators_to_defs(parsed_source, &param.decorators);
   def
 }
 
 pub fn pat_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   pat: &deno_ast::swc::ast::Pat,
 ) -> ParamDef {
   match pat {
-    Pat::Ident(ident) => ident_to_param_def(parsed_source, ident),
-    Pat::Array(array_pat) => array_pat_to_param_def(parsed_source, array_pat),
-    Pat::Rest(rest_pat) => rest_pat_to_param_def(parsed_source, rest_pat),
+    Pat::Ident(ident) => ident_to_param_def(parsed_source, options, ident),
+    Pat::Array(array_pat) => {
+      array_pat_to_param_def(parsed_source, options, array_pat)
+    }
+    Pat::Rest(rest_pat) => {
+      rest_pat_to_param_def(parsed_source, options, rest_pat)
+    }
     Pat::Object(object_pat) => {
-      object_pat_to_param_def(parsed_source, object_pat)
+      object_pat_to_param_def(parsed_source, options, object_pat)
     }
     Pat::Assign(assign_pat) => {
-      assign_pat_to_param_def(parsed_source, assign_pat)
+      assign_pat_to_param_def(parsed_source, options, assign_pat)
     }
     _ => unreachable!(),
   }
@@ -299,16 +421,21 @@ pub fn pat_to_param_def(
 
 pub fn ts_fn_param_to_param_def(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   ts_fn_param: &deno_ast::swc::ast::TsFnParam,
 ) -> ParamDef {
   match ts_fn_param {
-    TsFnParam::Ident(ident) => ident_to_param_def(parsed_source, ident),
+    TsFnParam::Ident(ident) => {
+      ident_to_param_def(parsed_source, options, ident)
+    }
     TsFnParam::Array(array_pat) => {
-      array_pat_to_param_def(parsed_source, array_pat)
+      array_pat_to_param_def(parsed_source, options, array_pat)
+    }
+    TsFnParam::Rest(rest_pat) => {
+      rest_pat_to_param_def(parsed_source, options, rest_pat)
     }
-    TsFnParam::Rest(rest_pat) => rest_pat_to_param_def(parsed_source, rest_pat),
     TsFnParam::Object(object_pat) => {
-      object_pat_to_param_def(parsed_source, object_pat)
+      object_pat_to_param_def(parsed_source, options, object_pat)
     }
   }
 }
@@ -3,22 +3,31 @@
 use crate::diagnostics::DiagnosticsCollector;
 use crate::diagnostics::DocDiagnostic;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
 use crate::node;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
+use crate::node::ExportKind;
 use crate::node::ModuleDoc;
 use crate::node::NamespaceDef;
+use crate::node::PackageDoc;
 use crate::ts_type::LiteralPropertyDef;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeDefOptions;
 use crate::ts_type::TsTypeLiteralDef;
 use crate::util::graph::resolve_deno_graph_module;
 use crate::util::swc::get_location;
 use crate::util::swc::get_text_info_location;
 use crate::util::swc::js_doc_for_range;
+use crate::util::swc::js_docs_for_source;
 use crate::util::swc::module_export_name_value;
 use crate::util::swc::module_js_doc_for_source;
+use crate::util::swc::module_references_for_source;
+use crate::util::swc::package_js_doc_for_source;
 use crate::util::symbol::get_module_info;
+use crate::util::symbol::is_type_only_reexport;
+use crate::util::symbol::type_only_named_exports;
 use crate::variable::VariableDef;
 use crate::visibility::SymbolVisibility;
 use crate::DocNodeKind;
@@ -26,6 +35,7 @@ use crate::ImportDef;
 use crate::Location;
 use crate::ReexportKind;
 
+use deno_ast::swc::ast::Class;
 use deno_ast::swc::ast::ClassDecl;
 use deno_ast::swc::ast::Decl;
 use deno_ast::swc::ast::DefaultDecl;
@@ -33,34 +43,46 @@ use deno_ast::swc::ast::ExportDecl;
 use deno_ast::swc::ast::ExportDefaultDecl;
 use deno_ast::swc::ast::ExportDefaultExpr;
 use deno_ast::swc::ast::ExportSpecifier;
+use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::FnDecl;
 use deno_ast::swc::ast::Ident;
 use deno_ast::swc::ast::ImportSpecifier;
+use deno_ast::swc::ast::MemberProp;
 use deno_ast::swc::ast::ModuleDecl;
 use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Stmt;
 use deno_ast::swc::ast::TsEnumDecl;
 use deno_ast::swc::ast::TsInterfaceDecl;
+use deno_ast::swc::ast::TsModuleBlock;
 use deno_ast::swc::ast::TsModuleDecl;
 use deno_ast::swc::ast::TsModuleName;
+use deno_ast::swc::ast::TsNamespaceBody;
 use deno_ast::swc::ast::TsTypeAliasDecl;
+use deno_ast::swc::ast::UsingDecl;
 use deno_ast::swc::ast::VarDecl;
 use deno_ast::swc::ast::VarDeclKind;
 use deno_ast::swc::ast::VarDeclarator;
 use deno_ast::ParsedSource;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
+use deno_graph::symbols::Definition;
 use deno_graph::symbols::EsModuleInfo;
 use deno_graph::symbols::ExportDeclRef;
 use deno_graph::symbols::ModuleInfoRef;
+use deno_graph::symbols::ResolvedSymbolDepEntry;
 use deno_graph::symbols::Symbol;
+use deno_graph::symbols::SymbolNodeDep;
 use deno_graph::symbols::SymbolNodeRef;
 use deno_graph::symbols::UniqueSymbolId;
 use deno_graph::Module;
 use deno_graph::ModuleGraph;
 use deno_graph::ModuleParser;
 use deno_graph::ModuleSpecifier;
+use sha2::Digest;
+use sha2::Sha256;
 
 use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -107,7 +129,7 @@ struct Import {
   kind: ImportKind,
 }
 
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct DocParserOptions {
   /// Whether diagnostics should be collected.
   pub diagnostics: bool,
@@ -116,17 +138,79 @@ pub struct DocParserOptions {
   /// Note: Private nodes that are referenced by public nodes
   /// are always included.
   pub private: bool,
+  /// Document modules from the declaration file next to them when it is in
+  /// the graph, eg. `mod.d.ts` for `mod.ts` or `mod.js`, as its types and
+  /// JSDoc are considered authoritative.
+  pub prefer_declaration_files: bool,
+  /// Keep the source text of classes in [`ClassDef::body_source`], eg. to
+  /// display it in the generated documentation.
+  ///
+  /// [`ClassDef::body_source`]: crate::class::ClassDef::body_source
+  pub include_source: bool,
+  /// How much of each documented symbol is kept in the output.
+  pub mode: DocMode,
+  /// The number of nested type levels to document, beyond which types are
+  /// replaced by a `...` type reference, so that recursive types stay
+  /// bounded. Defaults to `10`, `None` documents the whole types.
+  pub max_depth: Option<usize>,
+  /// Document the modules as compiled with TypeScript's
+  /// `isolatedDeclarations` option, which requires explicit type annotations
+  /// on the exported symbols: the types of variables, properties, parameters
+  /// with default values and return types are not inferred, so that the
+  /// declarations lacking an annotation get a `missing-explicit-type` or
+  /// `missing-return-type` diagnostic.
+  pub isolated_declarations: bool,
+}
+
+impl Default for DocParserOptions {
+  fn default() -> Self {
+    Self {
+      diagnostics: false,
+      private: false,
+      prefer_declaration_files: false,
+      include_source: false,
+      mode: DocMode::default(),
+      max_depth: Some(10),
+      isolated_declarations: false,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DocMode {
+  /// The complete documentation of each symbol.
+  #[default]
+  Full,
+  /// Only the name, kind, location, signature and first sentence of the
+  /// JSDoc description of each symbol, eg. for an inventory of a large
+  /// module. The members of classes, interfaces and enums are omitted, see
+  /// [`DocNode::into_summary`].
+  Summary,
 }
 
 pub struct DocParser<'a> {
   graph: &'a ModuleGraph,
   private: bool,
+  prefer_declaration_files: bool,
+  include_source: bool,
+  mode: DocMode,
+  max_depth: Option<usize>,
+  isolated_declarations: bool,
   root_symbol: Rc<deno_graph::symbols::RootSymbol<'a>>,
   visibility: SymbolVisibility,
   diagnostics: Option<RefCell<DiagnosticsCollector<'a>>>,
+  /// The specifiers of the modules re-exporting each module, built on first
+  /// use.
+  modules_reexporting: OnceCell<HashMap<ModuleSpecifier, Vec<String>>>,
 }
 
 impl<'a> DocParser<'a> {
+  /// Creates a parser for an already built module graph. The graph is only
+  /// borrowed, so the same graph can be shared by several parsers, eg. with
+  /// different options, without being rebuilt.
+  ///
+  /// The `parser` must be the one the graph was built with, so that the
+  /// already parsed sources are reused.
   pub fn new(
     graph: &'a ModuleGraph,
     parser: &'a dyn ModuleParser,
@@ -144,12 +228,27 @@ impl<'a> DocParser<'a> {
     Ok(DocParser {
       graph,
       private: options.private,
+      prefer_declaration_files: options.prefer_declaration_files,
+      include_source: options.include_source,
+      mode: options.mode,
+      max_depth: options.max_depth,
+      isolated_declarations: options.isolated_declarations,
       root_symbol,
       visibility,
       diagnostics,
+      modules_reexporting: OnceCell::new(),
     })
   }
 
+  /// The options of the parser that apply to the types it builds.
+  fn type_options(&self) -> TsTypeDefOptions {
+    TsTypeDefOptions {
+      max_depth: self.max_depth,
+      depth: 0,
+      isolated_declarations: self.isolated_declarations,
+    }
+  }
+
   /// Gets diagnostics found during any of the previous parses.
   pub fn take_diagnostics(&self) -> Vec<DocDiagnostic> {
     if let Some(diagnostics) = &self.diagnostics {
@@ -172,19 +271,144 @@ impl<'a> DocParser<'a> {
     let module_info = self.get_module_info(specifier)?;
     let definitions = self.get_doc_nodes_for_module_info(module_info)?;
     self.collect_diagnostics_for_nodes(&definitions);
+    let definitions = self.apply_mode(definitions);
     let reexports = self.get_reexports_for_module(module_info);
+    let (is_module, references, package_doc, text) = match module_info {
+      ModuleInfoRef::Esm(module_info) => (
+        module_has_module_decl(module_info),
+        module_references_for_source(module_info.source()),
+        package_js_doc_for_source(module_info.source()).map(|js_doc| {
+          PackageDoc {
+            description: js_doc
+              .doc
+              .as_deref()
+              .unwrap_or_default()
+              .trim()
+              .to_string(),
+            tags: js_doc,
+          }
+        }),
+        module_info.source().text_info().text_str(),
+      ),
+      ModuleInfoRef::Json(module_info) => {
+        (false, vec![], None, module_info.text_info().text_str())
+      }
+    };
+    let re_exported_by = self.get_modules_reexporting(module_info.specifier());
     let module_doc = ModuleDoc {
+      filename: module_info.specifier().to_string(),
+      checksum: format!("{:x}", Sha256::digest(text)),
       definitions,
       reexports,
+      is_module,
+      references,
+      re_exported_by,
+      package_doc,
     };
     Ok(module_doc)
   }
 
+  /// The specifiers of the modules of the graph that re-export from the given
+  /// one, with `export * from`, `export * as ns from` or `export { a } from`.
+  fn get_modules_reexporting(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Vec<String> {
+    self
+      .modules_reexporting
+      .get_or_init(|| self.build_modules_reexporting())
+      .get(specifier)
+      .cloned()
+      .unwrap_or_default()
+  }
+
+  /// Maps every module of the graph to the modules re-exporting it, the
+  /// inverse of their re-exports.
+  fn build_modules_reexporting(&self) -> HashMap<ModuleSpecifier, Vec<String>> {
+    let mut modules_reexporting =
+      HashMap::<ModuleSpecifier, Vec<String>>::new();
+    for module in self.graph.modules() {
+      let Ok(ModuleInfoRef::Esm(module_info)) =
+        get_module_info(&self.root_symbol, module.specifier())
+      else {
+        continue;
+      };
+      let reexported_specifiers = module_info
+        .source()
+        .module()
+        .body
+        .iter()
+        .filter_map(|item| match item {
+          ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => {
+            Some(&export_all.src)
+          }
+          ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+            named_export.src.as_ref()
+          }
+          _ => None,
+        })
+        .filter_map(|src| {
+          self.resolve_dependency(&src.value, module.specifier()).ok()
+        })
+        .map(|dependency| self.resolve_specifier(&dependency).into_owned())
+        .collect::<HashSet<_>>();
+      for reexported_specifier in reexported_specifiers {
+        modules_reexporting
+          .entry(reexported_specifier)
+          .or_default()
+          .push(module.specifier().to_string());
+      }
+    }
+    modules_reexporting
+  }
+
   fn get_module_info(
     &self,
     specifier: &ModuleSpecifier,
   ) -> Result<ModuleInfoRef, DocError> {
-    get_module_info(&self.root_symbol, specifier)
+    get_module_info(&self.root_symbol, &self.resolve_specifier(specifier))
+  }
+
+  /// The specifier of the declaration file to document instead of the
+  /// module, if preferred and available.
+  fn resolve_specifier<'b>(
+    &self,
+    specifier: &'b ModuleSpecifier,
+  ) -> Cow<'b, ModuleSpecifier> {
+    if !self.prefer_declaration_files {
+      return Cow::Borrowed(specifier);
+    }
+    let path = specifier.path();
+    let declaration_path = [
+      (".d.ts", None),
+      (".d.mts", None),
+      (".d.cts", None),
+      (".ts", Some(".d.ts")),
+      (".tsx", Some(".d.ts")),
+      (".js", Some(".d.ts")),
+      (".jsx", Some(".d.ts")),
+      (".mts", Some(".d.mts")),
+      (".mjs", Some(".d.mts")),
+      (".cts", Some(".d.cts")),
+      (".cjs", Some(".d.cts")),
+    ]
+    .into_iter()
+    .find_map(|(extension, declaration_extension)| {
+      let stem = path.strip_suffix(extension)?;
+      Some(declaration_extension.map(|ext| format!("{stem}{ext}")))
+    })
+    .flatten();
+    let Some(declaration_path) = declaration_path else {
+      return Cow::Borrowed(specifier);
+    };
+
+    let mut declaration_specifier = specifier.clone();
+    declaration_specifier.set_path(&declaration_path);
+    if self.graph.get(&declaration_specifier).is_some() {
+      Cow::Owned(declaration_specifier)
+    } else {
+      Cow::Borrowed(specifier)
+    }
   }
 
   /// Fetches `file_name` and returns a list of exported items (no reexports).
@@ -196,7 +420,7 @@ impl<'a> DocParser<'a> {
     let module_info = self.get_module_info(specifier)?;
     let doc_nodes = self.get_doc_nodes_for_module_info(module_info)?;
     self.collect_diagnostics_for_nodes(&doc_nodes);
-    Ok(doc_nodes)
+    Ok(self.apply_mode(doc_nodes))
   }
 
   /// Fetches `file_name`, parses it, and resolves its reexports.
@@ -207,7 +431,7 @@ impl<'a> DocParser<'a> {
     let doc_nodes =
       self.parse_with_reexports_inner(specifier, HashSet::new())?;
     self.collect_diagnostics_for_nodes(&doc_nodes);
-    Ok(doc_nodes)
+    Ok(self.apply_mode(doc_nodes))
   }
 
   fn collect_diagnostics_for_nodes(&self, nodes: &[DocNode]) {
@@ -217,6 +441,17 @@ impl<'a> DocParser<'a> {
     }
   }
 
+  /// Reduces the nodes to their summary in [`DocMode::Summary`], once the
+  /// diagnostics, which need the full nodes, were collected.
+  fn apply_mode(&self, doc_nodes: Vec<DocNode>) -> Vec<DocNode> {
+    match self.mode {
+      DocMode::Full => doc_nodes,
+      DocMode::Summary => {
+        doc_nodes.into_iter().map(DocNode::into_summary).collect()
+      }
+    }
+  }
+
   fn parse_with_reexports_inner(
     &self,
     specifier: &ModuleSpecifier,
@@ -225,7 +460,10 @@ impl<'a> DocParser<'a> {
     if !visited.insert(specifier.clone()) {
       return Ok(Vec::new()); // circular
     }
-    let module = resolve_deno_graph_module(self.graph, specifier)?;
+    let module = resolve_deno_graph_module(
+      self.graph,
+      &self.resolve_specifier(specifier),
+    )?;
 
     match module {
       Module::Js(_) | Module::Json(_) => {
@@ -234,7 +472,10 @@ impl<'a> DocParser<'a> {
           self.get_doc_nodes_for_module_info(module_info)?;
         let mut flattened_docs = Vec::new();
         let exports = module_info.exports(&self.root_symbol);
+        let type_only_exports = type_only_named_exports(module_info);
         for (export_name, export) in exports.resolved {
+          let is_type_only = is_type_only_reexport(&export)
+            || type_only_exports.contains(&export_name);
           let export = export.as_resolved_export();
           let export_symbol = export.module.symbol(export.symbol_id).unwrap();
           let definitions = self
@@ -260,20 +501,32 @@ impl<'a> DocParser<'a> {
                     js_doc = doc_node.js_doc.clone();
                   }
                 }
-                let ns_def = NamespaceDef {
+                let mut ns_def = NamespaceDef {
                   elements: doc_nodes
                     .iter()
                     .filter(|dn| !matches!(dn.kind, DocNodeKind::ModuleDoc))
                     .cloned()
                     .collect(),
+                  is_wildcard_module: false,
+                  is_global_augmentation: false,
+                  parent: None,
                 };
-                let ns_doc_node = DocNode::namespace(
+                // the namespaces of the module are now nested in this one
+                crate::node::set_namespace_parents(
+                  &mut ns_def.elements,
+                  Some(&export_name),
+                );
+                let mut ns_doc_node = DocNode::namespace(
                   export_name,
                   definition_location(first_def),
                   DeclarationKind::Export,
                   js_doc,
                   ns_def,
                 );
+                ns_doc_node.re_export_source = Some(specifier.to_string());
+                if is_type_only {
+                  ns_doc_node.export_kind = ExportKind::Type;
+                }
                 flattened_docs.push(ns_doc_node);
               }
               DefinitionKind::Definition => {
@@ -286,8 +539,21 @@ impl<'a> DocParser<'a> {
                       decl.maybe_node(),
                     );
                     if let Some(mut doc_node) = maybe_doc {
+                      if is_type_only
+                        && matches!(
+                          doc_node.kind,
+                          DocNodeKind::Function | DocNodeKind::Variable
+                        )
+                      {
+                        continue;
+                      }
                       doc_node.name = export_name.clone();
                       doc_node.declaration_kind = DeclarationKind::Export;
+                      if is_type_only {
+                        doc_node.export_kind = ExportKind::Type;
+                      }
+                      doc_node.re_export_source =
+                        Some(definition.module.specifier().to_string());
 
                       flattened_docs.push(doc_node);
                     }
@@ -299,6 +565,7 @@ impl<'a> DocParser<'a> {
         }
 
         flattened_docs.extend(module_doc_nodes);
+        mark_function_overloads(&mut flattened_docs);
         Ok(flattened_docs)
       }
       Module::Npm(_) | Module::Node(_) | Module::External(_) => Ok(vec![]),
@@ -376,6 +643,53 @@ impl<'a> DocParser<'a> {
     var_declarator: &VarDeclarator,
     ident: &Ident,
     full_range: &SourceRange,
+  ) -> Option<DocNode> {
+    self.get_doc_for_declarator_ident(
+      module_info,
+      var_declarator,
+      ident,
+      full_range,
+      || {
+        super::variable::get_docs_for_var_declarator(
+          module_info,
+          self.type_options(),
+          var_decl,
+          var_declarator,
+        )
+      },
+    )
+  }
+
+  fn get_doc_for_using_declarator_ident(
+    &self,
+    module_info: &EsModuleInfo,
+    using_decl: &UsingDecl,
+    var_declarator: &VarDeclarator,
+    ident: &Ident,
+  ) -> Option<DocNode> {
+    self.get_doc_for_declarator_ident(
+      module_info,
+      var_declarator,
+      ident,
+      &using_decl.range(),
+      || {
+        super::variable::get_docs_for_using_declarator(
+          module_info,
+          self.type_options(),
+          using_decl,
+          var_declarator,
+        )
+      },
+    )
+  }
+
+  fn get_doc_for_declarator_ident(
+    &self,
+    module_info: &EsModuleInfo,
+    var_declarator: &VarDeclarator,
+    ident: &Ident,
+    full_range: &SourceRange,
+    get_docs: impl FnOnce() -> Vec<(String, VariableDef, Option<SourceRange>)>,
   ) -> Option<DocNode> {
     let full_range = if ident.start() != var_declarator.start() {
       Cow::Owned(ident.range())
@@ -386,23 +700,19 @@ impl<'a> DocParser<'a> {
     // todo(dsherret): it's not ideal to call this function over
     // and over for the same var declarator when there are a lot
     // of idents
-    super::variable::get_docs_for_var_declarator(
-      module_info,
-      var_decl,
-      var_declarator,
-    )
-    .into_iter()
-    .find(|(name, _, _)| name.as_str() == &*ident.sym)
-    .map(|(name, var_def, _)| {
-      let location = get_location(module_info.source(), ident.start());
-      DocNode::variable(
-        name,
-        location,
-        DeclarationKind::Declare,
-        js_doc.clone(),
-        var_def,
-      )
-    })
+    get_docs()
+      .into_iter()
+      .find(|(name, _, _)| name.as_str() == &*ident.sym)
+      .map(|(name, var_def, _)| {
+        let location = get_location(module_info.source(), ident.start());
+        DocNode::variable(
+          name,
+          location,
+          DeclarationKind::Declare,
+          js_doc.clone(),
+          var_def,
+        )
+      })
   }
 
   fn get_doc_for_class_decl(
@@ -419,8 +729,13 @@ impl<'a> DocParser<'a> {
     };
     let js_doc = js_doc_for_range(parsed_source, &jsdoc_range)?;
     // declared classes cannot have decorators, so we ignore that return
-    let (name, class_def, _) =
-      super::class::get_doc_for_class_decl(parsed_source, class_decl);
+    let (name, mut class_def, _) = super::class::get_doc_for_class_decl(
+      parsed_source,
+      self.type_options(),
+      class_decl,
+    );
+    class_def.body_source =
+      self.get_source_text(parsed_source, &class_decl.class.range());
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::class(
       name,
@@ -431,6 +746,18 @@ impl<'a> DocParser<'a> {
     ))
   }
 
+  /// The source text of the range when [`DocParserOptions::include_source`]
+  /// is set.
+  fn get_source_text(
+    &self,
+    parsed_source: &ParsedSource,
+    range: &SourceRange,
+  ) -> Option<String> {
+    self
+      .include_source
+      .then(|| parsed_source.text_info().range_text(range).to_string())
+  }
+
   fn get_doc_for_fn_decl(
     &self,
     parsed_source: &ParsedSource,
@@ -438,8 +765,11 @@ impl<'a> DocParser<'a> {
     full_range: &SourceRange,
   ) -> Option<DocNode> {
     let js_doc = js_doc_for_range(parsed_source, full_range)?;
-    let (name, function_def) =
-      super::function::get_doc_for_fn_decl(parsed_source, fn_decl);
+    let (name, function_def) = super::function::get_doc_for_fn_decl(
+      parsed_source,
+      self.type_options(),
+      fn_decl,
+    );
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::function(
       name,
@@ -459,6 +789,7 @@ impl<'a> DocParser<'a> {
     let js_doc = js_doc_for_range(parsed_source, full_range)?;
     let (name, interface_def) = super::interface::get_doc_for_ts_interface_decl(
       parsed_source,
+      self.type_options(),
       ts_interface_decl,
       None,
     );
@@ -482,6 +813,7 @@ impl<'a> DocParser<'a> {
     let (name, type_alias_def) =
       super::type_alias::get_doc_for_ts_type_alias_decl(
         parsed_source,
+        self.type_options(),
         ts_type_alias,
       );
     let location = get_location(parsed_source, full_range.start);
@@ -501,8 +833,11 @@ impl<'a> DocParser<'a> {
     full_range: &SourceRange,
   ) -> Option<DocNode> {
     let js_doc = js_doc_for_range(parsed_source, full_range)?;
-    let (name, enum_def) =
-      super::r#enum::get_doc_for_ts_enum_decl(parsed_source, ts_enum);
+    let (name, enum_def) = super::r#enum::get_doc_for_ts_enum_decl(
+      parsed_source,
+      self.type_options(),
+      ts_enum,
+    );
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::r#enum(
       name,
@@ -520,6 +855,9 @@ impl<'a> DocParser<'a> {
     ts_module: &TsModuleDecl,
     full_range: &SourceRange,
   ) -> Option<DocNode> {
+    if ts_module.global {
+      return None; // documented with the other ambient module blocks
+    }
     let first_ns_decl = symbol
       .decls()
       .iter()
@@ -596,6 +934,8 @@ impl<'a> DocParser<'a> {
       }
     }
 
+    mark_function_overloads(&mut elements);
+
     let js_doc = js_doc_for_range(module_info.source(), full_range)?;
     let location = get_location(module_info.source(), full_range.start);
     Some(DocNode::namespace(
@@ -603,7 +943,12 @@ impl<'a> DocParser<'a> {
       location,
       DeclarationKind::Declare,
       js_doc,
-      NamespaceDef { elements },
+      NamespaceDef {
+        elements,
+        is_wildcard_module: false,
+        is_global_augmentation: false,
+        parent: None,
+      },
     ))
   }
 
@@ -649,11 +994,15 @@ impl<'a> DocParser<'a> {
       DefaultDecl::Class(class_expr) => {
         let default_name =
           class_expr.ident.as_ref().map(|ident| ident.sym.to_string());
-        let (class_def, decorator_js_doc) = crate::class::class_to_class_def(
-          parsed_source,
-          &class_expr.class,
-          default_name,
-        );
+        let (mut class_def, decorator_js_doc) =
+          crate::class::class_to_class_def(
+            parsed_source,
+            self.type_options(),
+            &class_expr.class,
+            default_name,
+          );
+        class_def.body_source =
+          self.get_source_text(parsed_source, &class_expr.class.range());
         let js_doc = if js_doc.is_empty() {
           decorator_js_doc
         } else {
@@ -672,6 +1021,7 @@ impl<'a> DocParser<'a> {
           fn_expr.ident.as_ref().map(|ident| ident.sym.to_string());
         let function_def = crate::function::function_to_function_def(
           parsed_source,
+          self.type_options(),
           &fn_expr.function,
           default_name,
         );
@@ -688,6 +1038,7 @@ impl<'a> DocParser<'a> {
         let (_, interface_def) =
           crate::interface::get_doc_for_ts_interface_decl(
             parsed_source,
+            self.type_options(),
             interface_decl,
             Some(default_name),
           );
@@ -716,11 +1067,17 @@ impl<'a> DocParser<'a> {
         String::from("default"),
         location,
         DeclarationKind::Export,
-        js_doc,
+        js_doc.clone(),
         super::variable::VariableDef {
           kind: deno_ast::swc::ast::VarDeclKind::Var,
+          using: None,
+          deploy_handler: super::variable::is_deploy_handler(
+            &js_doc,
+            &export_expr.expr,
+          ),
           ts_type: super::ts_type::infer_ts_type_from_expr(
             parsed_source,
+            self.type_options(),
             export_expr.expr.as_ref(),
             true,
           ),
@@ -794,6 +1151,7 @@ impl<'a> DocParser<'a> {
     if self.private {
       reexports.extend(imports.values().cloned().map(|import| node::Reexport {
         src: import.src,
+        is_type_only: false,
         kind: match import.kind {
           ImportKind::Named(orig, exported) => {
             ReexportKind::Named(orig, exported)
@@ -818,6 +1176,7 @@ impl<'a> DocParser<'a> {
                       module_export_name_value(&ns_export.name),
                     ),
                     src: src_str.to_string(),
+                    is_type_only: named_export.type_only,
                   },
                   ExportSpecifier::Default(specifier) => node::Reexport {
                     kind: node::ReexportKind::Named(
@@ -825,19 +1184,21 @@ impl<'a> DocParser<'a> {
                       Some(specifier.exported.sym.to_string()),
                     ),
                     src: src_str.to_string(),
+                    is_type_only: named_export.type_only,
                   },
-                  ExportSpecifier::Named(named_export) => {
-                    let export_name =
-                      module_export_name_value(&named_export.orig);
-                    let maybe_alias = named_export
-                      .exported
-                      .as_ref()
-                      .map(module_export_name_value);
+                  ExportSpecifier::Named(specifier) => {
+                    let export_name = module_export_name_value(&specifier.orig);
+                    let maybe_alias =
+                      specifier.exported.as_ref().map(module_export_name_value);
                     let kind =
                       node::ReexportKind::Named(export_name, maybe_alias);
                     node::Reexport {
                       kind,
                       src: src_str.to_string(),
+                      // `export { type Foo }` is type-only for this specifier
+                      // only, unlike `export type { Foo }`
+                      is_type_only: named_export.type_only
+                        || specifier.is_type_only,
                     }
                   }
                 })
@@ -862,6 +1223,8 @@ impl<'a> DocParser<'a> {
                       );
                       Some(node::Reexport {
                         src: import.src.clone(),
+                        is_type_only: named_export.type_only
+                          || specifier.is_type_only,
                         kind: match &import.kind {
                           ImportKind::Named(orig, maybe_export) => {
                             ReexportKind::Named(
@@ -890,6 +1253,7 @@ impl<'a> DocParser<'a> {
             let reexport = node::Reexport {
               kind: node::ReexportKind::All,
               src: export_all.src.value.to_string(),
+              is_type_only: export_all.type_only,
             };
             vec![reexport]
           }
@@ -993,6 +1357,187 @@ impl<'a> DocParser<'a> {
       }
     }
 
+    doc_nodes.extend(self.get_docs_for_ambient_module_blocks(module_info));
+    doc_nodes.extend(get_docs_for_callback_tags(parsed_source));
+    crate::merge::merge_declarations(&mut doc_nodes);
+    mark_function_overloads(&mut doc_nodes);
+    crate::inherit_doc::inherit_docs(&mut doc_nodes);
+    crate::class::compute_overrides(&mut doc_nodes);
+    crate::node::propagate_stability(&mut doc_nodes);
+    crate::node::set_namespace_parents(&mut doc_nodes, None);
+
+    doc_nodes
+  }
+
+  /// Wildcard module declarations, eg. `declare module "*.svg" { ... }`, and
+  /// `declare global { ... }` blocks are not part of the module's symbols, so
+  /// they are read from the AST.
+  fn get_docs_for_ambient_module_blocks(
+    &self,
+    module_info: &EsModuleInfo,
+  ) -> Vec<DocNode> {
+    let parsed_source = module_info.source();
+    let mut doc_nodes = Vec::new();
+    for item in &parsed_source.module().body {
+      let ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) = item else {
+        continue;
+      };
+      let js_doc = js_doc_for_range(parsed_source, &ts_module.range());
+      let elements = match &ts_module.body {
+        Some(TsNamespaceBody::TsModuleBlock(block)) => {
+          self.get_docs_for_ambient_module_block(module_info, block)
+        }
+        _ => vec![],
+      };
+
+      let (name, namespace_def) = match &ts_module.id {
+        TsModuleName::Str(pattern) if pattern.value.contains('*') => {
+          if js_doc.is_none() {
+            continue;
+          }
+          (
+            pattern.value.to_string(),
+            NamespaceDef {
+              elements,
+              is_wildcard_module: true,
+              is_global_augmentation: false,
+              parent: None,
+            },
+          )
+        }
+        // augmentations are documented through the declarations they add,
+        // so the block itself does not need to be documented
+        TsModuleName::Ident(_) if ts_module.global => {
+          if js_doc.is_none() && elements.is_empty() {
+            continue;
+          }
+          (
+            String::from("global"),
+            NamespaceDef {
+              elements,
+              is_wildcard_module: false,
+              is_global_augmentation: true,
+              parent: None,
+            },
+          )
+        }
+        _ => continue,
+      };
+
+      doc_nodes.push(DocNode::namespace(
+        name,
+        get_location(parsed_source, ts_module.start()),
+        DeclarationKind::Declare,
+        js_doc.unwrap_or_default(),
+        namespace_def,
+      ));
+    }
+    doc_nodes
+  }
+
+  fn get_docs_for_ambient_module_block(
+    &self,
+    module_info: &EsModuleInfo,
+    block: &TsModuleBlock,
+  ) -> Vec<DocNode> {
+    let parsed_source = module_info.source();
+    let mut elements = Vec::new();
+    for item in &block.body {
+      match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          elements.extend(self.get_docs_for_ambient_module_decl(
+            module_info,
+            decl,
+            &item.range(),
+            DeclarationKind::Declare,
+          ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          elements.extend(self.get_docs_for_ambient_module_decl(
+            module_info,
+            &export_decl.decl,
+            &export_decl.range(),
+            DeclarationKind::Export,
+          ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_expr)) => {
+          // `export default content;` re-exports a declaration of the
+          // module, which is then documented as the default export
+          let local = export_expr.expr.as_ident().and_then(|ident| {
+            elements
+              .iter()
+              .find(|doc_node: &&DocNode| doc_node.name.as_str() == &*ident.sym)
+          });
+          if let Some(local) = local {
+            let mut doc_node = local.clone();
+            doc_node.name = String::from("default");
+            doc_node.declaration_kind = DeclarationKind::Export;
+            elements.push(doc_node);
+          } else {
+            elements.extend(
+              self.get_doc_for_export_default_expr(parsed_source, export_expr),
+            );
+          }
+        }
+        _ => {}
+      }
+    }
+    mark_function_overloads(&mut elements);
+    elements
+  }
+
+  fn get_docs_for_ambient_module_decl(
+    &self,
+    module_info: &EsModuleInfo,
+    decl: &Decl,
+    full_range: &SourceRange,
+    declaration_kind: DeclarationKind,
+  ) -> Vec<DocNode> {
+    let parsed_source = module_info.source();
+    let mut doc_nodes = match decl {
+      Decl::Class(class_decl) => self
+        .get_doc_for_class_decl(parsed_source, class_decl, full_range)
+        .into_iter()
+        .collect(),
+      Decl::Fn(fn_decl) => self
+        .get_doc_for_fn_decl(parsed_source, fn_decl, full_range)
+        .into_iter()
+        .collect(),
+      Decl::TsInterface(ts_interface_decl) => self
+        .get_doc_for_interface_decl(
+          parsed_source,
+          ts_interface_decl,
+          full_range,
+        )
+        .into_iter()
+        .collect(),
+      Decl::TsTypeAlias(ts_type_alias) => self
+        .get_docs_for_type_alias(parsed_source, ts_type_alias, full_range)
+        .into_iter()
+        .collect(),
+      Decl::TsEnum(ts_enum) => self
+        .get_doc_for_enum(parsed_source, ts_enum, full_range)
+        .into_iter()
+        .collect(),
+      Decl::Var(var_decl) => var_decl
+        .decls
+        .iter()
+        .filter_map(|var_declarator| {
+          let ident = var_declarator.name.as_ident()?;
+          self.get_doc_for_var_declarator_ident(
+            module_info,
+            var_decl,
+            var_declarator,
+            &ident.id,
+            full_range,
+          )
+        })
+        .collect(),
+      Decl::TsModule(_) | Decl::Using(_) => vec![],
+    };
+    for doc_node in &mut doc_nodes {
+      doc_node.declaration_kind = declaration_kind;
+    }
     doc_nodes
   }
 
@@ -1083,12 +1628,21 @@ impl<'a> DocParser<'a> {
   ) -> Option<DocNode> {
     let parsed_source = module_info.source();
     match node {
-      SymbolNodeRef::ClassDecl(n) => {
-        self.get_doc_for_class_decl(parsed_source, n, &n.class.range())
-      }
-      SymbolNodeRef::ExportDefaultDecl(n) => {
-        self.get_doc_for_export_default_decl(parsed_source, n)
-      }
+      SymbolNodeRef::ClassDecl(n) => self
+        .get_doc_for_class_decl(parsed_source, n, &n.class.range())
+        .map(|doc_node| {
+          self.with_resolved_overrides(module_info, &n.class, doc_node)
+        }),
+      SymbolNodeRef::ExportDefaultDecl(n) => self
+        .get_doc_for_export_default_decl(parsed_source, n)
+        .map(|doc_node| match &n.decl {
+          DefaultDecl::Class(class_expr) => self.with_resolved_overrides(
+            module_info,
+            &class_expr.class,
+            doc_node,
+          ),
+          _ => doc_node,
+        }),
       SymbolNodeRef::ExportDefaultExpr(n) => {
         self.get_doc_for_export_default_expr(parsed_source, n)
       }
@@ -1115,14 +1669,14 @@ impl<'a> DocParser<'a> {
           ident,
           &parent_decl.range(),
         ),
-      SymbolNodeRef::UsingVar(_, _, _) => {
-        // makes no sense for using declarations to be in the public API
-        None
-      }
+      SymbolNodeRef::UsingVar(using_decl, n, ident) => self
+        .get_doc_for_using_declarator_ident(module_info, using_decl, n, ident),
       SymbolNodeRef::ExportDecl(export_decl, inner) => match inner {
-        ExportDeclRef::Class(n) => {
-          self.get_doc_for_class_decl(parsed_source, n, &export_decl.range())
-        }
+        ExportDeclRef::Class(n) => self
+          .get_doc_for_class_decl(parsed_source, n, &export_decl.range())
+          .map(|doc_node| {
+            self.with_resolved_overrides(module_info, &n.class, doc_node)
+          }),
         ExportDeclRef::Fn(n) => {
           self.get_doc_for_fn_decl(parsed_source, n, &export_decl.range())
         }
@@ -1171,6 +1725,82 @@ impl<'a> DocParser<'a> {
     }
   }
 
+  /// Sets `overrides` on the members of a class doc node which override a
+  /// member of one of the classes it extends.
+  fn with_resolved_overrides(
+    &self,
+    module_info: &EsModuleInfo,
+    class: &Class,
+    mut doc_node: DocNode,
+  ) -> DocNode {
+    let Some(class_def) = doc_node.class_def.as_mut() else {
+      return doc_node;
+    };
+    let mut module = ModuleInfoRef::Esm(module_info);
+    let mut class = class;
+    let mut visited = HashSet::new();
+    while let Some(parent) = self.resolve_super_class(module, class) {
+      // guard against circular hierarchies
+      if !visited.insert(parent.symbol.unique_id()) {
+        break;
+      }
+      let Some((node, parent_source)) =
+        parent.symbol_decl.maybe_node_and_source()
+      else {
+        break;
+      };
+      let Some(parent_class) = class_for_symbol_node(node) else {
+        break;
+      };
+      let Some(parent_name) =
+        qualified_symbol_name(parent.module, parent.symbol)
+      else {
+        break;
+      };
+      crate::class::resolve_overridden_members(
+        class_def,
+        &parent_name,
+        parent_source,
+        parent_class,
+      );
+      module = parent.module;
+      class = parent_class;
+    }
+    doc_node
+  }
+
+  /// Resolves the class declaration a class extends, which may be referred
+  /// to through namespaces, eg. `ns.Base`.
+  fn resolve_super_class<'b>(
+    &'b self,
+    module: ModuleInfoRef<'b>,
+    class: &Class,
+  ) -> Option<Definition<'b>> {
+    let (ident, parts) = qualified_expr_parts(class.super_class.as_ref()?)?;
+    let symbol = module.esm()?.symbol_from_swc(&ident.to_id())?;
+    let dep = if parts.is_empty() {
+      SymbolNodeDep::Id(ident.to_id())
+    } else {
+      SymbolNodeDep::QualifiedId(ident.to_id(), parts)
+    };
+    self
+      .root_symbol
+      .resolve_symbol_dep(module, symbol, &dep)
+      .into_iter()
+      .filter_map(|entry| match entry {
+        ResolvedSymbolDepEntry::Path(path) => Some(path),
+        ResolvedSymbolDepEntry::ImportType(_) => None,
+      })
+      .flat_map(|path| path.into_definitions())
+      .find(|definition| {
+        definition
+          .symbol_decl
+          .maybe_node()
+          .and_then(class_for_symbol_node)
+          .is_some()
+      })
+  }
+
   fn get_declare_for_symbol_node(&self, node: SymbolNodeRef) -> bool {
     match node {
       SymbolNodeRef::ClassDecl(n) => n.declare,
@@ -1250,6 +1880,8 @@ fn parse_json_module_doc_node(
       declaration_kind: DeclarationKind::Export,
       variable_def: Some(VariableDef {
         kind: VarDeclKind::Var,
+        using: None,
+        deploy_handler: false,
         ts_type: Some(parse_json_module_type(&value)),
       }),
       ..Default::default()
@@ -1300,6 +1932,75 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
   }
 }
 
+/// `@callback` tags declare a function type alias without any code, so they
+/// are read from all the JSDoc comments of the module.
+fn get_docs_for_callback_tags(parsed_source: &ParsedSource) -> Vec<DocNode> {
+  js_docs_for_source(parsed_source)
+    .into_iter()
+    .filter(|(js_doc, _)| !js_doc.tags.contains(&JsDocTag::Ignore))
+    .filter_map(|(js_doc, range)| {
+      let (name, type_alias_def) =
+        crate::type_alias::callback_to_type_alias_def(&js_doc)?;
+      Some(DocNode::type_alias(
+        name,
+        get_location(parsed_source, range.start),
+        DeclarationKind::Export,
+        js_doc,
+        type_alias_def,
+      ))
+    })
+    .collect()
+}
+
+/// The name of a symbol qualified by the namespaces it is declared in, eg.
+/// `ns.Base`, as it is documented in the module that declares it.
+fn qualified_symbol_name(
+  module: ModuleInfoRef,
+  symbol: &Symbol,
+) -> Option<String> {
+  let mut names = vec![symbol.maybe_name()?.to_string()];
+  let mut parent_id = symbol.parent_id();
+  while let Some(parent) = parent_id.and_then(|id| module.symbol(id)) {
+    // the module itself is the root symbol
+    if parent.parent_id().is_none() {
+      break;
+    }
+    names.push(parent.maybe_name()?.to_string());
+    parent_id = parent.parent_id();
+  }
+  names.reverse();
+  Some(names.join("."))
+}
+
+/// The identifier an expression like `a.b.C` starts with, and the names of
+/// the properties it accesses, eg. `["b", "C"]`.
+fn qualified_expr_parts(expr: &Expr) -> Option<(&Ident, Vec<String>)> {
+  match expr {
+    Expr::Ident(ident) => Some((ident, vec![])),
+    Expr::Member(member_expr) => {
+      let MemberProp::Ident(prop) = &member_expr.prop else {
+        return None;
+      };
+      let (ident, mut parts) = qualified_expr_parts(&member_expr.obj)?;
+      parts.push(prop.sym.to_string());
+      Some((ident, parts))
+    }
+    _ => None,
+  }
+}
+
+fn class_for_symbol_node(node: SymbolNodeRef) -> Option<&Class> {
+  match node {
+    SymbolNodeRef::ClassDecl(n)
+    | SymbolNodeRef::ExportDecl(_, ExportDeclRef::Class(n)) => Some(&n.class),
+    SymbolNodeRef::ExportDefaultDecl(ExportDefaultDecl {
+      decl: DefaultDecl::Class(class_expr),
+      ..
+    }) => Some(&class_expr.class),
+    _ => None,
+  }
+}
+
 fn module_has_import(module_info: &EsModuleInfo) -> bool {
   module_info.source().module().body.iter().any(|m| {
     matches!(
@@ -1311,6 +2012,46 @@ fn module_has_import(module_info: &EsModuleInfo) -> bool {
   })
 }
 
+fn module_has_module_decl(module_info: &EsModuleInfo) -> bool {
+  module_info
+    .source()
+    .module()
+    .body
+    .iter()
+    .any(|m| matches!(m, ModuleItem::ModuleDecl(_)))
+}
+
+/// Sets `is_overload` on the signatures without a body of the functions
+/// declared more than once, and removes the implementation signature of those
+/// functions as it is not part of their public API.
+fn mark_function_overloads(doc_nodes: &mut Vec<DocNode>) {
+  // the number of signatures and of signatures without a body by name
+  let mut signature_counts: HashMap<String, (usize, usize)> = HashMap::new();
+  for doc_node in doc_nodes.iter() {
+    if let Some(function_def) = &doc_node.function_def {
+      let counts = signature_counts.entry(doc_node.name.clone()).or_default();
+      counts.0 += 1;
+      if !function_def.has_body {
+        counts.1 += 1;
+      }
+    }
+  }
+  doc_nodes.retain_mut(|doc_node| {
+    let Some(function_def) = &mut doc_node.function_def else {
+      return true;
+    };
+    let (signatures, overloads) = signature_counts[&doc_node.name];
+    if signatures == 1 || overloads == 0 {
+      return true;
+    }
+    if function_def.has_body {
+      return false;
+    }
+    function_def.is_overload = true;
+    true
+  });
+}
+
 fn definition_location(
   definition: &deno_graph::symbols::Definition,
 ) -> Location {
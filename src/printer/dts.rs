@@ -0,0 +1,550 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassDef;
+use crate::display::display_accessibility;
+use crate::display::display_readonly;
+use crate::display::with_display_options;
+use crate::display::DisplayOptions;
+use crate::display::Indent;
+use crate::display::SliceDisplayer;
+use crate::interface::InterfaceDef;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type_param::TsTypeParamDef;
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use deno_ast::swc::ast::VarDeclKind;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+impl DocNode {
+  /// Generates TypeScript declaration (`.d.ts`) text for this node, eg.
+  /// `export declare function foo(x: number): string;`.
+  ///
+  /// Everything that can not be expressed in a declaration file is dropped,
+  /// such as parameter default values, decorators and JSDoc.
+  pub fn to_dts(&self) -> String {
+    with_display_options(DisplayOptions::default(), || {
+      let mut output = String::new();
+      write_node(&mut output, self, 0, false).unwrap();
+      output
+    })
+  }
+}
+
+/// Writes the declaration of a node. `ambient` is set for the elements of a
+/// namespace, which can not have a `declare` modifier themselves.
+fn write_node(
+  w: &mut String,
+  node: &DocNode,
+  indent: i64,
+  ambient: bool,
+) -> FmtResult {
+  let is_default_export = !ambient
+    && node.name == "default"
+    && node.declaration_kind == DeclarationKind::Export;
+
+  // the namespace merged with an enum or an interface is declared after it
+  if let Some(namespace_def) = node
+    .namespace_def
+    .as_ref()
+    .filter(|_| node.kind != DocNodeKind::Namespace)
+  {
+    let declaration = DocNode {
+      namespace_def: None,
+      ..node.clone()
+    };
+    write_node(w, &declaration, indent, ambient)?;
+    let namespace = DocNode {
+      kind: DocNodeKind::Namespace,
+      namespace_def: Some(namespace_def.clone()),
+      ..declaration
+    };
+    return write_node(w, &namespace, indent, ambient);
+  }
+
+  let prefix = match (node.declaration_kind, ambient) {
+    _ if is_default_export => "export default ",
+    (DeclarationKind::Export, true) => "export ",
+    (_, true) => "",
+    (DeclarationKind::Export, false) => "export declare ",
+    (_, false) => "declare ",
+  };
+
+  match node.kind {
+    DocNodeKind::ModuleDoc => Ok(()),
+    DocNodeKind::Function => {
+      let function_def = node.function_def.as_ref().unwrap();
+      let name = if is_default_export {
+        function_def.def_name.as_deref().unwrap_or_default()
+      } else {
+        &node.name
+      };
+      write!(
+        w,
+        "{}{prefix}function {name}{}({})",
+        Indent(indent),
+        TypeParams(&function_def.type_params),
+        Params(&function_def.signature_params()),
+      )?;
+      if let Some(return_type) = &function_def.return_type {
+        write!(w, ": {return_type}")?;
+      }
+      writeln!(w, ";")
+    }
+    DocNodeKind::Variable => {
+      let variable_def = node.variable_def.as_ref().unwrap();
+      // `using` declarations can not be ambient, but they have the same type
+      // as a `const` declaration.
+      let kind = match variable_def.kind {
+        VarDeclKind::Const => "const",
+        VarDeclKind::Let => "let",
+        VarDeclKind::Var => "var",
+      };
+      // `export default` can only be followed by an expression, so the type
+      // is declared on a variable which is then exported.
+      let name = if is_default_export {
+        "_default"
+      } else {
+        &node.name
+      };
+      let prefix = if is_default_export {
+        "declare "
+      } else {
+        prefix
+      };
+      write!(w, "{}{prefix}{kind} {name}", Indent(indent))?;
+      if let Some(ts_type) = &variable_def.ts_type {
+        write!(w, ": {ts_type}")?;
+      }
+      writeln!(w, ";")?;
+      if is_default_export {
+        writeln!(w, "{}export default {name};", Indent(indent))?;
+      }
+      Ok(())
+    }
+    DocNodeKind::Class => {
+      let class_def = node.class_def.as_ref().unwrap();
+      let name = if is_default_export {
+        class_def.def_name.as_deref().unwrap_or_default()
+      } else {
+        &node.name
+      };
+      write!(
+        w,
+        "{}{prefix}{}class{}{}",
+        Indent(indent),
+        if class_def.is_abstract {
+          "abstract "
+        } else {
+          ""
+        },
+        if name.is_empty() {
+          String::new()
+        } else {
+          format!(" {name}")
+        },
+        TypeParams(&class_def.type_params),
+      )?;
+      if let Some(extends) = &class_def.extends {
+        write!(w, " extends ")?;
+        for mixin in &class_def.mixins {
+          write!(w, "{mixin}(")?;
+        }
+        write!(w, "{extends}{}", ")".repeat(class_def.mixins.len()))?;
+        if !class_def.super_type_params.is_empty() {
+          write!(
+            w,
+            "<{}>",
+            SliceDisplayer::new(&class_def.super_type_params, ", ", false)
+          )?;
+        }
+      }
+      if !class_def.implements.is_empty() {
+        write!(
+          w,
+          " implements {}",
+          SliceDisplayer::new(&class_def.implements, ", ", false)
+        )?;
+      }
+      writeln!(w, " {{")?;
+      write_class_members(w, class_def, indent + 1)?;
+      writeln!(w, "{}}}", Indent(indent))
+    }
+    DocNodeKind::Enum => {
+      let enum_def = node.enum_def.as_ref().unwrap();
+      writeln!(w, "{}{prefix}enum {} {{", Indent(indent), node.name)?;
+      for member in &enum_def.members {
+        write!(
+          w,
+          "{}{}",
+          Indent(indent + 1),
+          property_name(&member.name, false)
+        )?;
+        if let Some(init) = &member.init {
+          write!(w, " = {init}")?;
+        }
+        writeln!(w, ",")?;
+      }
+      writeln!(w, "{}}}", Indent(indent))
+    }
+    DocNodeKind::Interface => {
+      let interface_def = node.interface_def.as_ref().unwrap();
+      let name = if is_default_export {
+        interface_def.def_name.as_deref().unwrap_or("_default")
+      } else {
+        &node.name
+      };
+      write!(
+        w,
+        "{}{prefix}interface {name}{}",
+        Indent(indent),
+        TypeParams(&interface_def.type_params),
+      )?;
+      if !interface_def.extends.is_empty() {
+        write!(
+          w,
+          " extends {}",
+          SliceDisplayer::new(&interface_def.extends, ", ", false)
+        )?;
+      }
+      writeln!(w, " {{")?;
+      write_interface_members(w, interface_def, indent + 1)?;
+      writeln!(w, "{}}}", Indent(indent))
+    }
+    DocNodeKind::TypeAlias => {
+      let type_alias_def = node.type_alias_def.as_ref().unwrap();
+      writeln!(
+        w,
+        "{}{prefix}type {}{} = {};",
+        Indent(indent),
+        node.name,
+        TypeParams(&type_alias_def.type_params),
+        type_alias_def.ts_type,
+      )
+    }
+    DocNodeKind::Namespace => {
+      let namespace_def = node.namespace_def.as_ref().unwrap();
+      if namespace_def.is_wildcard_module {
+        writeln!(w, "{}{prefix}module \"{}\" {{", Indent(indent), node.name)?;
+      } else if namespace_def.is_global_augmentation {
+        writeln!(w, "{}{prefix}global {{", Indent(indent))?;
+      } else {
+        writeln!(w, "{}{prefix}namespace {} {{", Indent(indent), node.name)?;
+      }
+      for element in &namespace_def.elements {
+        write_node(w, element, indent + 1, true)?;
+      }
+      writeln!(w, "{}}}", Indent(indent))
+    }
+    DocNodeKind::Import => {
+      let import_def = node.import_def.as_ref().unwrap();
+      write!(w, "{}import ", Indent(indent))?;
+      match import_def.imported.as_deref() {
+        None => write!(w, "* as {}", node.name)?,
+        Some("default") => write!(w, "{}", node.name)?,
+        Some(imported) if imported == node.name => {
+          write!(w, "{{ {imported} }}")?
+        }
+        Some(imported) => write!(w, "{{ {imported} as {} }}", node.name)?,
+      }
+      writeln!(w, " from \"{}\";", import_def.src)
+    }
+  }
+}
+
+fn write_class_members(
+  w: &mut String,
+  class_def: &ClassDef,
+  indent: i64,
+) -> FmtResult {
+  // the implementation is not part of the declarations when overloaded
+  let constructors = if class_def.constructor_overloads.is_empty() {
+    &class_def.constructors
+  } else {
+    &class_def.constructor_overloads
+  };
+  for constructor in constructors {
+    writeln!(
+      w,
+      "{}{}constructor({});",
+      Indent(indent),
+      display_accessibility(constructor.accessibility, false),
+      Params(
+        &constructor
+          .params
+          .iter()
+          .map(|param| param.param.clone())
+          .collect::<Vec<_>>()
+      ),
+    )?;
+  }
+
+  for property in &class_def.properties {
+    write!(
+      w,
+      "{}{}{}{}{}{}{}{}",
+      Indent(indent),
+      display_accessibility(property.accessibility, true),
+      if property.is_static { "static " } else { "" },
+      if property.is_abstract {
+        "abstract "
+      } else {
+        ""
+      },
+      if property.is_override {
+        "override "
+      } else {
+        ""
+      },
+      display_readonly(property.readonly),
+      property_name(&property.name, false),
+      if property.optional { "?" } else { "" },
+    )?;
+    match &property.ts_type {
+      // private members are emitted without their types
+      Some(ts_type)
+        if property.accessibility != Some(Accessibility::Private) =>
+      {
+        writeln!(w, ": {ts_type};")?
+      }
+      _ => writeln!(w, ";")?,
+    }
+  }
+
+  // private methods are declared like properties, once for all their
+  // overloads and accessors, so that declarations parse back the same
+  let mut private_methods = vec![];
+  for method in &class_def.methods {
+    if method.accessibility == Some(Accessibility::Private)
+      && !private_methods.contains(&&method.name)
+    {
+      writeln!(
+        w,
+        "{}{}{}{};",
+        Indent(indent),
+        display_accessibility(method.accessibility, true),
+        if method.is_static { "static " } else { "" },
+        property_name(&method.name, false),
+      )?;
+      private_methods.push(&method.name);
+    }
+  }
+
+  for index_signature in &class_def.index_signatures {
+    write!(
+      w,
+      "{}{}[{}]",
+      Indent(indent),
+      display_readonly(index_signature.readonly),
+      Params(&index_signature.params),
+    )?;
+    if let Some(ts_type) = &index_signature.ts_type {
+      write!(w, ": {ts_type}")?;
+    }
+    writeln!(w, ";")?;
+  }
+
+  for method in &class_def.methods {
+    if method.accessibility == Some(Accessibility::Private) {
+      continue;
+    }
+
+    let has_overloads = class_def
+      .methods
+      .iter()
+      .filter(|m| {
+        m.name == method.name
+          && m.kind == method.kind
+          && m.is_static == method.is_static
+      })
+      .count()
+      > 1;
+    if has_overloads && method.function_def.has_body {
+      continue;
+    }
+
+    write!(
+      w,
+      "{}{}{}{}{}{}{}{}",
+      Indent(indent),
+      display_accessibility(method.accessibility, true),
+      if method.is_static { "static " } else { "" },
+      if method.is_abstract { "abstract " } else { "" },
+      if method.is_override { "override " } else { "" },
+      method_kind(method.kind),
+      property_name(&method.name, false),
+      if method.optional { "?" } else { "" },
+    )?;
+    write!(
+      w,
+      "{}({})",
+      TypeParams(&method.function_def.type_params),
+      Params(&method.function_def.signature_params()),
+    )?;
+    // setters can not have a return type annotation
+    if let Some(return_type) = method
+      .function_def
+      .return_type
+      .as_ref()
+      .filter(|_| method.kind != MethodKind::Setter)
+    {
+      write!(w, ": {return_type}")?;
+    }
+    writeln!(w, ";")?;
+  }
+
+  Ok(())
+}
+
+fn write_interface_members(
+  w: &mut String,
+  interface_def: &InterfaceDef,
+  indent: i64,
+) -> FmtResult {
+  for call_signature in &interface_def.call_signatures {
+    write!(
+      w,
+      "{}{}({})",
+      Indent(indent),
+      TypeParams(&call_signature.type_params),
+      Params(&call_signature.params),
+    )?;
+    if let Some(ts_type) = &call_signature.ts_type {
+      write!(w, ": {ts_type}")?;
+    }
+    writeln!(w, ";")?;
+  }
+
+  for property in &interface_def.properties {
+    write!(
+      w,
+      "{}{}{}{}",
+      Indent(indent),
+      display_readonly(property.readonly),
+      property_name(&property.name, property.computed),
+      if property.optional { "?" } else { "" },
+    )?;
+    if let Some(ts_type) = &property.ts_type {
+      write!(w, ": {ts_type}")?;
+    }
+    writeln!(w, ";")?;
+  }
+
+  for method in interface_def.methods.iter().flat_map(|m| m.signatures()) {
+    write!(
+      w,
+      "{}{}{}{}{}({})",
+      Indent(indent),
+      method_kind(method.kind),
+      property_name(&method.name, method.computed),
+      if method.optional { "?" } else { "" },
+      TypeParams(&method.type_params),
+      Params(&method.signature_params()),
+    )?;
+    if let Some(return_type) = method
+      .return_type
+      .as_ref()
+      .filter(|_| method.kind != MethodKind::Setter)
+    {
+      write!(w, ": {return_type}")?;
+    }
+    writeln!(w, ";")?;
+  }
+
+  for index_signature in &interface_def.index_signatures {
+    writeln!(w, "{}{};", Indent(indent), index_signature)?;
+  }
+
+  Ok(())
+}
+
+/// The name of a member as it is written in a declaration: computed names
+/// are between brackets, and names which aren't identifiers nor numbers are
+/// quoted.
+fn property_name(name: &str, computed: bool) -> String {
+  if computed {
+    return format!("[{name}]");
+  }
+  let is_identifier = name
+    .chars()
+    .next()
+    .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+    && name
+      .chars()
+      .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+  let is_number = !name.is_empty()
+    && name.chars().all(|c| c.is_ascii_digit() || c == '.')
+    && name.parse::<f64>().is_ok();
+  // the names of the computed members of classes already include the
+  // brackets, and private names are kept as is
+  if is_identifier
+    || is_number
+    || name.starts_with('[')
+    || name.starts_with('#')
+  {
+    name.to_string()
+  } else {
+    format!("{name:?}")
+  }
+}
+
+fn method_kind(kind: MethodKind) -> &'static str {
+  match kind {
+    MethodKind::Getter => "get ",
+    MethodKind::Setter => "set ",
+    MethodKind::Method => "",
+  }
+}
+
+pub(super) struct TypeParams<'a>(pub(super) &'a [TsTypeParamDef]);
+
+impl Display for TypeParams<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if self.0.is_empty() {
+      return Ok(());
+    }
+    write!(f, "<{}>", SliceDisplayer::new(self.0, ", ", false))
+  }
+}
+
+pub(super) struct Params<'a>(pub(super) &'a [ParamDef]);
+
+impl Display for Params<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    for (i, param) in self.0.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+
+      // decorators and default values can not be expressed in declarations,
+      // so they are dropped and parameters with default values become
+      // optional instead.
+      let (pattern, optional, ts_type) = match &param.pattern {
+        ParamPatternDef::Assign { left, .. } => (
+          &left.pattern,
+          true,
+          param.ts_type.as_ref().or(left.ts_type.as_ref()),
+        ),
+        pattern => (pattern, false, param.ts_type.as_ref()),
+      };
+      let pattern = ParamDef {
+        pattern: pattern.clone(),
+        decorators: vec![],
+        ts_type: None,
+        default_value_text: None,
+      };
+      write!(f, "{pattern}{}", if optional { "?" } else { "" })?;
+      if let Some(ts_type) = ts_type {
+        write!(f, ": {ts_type}")?;
+      }
+    }
+    Ok(())
+  }
+}
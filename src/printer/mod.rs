@@ -10,6 +10,11 @@
 // unnecessary and can result in unnecessary copying. Instead they should take
 // references.
 
+mod dts;
+mod rust_doc;
+mod signature;
+mod tree;
+
 use crate::colors;
 use crate::display::display_abstract;
 use crate::display::display_async;
@@ -21,11 +26,14 @@ use crate::js_doc::JsDocTag;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
 use crate::node::DocNodeKind;
+use crate::variable::UsingDeclKind;
 
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+pub use tree::TreePrinter;
+
 pub struct DocPrinter<'a> {
   doc_nodes: &'a [DocNode],
   use_color: bool,
@@ -111,9 +119,12 @@ impl<'a> DocPrinter<'a> {
         DocNodeKind::Class => self.format_class(w, node)?,
         DocNodeKind::Enum => self.format_enum(w, node)?,
         DocNodeKind::Interface => self.format_interface(w, node)?,
-        DocNodeKind::Namespace => self.format_namespace(w, node)?,
         _ => {}
       }
+      // namespaces, including the ones merged with an enum or an interface
+      if node.namespace_def.is_some() {
+        self.format_namespace(w, node)?;
+      }
     }
 
     if self.use_color {
@@ -265,6 +276,9 @@ impl<'a> DocPrinter<'a> {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("example"))?;
         self.format_jsdoc_tag_doc(w, doc, indent)
       }
+      JsDocTag::Experimental => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("experimental"))
+      }
       JsDocTag::Extends { type_ref, doc } => {
         writeln!(
           w,
@@ -275,12 +289,31 @@ impl<'a> DocPrinter<'a> {
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Fires { name, doc } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta("fires"),
+          colors::bold(name)
+        )?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
+      JsDocTag::DeployHandler => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("deployHandler"))
+      }
       JsDocTag::Ignore => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("ignore"))
       }
+      JsDocTag::InheritDoc => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("inheritDoc"))
+      }
       JsDocTag::Module => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("module"))
       }
+      JsDocTag::Override => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("override"))
+      }
       JsDocTag::Param {
         name,
         type_ref,
@@ -300,6 +333,17 @@ impl<'a> DocPrinter<'a> {
         writeln!(w, " {}", colors::bold(name))?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Package => {
+        writeln!(w, "{}@{}", Indent(indent), colors::magenta("package"))
+      }
+      JsDocTag::PackageDocumentation => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta("packageDocumentation")
+        )
+      }
       JsDocTag::Public => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("public"))
       }
@@ -336,6 +380,9 @@ impl<'a> DocPrinter<'a> {
         }
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Tag { doc } => {
+        writeln!(w, "{}@{} {}", Indent(indent), colors::magenta("tag"), doc)
+      }
       JsDocTag::Tags { tags } => {
         writeln!(
           w,
@@ -345,13 +392,35 @@ impl<'a> DocPrinter<'a> {
           tags.join(", "),
         )
       }
-      JsDocTag::Template { name, doc } => {
+      JsDocTag::Template {
+        name,
+        type_ref,
+        doc,
+      } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("template"))?;
+        if let Some(type_ref) = type_ref {
+          write!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
+        }
+        writeln!(w, " {}", colors::bold(name))?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
+      JsDocTag::Proxy { type_ref, doc } => {
         writeln!(
           w,
           "{}@{} {}",
           Indent(indent),
-          colors::magenta("template"),
-          colors::bold(name)
+          colors::magenta("proxy"),
+          colors::italic_cyan(type_ref)
+        )?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
+      JsDocTag::Satisfies { type_ref, doc } => {
+        writeln!(
+          w,
+          "{}@{} {{{}}}",
+          Indent(indent),
+          colors::magenta("satisfies"),
+          colors::italic_cyan(type_ref)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
@@ -365,6 +434,15 @@ impl<'a> DocPrinter<'a> {
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Throws { type_ref, doc } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("throws"))?;
+        if let Some(type_ref) = type_ref {
+          writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
+        } else {
+          writeln!(w)?;
+        }
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
       JsDocTag::TypeDef {
         name,
         type_ref,
@@ -410,13 +488,16 @@ impl<'a> DocPrinter<'a> {
 
   fn format_class(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
     let class_def = node.class_def.as_ref().unwrap();
-    let has_overloads = class_def.constructors.len() > 1;
-    for node in &class_def.constructors {
-      if !has_overloads || !node.has_body {
+    if class_def.constructor_overloads.is_empty() {
+      for node in &class_def.constructors {
         writeln!(w, "{}{}", Indent(1), node,)?;
         self.format_jsdoc(w, &node.js_doc, 2)?;
       }
     }
+    for node in &class_def.constructor_overloads {
+      writeln!(w, "{}{}", Indent(1), node,)?;
+      self.format_jsdoc(w, &node.js_doc, 2)?;
+    }
     for node in class_def.properties.iter().filter(|node| {
       self.private
         || node
@@ -443,7 +524,7 @@ impl<'a> DocPrinter<'a> {
       let has_overloads = class_def
         .methods
         .iter()
-        .filter(|n| n.name == node.name)
+        .filter(|n| n.name == node.name && n.kind == node.kind)
         .count()
         > 1;
       if !has_overloads || !node.function_def.has_body {
@@ -473,11 +554,16 @@ impl<'a> DocPrinter<'a> {
   ) -> FmtResult {
     let interface_def = node.interface_def.as_ref().unwrap();
 
+    for call_sign_def in &interface_def.call_signatures {
+      writeln!(w, "{}{}", Indent(1), call_sign_def)?;
+      self.format_jsdoc(w, &call_sign_def.js_doc, 2)?;
+    }
     for property_def in &interface_def.properties {
       writeln!(w, "{}{}", Indent(1), property_def)?;
       self.format_jsdoc(w, &property_def.js_doc, 2)?;
     }
-    for method_def in &interface_def.methods {
+    for method_def in interface_def.methods.iter().flat_map(|m| m.signatures())
+    {
       writeln!(w, "{}{}", Indent(1), method_def)?;
       self.format_jsdoc(w, &method_def.js_doc, 2)?;
     }
@@ -537,7 +623,11 @@ impl<'a> DocPrinter<'a> {
     }
 
     if let Some(extends) = &class_def.extends {
-      write!(w, " {} {}", colors::magenta("extends"), extends)?;
+      write!(w, " {} ", colors::magenta("extends"))?;
+      for mixin in &class_def.mixins {
+        write!(w, "{}(", mixin)?;
+      }
+      write!(w, "{}{}", extends, ")".repeat(class_def.mixins.len()))?;
     }
     if !class_def.super_type_params.is_empty() {
       write!(
@@ -604,7 +694,7 @@ impl<'a> DocPrinter<'a> {
       write!(
         w,
         "({})",
-        SliceDisplayer::new(&function_def.params, ", ", false)
+        SliceDisplayer::new(&function_def.signature_params(), ", ", false)
       )?;
       if let Some(return_type) = &function_def.return_type {
         write!(w, ": {}", return_type)?;
@@ -694,6 +784,26 @@ impl<'a> DocPrinter<'a> {
     node: &DocNode,
     indent: i64,
   ) -> FmtResult {
+    let namespace_def = node.namespace_def.as_ref().unwrap();
+    if namespace_def.is_global_augmentation {
+      return writeln!(
+        w,
+        "{}{}{}",
+        Indent(indent),
+        fmt_visibility(node.declaration_kind),
+        colors::magenta("global"),
+      );
+    }
+    if namespace_def.is_wildcard_module {
+      return writeln!(
+        w,
+        "{}{}{} {}",
+        Indent(indent),
+        fmt_visibility(node.declaration_kind),
+        colors::magenta("module"),
+        colors::bold(format!("\"{}\"", node.name))
+      );
+    }
     writeln!(
       w,
       "{}{}{} {}",
@@ -716,10 +826,12 @@ impl<'a> DocPrinter<'a> {
       "{}{}{} {}",
       Indent(indent),
       fmt_visibility(node.declaration_kind),
-      colors::magenta(match variable_def.kind {
-        deno_ast::swc::ast::VarDeclKind::Const => "const",
-        deno_ast::swc::ast::VarDeclKind::Let => "let",
-        deno_ast::swc::ast::VarDeclKind::Var => "var",
+      colors::magenta(match (variable_def.using, variable_def.kind) {
+        (Some(UsingDeclKind::Using), _) => "using",
+        (Some(UsingDeclKind::AwaitUsing), _) => "await using",
+        (None, deno_ast::swc::ast::VarDeclKind::Const) => "const",
+        (None, deno_ast::swc::ast::VarDeclKind::Let) => "let",
+        (None, deno_ast::swc::ast::VarDeclKind::Var) => "var",
       }),
       colors::bold(&node.name),
     )?;
@@ -0,0 +1,97 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+
+impl DocNode {
+  /// Generates the Rust `///` doc comment equivalent to the JSDoc of this
+  /// node, eg. for bindings generated from a TypeScript API. The `@param`,
+  /// `@returns`, `@throws` and `@example` tags become the `# Arguments`,
+  /// `# Returns`, `# Errors` and `# Examples` sections, the other tags are
+  /// dropped.
+  ///
+  /// Code blocks of the examples without a language are marked as `ts`, so
+  /// that rustdoc does not compile them as Rust doctests.
+  pub fn to_rust_doc_comment(&self) -> String {
+    let js_doc = &self.js_doc;
+    let mut arguments = vec![];
+    let mut returns = vec![];
+    let mut errors = vec![];
+    let mut examples = vec![];
+    for tag in &js_doc.tags {
+      match tag {
+        JsDocTag::Param { name, doc, .. } => arguments.push(match doc {
+          Some(doc) => format!("* `{name}` - {doc}"),
+          None => format!("* `{name}`"),
+        }),
+        JsDocTag::Return { doc: Some(doc), .. } => returns.push(doc.clone()),
+        JsDocTag::Throws { type_ref, doc } => {
+          errors.push(match (type_ref, doc) {
+            (Some(type_ref), Some(doc)) => format!("Throws `{type_ref}` {doc}"),
+            (Some(type_ref), None) => format!("Throws `{type_ref}`."),
+            (None, Some(doc)) => format!("Throws {doc}"),
+            (None, None) => continue,
+          })
+        }
+        JsDocTag::Example { doc } => examples.push(example(doc)),
+        _ => {}
+      }
+    }
+
+    let mut paragraphs = vec![];
+    if let Some(doc) = js_doc.doc.as_deref().map(str::trim) {
+      if !doc.is_empty() {
+        paragraphs.push(doc.to_string());
+      }
+    }
+    for (title, section) in [
+      ("Arguments", arguments.join("\n")),
+      ("Returns", returns.join("\n\n")),
+      ("Errors", errors.join("\n\n")),
+      ("Examples", examples.join("\n\n")),
+    ] {
+      if !section.is_empty() {
+        paragraphs.push(format!("# {title}"));
+        paragraphs.push(section);
+      }
+    }
+
+    let mut output = String::new();
+    for line in paragraphs.join("\n\n").lines() {
+      if line.is_empty() {
+        output.push_str("///\n");
+      } else {
+        output.push_str("/// ");
+        output.push_str(line);
+        output.push('\n');
+      }
+    }
+    output
+  }
+}
+
+/// The example as markdown, with its code in a `ts` code block unless it
+/// already has code blocks.
+fn example(doc: &str) -> String {
+  let doc = doc.trim_matches('\n').trim_end();
+  if !doc.lines().any(|line| line.trim_start().starts_with("```")) {
+    return format!("```ts\n{doc}\n```");
+  }
+
+  let mut in_code_block = false;
+  let mut lines = vec![];
+  for line in doc.lines() {
+    let fence = line.trim_start();
+    if fence.starts_with("```") {
+      if !in_code_block && fence.trim_end() == "```" {
+        lines.push(line.replacen("```", "```ts", 1));
+      } else {
+        lines.push(line.to_string());
+      }
+      in_code_block = !in_code_block;
+    } else {
+      lines.push(line.to_string());
+    }
+  }
+  lines.join("\n")
+}
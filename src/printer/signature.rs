@@ -0,0 +1,140 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::dts::Params;
+use super::dts::TypeParams;
+use crate::colors;
+use crate::display::SliceDisplayer;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::variable::UsingDeclKind;
+
+use deno_ast::swc::ast::VarDeclKind;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+impl DocNode {
+  /// A one-line TypeScript signature of the node without its body, eg.
+  /// `function foo<T>(x: T): void` or `class Bar extends Baz`.
+  pub fn signature(&self) -> String {
+    colors::without_color(|| self.to_string())
+  }
+}
+
+impl Display for DocNode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let name = self.get_name();
+    match self.kind {
+      DocNodeKind::ModuleDoc => write!(f, "module \"{}\"", self.module_path()),
+      DocNodeKind::Function => {
+        let function_def = self.function_def.as_ref().unwrap();
+        write!(
+          f,
+          "{}function{} {name}{}({})",
+          if function_def.is_async { "async " } else { "" },
+          if function_def.is_generator { "*" } else { "" },
+          TypeParams(&function_def.type_params),
+          Params(&function_def.signature_params()),
+        )?;
+        if let Some(return_type) = &function_def.return_type {
+          write!(f, ": {return_type}")?;
+        }
+        Ok(())
+      }
+      DocNodeKind::Variable => {
+        let variable_def = self.variable_def.as_ref().unwrap();
+        let kind = match (variable_def.using, variable_def.kind) {
+          (Some(UsingDeclKind::Using), _) => "using",
+          (Some(UsingDeclKind::AwaitUsing), _) => "await using",
+          (None, VarDeclKind::Const) => "const",
+          (None, VarDeclKind::Let) => "let",
+          (None, VarDeclKind::Var) => "var",
+        };
+        write!(f, "{kind} {name}")?;
+        if let Some(ts_type) = &variable_def.ts_type {
+          write!(f, ": {ts_type}")?;
+        }
+        Ok(())
+      }
+      DocNodeKind::Class => {
+        let class_def = self.class_def.as_ref().unwrap();
+        write!(
+          f,
+          "{}class {name}{}",
+          if class_def.is_abstract {
+            "abstract "
+          } else {
+            ""
+          },
+          TypeParams(&class_def.type_params),
+        )?;
+        if let Some(extends) = &class_def.extends {
+          write!(f, " extends {extends}")?;
+          if !class_def.super_type_params.is_empty() {
+            write!(
+              f,
+              "<{}>",
+              SliceDisplayer::new(&class_def.super_type_params, ", ", false)
+            )?;
+          }
+        }
+        if !class_def.implements.is_empty() {
+          write!(
+            f,
+            " implements {}",
+            SliceDisplayer::new(&class_def.implements, ", ", false)
+          )?;
+        }
+        Ok(())
+      }
+      DocNodeKind::Enum => write!(f, "enum {name}"),
+      DocNodeKind::Interface => {
+        let interface_def = self.interface_def.as_ref().unwrap();
+        write!(
+          f,
+          "interface {name}{}",
+          TypeParams(&interface_def.type_params)
+        )?;
+        if !interface_def.extends.is_empty() {
+          write!(
+            f,
+            " extends {}",
+            SliceDisplayer::new(&interface_def.extends, ", ", false)
+          )?;
+        }
+        Ok(())
+      }
+      DocNodeKind::TypeAlias => {
+        let type_alias_def = self.type_alias_def.as_ref().unwrap();
+        write!(
+          f,
+          "type {name}{} = {}",
+          TypeParams(&type_alias_def.type_params),
+          type_alias_def.ts_type
+        )
+      }
+      DocNodeKind::Namespace => {
+        let namespace_def = self.namespace_def.as_ref().unwrap();
+        if namespace_def.is_wildcard_module {
+          write!(f, "module \"{name}\"")
+        } else if namespace_def.is_global_augmentation {
+          write!(f, "global")
+        } else {
+          write!(f, "namespace {name}")
+        }
+      }
+      DocNodeKind::Import => {
+        let import_def = self.import_def.as_ref().unwrap();
+        match import_def.imported.as_deref() {
+          Some("default") => write!(f, "import {name}")?,
+          Some(imported) if imported == name => {
+            write!(f, "import {{ {name} }}")?
+          }
+          Some(imported) => write!(f, "import {{ {imported} as {name} }}")?,
+          None => write!(f, "import * as {name}")?,
+        }
+        write!(f, " from \"{}\"", import_def.src)
+      }
+    }
+  }
+}
@@ -0,0 +1,236 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::class::ClassDef;
+use crate::colors;
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Prints the documentation of a module as a tree of signatures, eg.
+///
+/// ```text
+/// module ./mod.ts
+/// ├── function foo(x: number): string
+/// │   ├── @param x - The input number
+/// │   └── @returns The string representation
+/// └── class Bar
+///     ├── constructor(name: string)
+///     └── method greet(): void
+/// ```
+pub struct TreePrinter<'a> {
+  module: &'a str,
+  doc_nodes: &'a [DocNode],
+  private: bool,
+}
+
+impl<'a> TreePrinter<'a> {
+  pub fn new(
+    module: &'a str,
+    doc_nodes: &'a [DocNode],
+    private: bool,
+  ) -> TreePrinter<'a> {
+    TreePrinter {
+      module,
+      doc_nodes,
+      private,
+    }
+  }
+
+  fn doc_nodes_tree(&self, doc_nodes: &[DocNode]) -> Vec<Tree> {
+    doc_nodes
+      .iter()
+      .filter(|node| {
+        !matches!(node.kind, DocNodeKind::Import | DocNodeKind::ModuleDoc)
+      })
+      .map(|node| self.doc_node_tree(node))
+      .collect()
+  }
+
+  fn doc_node_tree(&self, node: &DocNode) -> Tree {
+    let mut children = js_doc_tree(&node.js_doc);
+    match node.kind {
+      DocNodeKind::Class => {
+        children.extend(self.class_tree(node.class_def.as_ref().unwrap()));
+      }
+      DocNodeKind::Enum => {
+        for member in &node.enum_def.as_ref().unwrap().members {
+          children.push(Tree::new(
+            format!("member {}", member.name),
+            js_doc_tree(&member.js_doc),
+          ));
+        }
+      }
+      DocNodeKind::Interface => {
+        children.extend(interface_tree(node.interface_def.as_ref().unwrap()));
+      }
+      DocNodeKind::Function
+      | DocNodeKind::Import
+      | DocNodeKind::ModuleDoc
+      | DocNodeKind::Namespace
+      | DocNodeKind::TypeAlias
+      | DocNodeKind::Variable => {}
+    }
+    // namespaces, including the ones merged with an enum or an interface
+    if let Some(namespace_def) = &node.namespace_def {
+      children.extend(self.doc_nodes_tree(&namespace_def.elements));
+    }
+    Tree::new(node.to_string(), children)
+  }
+
+  fn class_tree(&self, class_def: &ClassDef) -> Vec<Tree> {
+    let is_visible = |accessibility: Option<Accessibility>| {
+      self.private || accessibility != Some(Accessibility::Private)
+    };
+
+    let mut children = vec![];
+    for overload in &class_def.constructor_overloads {
+      if is_visible(overload.accessibility) {
+        children.push(Tree::new(
+          overload.to_string(),
+          js_doc_tree(&overload.js_doc),
+        ));
+      }
+    }
+    for constructor in &class_def.constructors {
+      if is_visible(constructor.accessibility) {
+        children.push(Tree::new(
+          constructor.to_string(),
+          js_doc_tree(&constructor.js_doc),
+        ));
+      }
+    }
+    for property in &class_def.properties {
+      if is_visible(property.accessibility) {
+        children.push(Tree::new(
+          format!("property {property}"),
+          js_doc_tree(&property.js_doc),
+        ));
+      }
+    }
+    for method in &class_def.methods {
+      let has_overloads = class_def
+        .methods
+        .iter()
+        .filter(|m| {
+          m.name == method.name
+            && m.kind == method.kind
+            && m.is_static == method.is_static
+        })
+        .count()
+        > 1;
+      // skip the implementation signature of overloaded methods
+      if !is_visible(method.accessibility)
+        || (has_overloads && method.function_def.has_body)
+      {
+        continue;
+      }
+      children.push(Tree::new(
+        member_label(method.kind, method),
+        js_doc_tree(&method.js_doc),
+      ));
+    }
+    children
+  }
+}
+
+impl<'a> Display for TreePrinter<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let tree = colors::without_color(|| {
+      Tree::new(
+        format!("module {}", self.module),
+        self.doc_nodes_tree(self.doc_nodes),
+      )
+    });
+    writeln!(f, "{}", tree.label)?;
+    write_children(f, &tree.children, "")
+  }
+}
+
+struct Tree {
+  label: String,
+  children: Vec<Tree>,
+}
+
+impl Tree {
+  fn new(label: String, children: Vec<Tree>) -> Self {
+    Self { label, children }
+  }
+}
+
+fn write_children(
+  f: &mut Formatter<'_>,
+  trees: &[Tree],
+  prefix: &str,
+) -> FmtResult {
+  for (i, tree) in trees.iter().enumerate() {
+    let is_last = i == trees.len() - 1;
+    let (branch, indent) = if is_last {
+      ("└── ", "    ")
+    } else {
+      ("├── ", "│   ")
+    };
+    writeln!(f, "{prefix}{branch}{}", tree.label)?;
+    write_children(f, &tree.children, &format!("{prefix}{indent}"))?;
+  }
+  Ok(())
+}
+
+fn member_label(kind: MethodKind, def: impl Display) -> String {
+  match kind {
+    MethodKind::Method => format!("method {def}"),
+    // getters and setters are already prefixed with `get` or `set`
+    MethodKind::Getter | MethodKind::Setter => def.to_string(),
+  }
+}
+
+fn interface_tree(interface_def: &InterfaceDef) -> Vec<Tree> {
+  let mut children = vec![];
+  for property in &interface_def.properties {
+    children.push(Tree::new(
+      format!("property {property}"),
+      js_doc_tree(&property.js_doc),
+    ));
+  }
+  for method in interface_def.methods.iter().flat_map(|m| m.signatures()) {
+    let label = match method.kind {
+      MethodKind::Getter => format!("get {method}"),
+      MethodKind::Setter => format!("set {method}"),
+      MethodKind::Method => format!("method {method}"),
+    };
+    children.push(Tree::new(label, js_doc_tree(&method.js_doc)));
+  }
+  children
+}
+
+fn js_doc_tree(js_doc: &JsDoc) -> Vec<Tree> {
+  js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| {
+      let (label, doc) = match tag {
+        JsDocTag::Param { name, doc, .. } => (format!("@param {name}"), doc),
+        JsDocTag::Return { doc, .. } => ("@returns".to_string(), doc),
+        JsDocTag::Deprecated { doc } => ("@deprecated".to_string(), doc),
+        _ => return None,
+      };
+      // labels are kept on a single line
+      let doc = doc
+        .as_ref()
+        .map(|doc| doc.lines().collect::<Vec<_>>().join(" "));
+      let label = match (tag, doc) {
+        (JsDocTag::Param { .. }, Some(doc)) => format!("{label} - {doc}"),
+        (_, Some(doc)) => format!("{label} {doc}"),
+        (_, None) => label,
+      };
+      Some(Tree::new(label, vec![]))
+    })
+    .collect()
+}
@@ -0,0 +1,229 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::js_doc_links;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::params::ParamDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type_param::TsTypeParamDef;
+
+use std::collections::HashSet;
+
+/// See [`DocNode::related_symbols`].
+pub(crate) fn related_symbols<'a>(
+  node: &DocNode,
+  all_nodes: &'a [DocNode],
+) -> Vec<&'a DocNode> {
+  let candidates = all_nodes
+    .iter()
+    .filter(|other| {
+      other.name != node.name
+        && !matches!(other.kind, DocNodeKind::Import | DocNodeKind::ModuleDoc)
+    })
+    .collect::<Vec<_>>();
+
+  let mut related: Vec<&DocNode> = vec![];
+  let mut push = |other: &'a DocNode| {
+    if !related.iter().any(|node| std::ptr::eq(*node, other)) {
+      related.push(other);
+    }
+  };
+
+  let see_names = see_names(node);
+  for other in &candidates {
+    if see_names.contains(&other.name) {
+      push(other);
+    }
+  }
+
+  let mut references = type_references(node);
+  references.insert(node.name.clone());
+  for other in &candidates {
+    if other.location.filename != node.location.filename {
+      continue;
+    }
+    let mut other_references = type_references(other);
+    other_references.insert(other.name.clone());
+    if !references.is_disjoint(&other_references) {
+      push(other);
+    }
+  }
+
+  let prefix = name_prefix(&node.name);
+  for other in &candidates {
+    if name_prefix(&other.name) == prefix {
+      push(other);
+    }
+  }
+
+  related
+}
+
+/// The names of the symbols referenced by the `@see` tags of the node, either
+/// through a `{@link}` or as the first word of the tag, eg. `Foo` for both
+/// `@see {@link Foo.bar}` and `@see Foo for more details`.
+fn see_names(node: &DocNode) -> HashSet<String> {
+  node
+    .js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| match tag {
+      JsDocTag::See { doc } => Some(doc),
+      _ => None,
+    })
+    .flat_map(|doc| {
+      let links = js_doc_links(doc);
+      if links.is_empty() {
+        doc.split_whitespace().next().into_iter().collect()
+      } else {
+        links
+      }
+    })
+    .map(|name| name.split('.').next().unwrap().to_string())
+    .collect()
+}
+
+/// The leading word of a camelCase or PascalCase name, eg. `read` for
+/// `readTextFile`.
+fn name_prefix(name: &str) -> &str {
+  let end = name
+    .char_indices()
+    .skip(1)
+    .find(|(_, c)| c.is_ascii_uppercase())
+    .map_or(name.len(), |(i, _)| i);
+  &name[..end]
+}
+
+/// See [`DocNode::all_type_refs`].
+pub(crate) fn all_type_refs(node: &DocNode) -> Vec<String> {
+  let (extends, ts_types, _) = signature_types(node);
+  let mut references: Vec<String> = vec![];
+  let names = extends.into_iter().cloned().chain(
+    ts_types
+      .iter()
+      .flat_map(|ts_type| ts_type.referenced_type_names()),
+  );
+  for name in names {
+    if !references.contains(&name) {
+      references.push(name);
+    }
+  }
+  references
+}
+
+/// The names of the types referenced by the signature of the node, without
+/// the type parameters it declares.
+fn type_references(node: &DocNode) -> HashSet<String> {
+  let (_, ts_types, type_params) = signature_types(node);
+  let mut declared = type_params
+    .iter()
+    .map(|type_param| type_param.name.clone())
+    .collect::<HashSet<_>>();
+  for ts_type in &ts_types {
+    declared.extend(ts_type.inner_type_param_names());
+  }
+
+  all_type_refs(node)
+    .into_iter()
+    .filter(|name| !declared.contains(name))
+    .collect()
+}
+
+/// The class `extends` clause, the types that appear in the signature of the
+/// node, including those of its parameters and type parameter constraints
+/// and defaults, and the type parameters it declares.
+fn signature_types(
+  node: &DocNode,
+) -> (Option<&String>, Vec<&TsTypeDef>, Vec<&TsTypeParamDef>) {
+  let mut extends = None;
+  let mut ts_types: Vec<&TsTypeDef> = vec![];
+  let mut type_params: Vec<&TsTypeParamDef> = vec![];
+  let mut params: Vec<&ParamDef> = vec![];
+
+  match node.kind {
+    DocNodeKind::Class => {
+      let class_def = node.class_def.as_ref().unwrap();
+      type_params.extend(&class_def.type_params);
+      extends = class_def.extends.as_ref();
+      ts_types.extend(&class_def.super_type_params);
+      ts_types.extend(&class_def.implements);
+      for constructor in &class_def.constructors {
+        params.extend(constructor.params.iter().map(|param| &param.param));
+      }
+      for overload in &class_def.constructor_overloads {
+        params.extend(overload.params.iter().map(|param| &param.param));
+      }
+      ts_types.extend(class_def.properties.iter().flat_map(|p| &p.ts_type));
+      for index_signature in &class_def.index_signatures {
+        params.extend(&index_signature.params);
+        ts_types.extend(&index_signature.ts_type);
+      }
+      for method in &class_def.methods {
+        let function_def = &method.function_def;
+        type_params.extend(&function_def.type_params);
+        params.extend(&function_def.params);
+        ts_types.extend(&function_def.this_type);
+        ts_types.extend(&function_def.return_type);
+      }
+    }
+    DocNodeKind::Function => {
+      let function_def = node.function_def.as_ref().unwrap();
+      type_params.extend(&function_def.type_params);
+      params.extend(&function_def.params);
+      ts_types.extend(&function_def.this_type);
+      ts_types.extend(&function_def.return_type);
+    }
+    DocNodeKind::Interface => {
+      let interface_def = node.interface_def.as_ref().unwrap();
+      type_params.extend(&interface_def.type_params);
+      ts_types.extend(&interface_def.extends);
+      for property in &interface_def.properties {
+        type_params.extend(&property.type_params);
+        params.extend(&property.params);
+        ts_types.extend(&property.ts_type);
+      }
+      for method in &interface_def.methods {
+        type_params.extend(&method.type_params);
+        params.extend(&method.params);
+        ts_types.extend(&method.this_type);
+        ts_types.extend(&method.return_type);
+        for overload in &method.overloads {
+          type_params.extend(&overload.type_params);
+          params.extend(&overload.params);
+          ts_types.extend(&overload.this_type);
+          ts_types.extend(&overload.return_type);
+        }
+      }
+      for call_signature in &interface_def.call_signatures {
+        type_params.extend(&call_signature.type_params);
+        params.extend(&call_signature.params);
+        ts_types.extend(&call_signature.ts_type);
+      }
+      for index_signature in &interface_def.index_signatures {
+        params.extend(&index_signature.params);
+        ts_types.extend(&index_signature.ts_type);
+      }
+    }
+    DocNodeKind::TypeAlias => {
+      let type_alias_def = node.type_alias_def.as_ref().unwrap();
+      type_params.extend(&type_alias_def.type_params);
+      ts_types.push(&type_alias_def.ts_type);
+    }
+    DocNodeKind::Variable => {
+      ts_types.extend(&node.variable_def.as_ref().unwrap().ts_type);
+    }
+    DocNodeKind::Enum
+    | DocNodeKind::Import
+    | DocNodeKind::ModuleDoc
+    | DocNodeKind::Namespace => {}
+  }
+
+  ts_types.extend(params.iter().flat_map(|param| &param.ts_type));
+  for type_param in &type_params {
+    ts_types.extend(&type_param.constraint);
+    ts_types.extend(&type_param.default);
+  }
+
+  (extends, ts_types, type_params)
+}
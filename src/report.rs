@@ -0,0 +1,140 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Statistics about the API surface of a module, see [`generate_api_report`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiReport {
+  pub total_symbols: usize,
+  pub by_kind: HashMap<DocNodeKind, usize>,
+  /// The number of symbols with type parameters.
+  pub generics_count: usize,
+  pub deprecated_count: usize,
+  /// The number of experimental symbols, see [`crate::node::Stability`].
+  pub unstable_count: usize,
+  /// The average number of parameters of the functions, where overloaded
+  /// functions count once with the parameters of their longest signature,
+  /// `0.0` if there are none.
+  pub avg_params_per_function: f64,
+  /// The fraction of the symbols that have a JSDoc description, `0.0` if
+  /// there are none.
+  pub documented_fraction: f64,
+  /// The effective API surface of each class, by name: the number of its
+  /// public and protected members, see [`ClassDef::public_count`] and
+  /// [`ClassDef::protected_count`].
+  ///
+  /// [`ClassDef::public_count`]: crate::class::ClassDef::public_count
+  /// [`ClassDef::protected_count`]: crate::class::ClassDef::protected_count
+  pub class_api_surfaces: HashMap<String, usize>,
+}
+
+/// Computes the [`ApiReport`] of the given nodes, eg. the ones of a module or
+/// of all the modules of a package, for CI dashboards or API reviews.
+///
+/// Only the given nodes are counted, not the elements of namespaces, and
+/// imports and module documentation are left out. Overloads and merged
+/// declarations make up a single symbol, which is documented, deprecated or
+/// generic if any of its declarations is.
+pub fn generate_api_report(nodes: &[DocNode]) -> ApiReport {
+  let mut symbols: IndexMap<(&str, &str, DocNodeKind), Vec<&DocNode>> =
+    IndexMap::new();
+  for node in nodes {
+    if matches!(node.kind, DocNodeKind::Import | DocNodeKind::ModuleDoc) {
+      continue;
+    }
+    symbols
+      .entry((&node.location.filename, &node.name, node.kind))
+      .or_default()
+      .push(node);
+  }
+
+  let mut by_kind = HashMap::new();
+  let mut generics_count = 0;
+  let mut deprecated_count = 0;
+  let mut unstable_count = 0;
+  let mut documented_count = 0;
+  let mut params = vec![];
+  let mut class_api_surfaces = HashMap::new();
+  for ((_, name, kind), declarations) in &symbols {
+    *by_kind.entry(*kind).or_insert(0) += 1;
+    if declarations.iter().any(|node| has_type_params(node)) {
+      generics_count += 1;
+    }
+    if declarations.iter().any(|node| {
+      node
+        .js_doc
+        .tags
+        .iter()
+        .any(|tag| matches!(tag, JsDocTag::Deprecated { .. }))
+    }) {
+      deprecated_count += 1;
+    }
+    if declarations.iter().any(|node| !node.stability.is_stable()) {
+      unstable_count += 1;
+    }
+    if declarations.iter().any(|node| {
+      node
+        .js_doc
+        .doc
+        .as_deref()
+        .is_some_and(|doc| !doc.is_empty())
+    }) {
+      documented_count += 1;
+    }
+    if let Some(function_params) = declarations
+      .iter()
+      .filter_map(|node| node.function_def.as_ref())
+      .map(|function_def| function_def.params.len())
+      .max()
+    {
+      params.push(function_params);
+    }
+    if let Some(class_def) =
+      declarations.iter().find_map(|node| node.class_def.as_ref())
+    {
+      class_api_surfaces.insert(
+        name.to_string(),
+        class_def.public_count() + class_def.protected_count(),
+      );
+    }
+  }
+
+  ApiReport {
+    total_symbols: symbols.len(),
+    by_kind,
+    generics_count,
+    deprecated_count,
+    unstable_count,
+    avg_params_per_function: fraction(params.iter().sum(), params.len()),
+    documented_fraction: fraction(documented_count, symbols.len()),
+    class_api_surfaces,
+  }
+}
+
+fn has_type_params(node: &DocNode) -> bool {
+  let type_params = match node.kind {
+    DocNodeKind::Class => &node.class_def.as_ref().unwrap().type_params,
+    DocNodeKind::Function => &node.function_def.as_ref().unwrap().type_params,
+    DocNodeKind::Interface => &node.interface_def.as_ref().unwrap().type_params,
+    DocNodeKind::TypeAlias => {
+      &node.type_alias_def.as_ref().unwrap().type_params
+    }
+    _ => return false,
+  };
+  !type_params.is_empty()
+}
+
+fn fraction(numerator: usize, denominator: usize) -> f64 {
+  if denominator == 0 {
+    0.0
+  } else {
+    numerator as f64 / denominator as f64
+  }
+}
@@ -0,0 +1,11 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::node::DocNode;
+
+/// Generates a declaration file, `.d.ts`, declaring the same API as the
+/// given doc nodes, eg. for TypeScript projects that consume a Deno module
+/// through Node.js compatibility. Implementations and documentation are left
+/// out, see [`DocNode::to_dts`].
+pub fn generate_type_stubs(nodes: &[DocNode]) -> String {
+  nodes.iter().map(DocNode::to_dts).collect()
+}
@@ -1,8 +1,41 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::broken_links_report;
+use crate::broken_links_report_with_known_types;
+use crate::class::ClassDef;
+use crate::class::ImplementsCheckResult;
+use crate::class::UnimplementedMember;
+use crate::class::UnimplementedMemberKind;
+use crate::function::BodySpan;
+use crate::function::FunctionDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::lint_jsdoc_links;
+use crate::lint_jsdoc_links_with_known_symbols;
+use crate::lint_shadow_builtins;
+use crate::lint_shadow_builtins_with_builtins;
+use crate::node::DeclarationKind;
+use crate::params::ParamPatternDef;
 use crate::parser::DocParser;
 use crate::printer::DocPrinter;
+use crate::ts_type::parse_ts_type;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::write_doc_nodes_compact;
+use crate::BrokenLink;
+use crate::DisplayOptions;
+use crate::DocBuildError;
+use crate::DocFilter;
+use crate::DocLintDiagnostic;
+use crate::DocLintDiagnosticKind;
+use crate::DocMode;
+use crate::DocNode;
+use crate::DocNodeKind;
 use crate::DocParserOptions;
+use crate::Location;
+use crate::ParamDef;
+use crate::ReexportKind;
+use crate::SimplifyOptions;
 use deno_graph::source::MemoryLoader;
 use deno_graph::source::Source;
 use deno_graph::BuildOptions;
@@ -12,6 +45,7 @@ use deno_graph::ModuleGraph;
 use deno_graph::ModuleSpecifier;
 use pretty_assertions::assert_eq;
 use serde_json::json;
+use std::collections::HashMap;
 
 type MaybeHeaders<S> = Option<Vec<(S, S)>>;
 
@@ -50,6 +84,16 @@ pub(crate) async fn setup<S: AsRef<str> + Copy>(
   (graph, analyzer, root)
 }
 
+/// Parses `source` as the module `file:///mod.ts`, with the default options.
+async fn parse_source(source: &str) -> Vec<DocNode> {
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+}
+
 #[tokio::test]
 async fn content_type_handling() {
   let sources = vec![(
@@ -230,6 +274,7 @@ export function fooFn(a: number) {
       "jsDoc": {
         "doc": "JSDoc for const",
       },
+      "reExportSource": "file:///reexport.ts",
       "variableDef": {
         "tsType": {
           "repr": "foo",
@@ -255,6 +300,7 @@ export function fooFn(a: number) {
       "jsDoc": {
         "doc": "JSDoc for bar",
       },
+      "reExportSource": "file:///nested_reexport.ts",
       "variableDef": {
         "tsType": {
           "repr": "bar",
@@ -296,6 +342,10 @@ export function fooFn(a: number) {
         "typeParams": [],
         "returnType": null,
         "hasBody": true,
+        "bodySpan": {
+          "start": 185,
+          "end": 200
+        },
         "isAsync": false,
         "isGenerator": false
       },
@@ -359,6 +409,7 @@ export { Hello } from "./reexport.ts";
         "byteIndex": 1
       },
       "declarationKind": "export",
+      "reExportSource": "file:///reexport.ts",
       "interfaceDef": {
         "extends": [],
         "methods": [],
@@ -378,6 +429,7 @@ export { Hello } from "./reexport.ts";
         "byteIndex": 27
       },
       "declarationKind": "export",
+      "reExportSource": "file:///reexport.ts",
       "classDef": {
         "isAbstract": false,
         "constructors": [],
@@ -430,6 +482,7 @@ async fn deep_reexports() {
         "byteIndex": 13
       },
       "declarationKind": "export",
+      "reExportSource": "file:///foo.ts",
       "variableDef": {
         "tsType": {
           "repr": "string",
@@ -496,6 +549,7 @@ export * as b from "./mod_doc.ts";
           }
         ]
       },
+      "reExportSource": "file:///mod_doc.ts",
       "namespaceDef": {
         "elements": [
           {
@@ -533,7 +587,6 @@ export * as b from "./mod_doc.ts";
 #[tokio::test]
 async fn filter_nodes_by_name() {
   use crate::find_nodes_by_name_recursively;
-  use crate::DocNodeKind;
   let source_code = r#"
 export namespace Deno {
   export class Buffer {}
@@ -578,10 +631,14 @@ export namespace Deno {
   // Overloaded functions
   let found =
     find_nodes_by_name_recursively(entries.clone(), "Deno.test".to_string());
-  assert_eq!(found.len(), 3);
+  assert_eq!(found.len(), 2);
   assert_eq!(found[0].name, "test".to_string());
   assert_eq!(found[1].name, "test".to_string());
-  assert_eq!(found[2].name, "test".to_string());
+  assert!(found.iter().all(|node| node
+    .function_def
+    .as_ref()
+    .unwrap()
+    .is_overload));
 
   // Nested namespace
   let found =
@@ -667,6 +724,7 @@ async fn exports_imported_earlier() {
         "byteIndex": 13
       },
       "declarationKind": "export",
+      "reExportSource": "file:///foo.ts",
       "variableDef": {
         "tsType": {
           "repr": "string",
@@ -729,6 +787,7 @@ async fn exports_imported_earlier_renamed() {
         "byteIndex": 13
       },
       "declarationKind": "export",
+      "reExportSource": "file:///foo.ts",
       "variableDef": {
         "tsType": {
           "repr": "string",
@@ -792,6 +851,7 @@ async fn exports_imported_earlier_default() {
         "byteIndex": 6
       },
       "declarationKind": "export",
+      "reExportSource": "file:///foo.ts",
       "variableDef": {
         "tsType": {
           "repr": "string",
@@ -861,6 +921,7 @@ async fn exports_imported_earlier_private() {
         "byteIndex": 13
       },
       "declarationKind": "export",
+      "reExportSource": "file:///foo.ts",
       "variableDef": {
         "tsType": {
           "repr": "string",
@@ -935,6 +996,7 @@ async fn json_module() {
         "byteIndex": 0,
       },
       "declarationKind": "export",
+      "reExportSource": "file:///bar.json",
       "variableDef": {
         "tsType": {
           "repr": "",
@@ -1041,3 +1103,3311 @@ async fn json_module() {
 fn create_analyzer() -> CapturingModuleAnalyzer {
   CapturingModuleAnalyzer::default()
 }
+
+fn strip_locations(value: &mut serde_json::Value) {
+  match value {
+    serde_json::Value::Object(map) => {
+      map.remove("location");
+      map.values_mut().for_each(strip_locations);
+    }
+    serde_json::Value::Array(values) => {
+      values.iter_mut().for_each(strip_locations)
+    }
+    _ => {}
+  }
+}
+
+#[tokio::test]
+async fn to_dts_round_trip() {
+  let source = r#"export declare function foo<T extends string>(x: number, y?: T, ...rest: boolean[]): string;
+export declare const bar: { a: number; };
+export declare let baz: string | undefined;
+export declare interface Qux<T> extends Foo, Bar<T> {
+  (x: T): void;
+  readonly a: string;
+  b?: number;
+  new(x: string): Qux<T>;
+  c<U>(x: U): T;
+  get d(): string;
+  [key: string]: unknown;
+}
+export declare abstract class Quux<T> extends Base<T> implements Qux<T> {
+  constructor(x: string);
+  static a: number;
+  protected readonly b?: string;
+  [key: string]: unknown;
+  abstract c(): void;
+  get d(): T;
+  e<U>(x: U, y?: number): Promise<U>;
+}
+export declare enum E {
+  A = 1,
+  B,
+  C = "c",
+}
+export declare type U<T> = T extends string ? "str" : T[];
+export declare namespace N {
+  export function f(): void;
+  export interface I {
+    a: string;
+  }
+}
+"#;
+
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(dts, source);
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![("file:///mod.d.ts", None, dts.as_str())],
+  )
+  .await;
+  let round_tripped =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+
+  let mut expected = serde_json::to_value(&entries).unwrap();
+  let mut actual = serde_json::to_value(&round_tripped).unwrap();
+  strip_locations(&mut expected);
+  strip_locations(&mut actual);
+  assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn to_dts_implementation() {
+  let entries = parse_source(
+    r#"export async function foo(a = 1, { b }: { b: string }): Promise<void> {}
+export default class Foo {
+  constructor(private x: number, public readonly y = "y") {}
+  bar(): void {}
+}
+"#,
+  )
+  .await;
+
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(
+    dts,
+    r#"export declare function foo(a?: number, {b}: { b: string; }): Promise<void>;
+export default class Foo {
+  constructor(x: number, y?: string);
+  private x;
+  public readonly y: string;
+  bar(): void;
+}
+"#
+  );
+}
+
+#[tokio::test]
+async fn to_dts_abstract_accessors() {
+  let entries = parse_source(
+    r#"export abstract class Shape {
+  abstract get name(): string;
+  abstract set name(value: string);
+  get size(): number {
+    return 1;
+  }
+  set size(value: number) {}
+}
+"#,
+  )
+  .await;
+
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(
+    dts,
+    r#"export declare abstract class Shape {
+  abstract get name(): string;
+  abstract set name(value: string);
+  get size(): number;
+  set size(value: number);
+}
+"#
+  );
+}
+
+#[tokio::test]
+async fn to_dts_member_names() {
+  let entries = parse_source(
+    r#"export interface Headers {
+  "content-type"?: string;
+  0: string;
+  set "x-value"(value: string);
+}
+
+export class Client {
+  "user-agent": string = "";
+  "get-header"(): string {
+    return "";
+  }
+}
+
+export enum Kind {
+  "a-b" = 1,
+  C,
+}
+"#,
+  )
+  .await;
+
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(
+    dts,
+    r#"export declare interface Headers {
+  "content-type"?: string;
+  0: string;
+  set "x-value"(value: string);
+}
+export declare class Client {
+  "user-agent": string;
+  "get-header"(): string;
+}
+export declare enum Kind {
+  "a-b" = 1,
+  C,
+}
+"#
+  );
+}
+
+#[tokio::test]
+async fn to_dts_global_augmentation() {
+  let entries = parse_source(
+    r#"declare global {
+  /** The browser window. */
+  interface Window {
+    __APP_VERSION__: string;
+  }
+}
+
+export {};
+"#,
+  )
+  .await;
+
+  assert_eq!(entries.len(), 1);
+  assert!(
+    entries[0]
+      .namespace_def
+      .as_ref()
+      .unwrap()
+      .is_global_augmentation
+  );
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(
+    dts,
+    r#"declare global {
+  interface Window {
+    __APP_VERSION__: string;
+  }
+}
+"#
+  );
+}
+
+#[tokio::test]
+async fn to_dts_namespace_merge() {
+  let entries = parse_source(
+    r#"export enum Color {
+  Red,
+}
+export namespace Color {
+  export const fallback: Color = Color.Red;
+}
+"#,
+  )
+  .await;
+
+  assert_eq!(entries.len(), 1);
+  let dts = entries.iter().map(|node| node.to_dts()).collect::<String>();
+  assert_eq!(
+    dts,
+    r#"export declare enum Color {
+  Red,
+}
+export declare namespace Color {
+  export const fallback: Color;
+}
+"#
+  );
+}
+
+#[tokio::test]
+async fn module_path() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { a } from "./a.ts";
+export * from "./b.ts";
+export function main() {}
+"#,
+      ),
+      ("file:///a.ts", None, "export const a = 1;"),
+      (
+        "file:///b.ts",
+        None,
+        "export class B {}\nexport namespace C { export const d = 1; }",
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let mut actual = entries
+    .iter()
+    .map(|node| (node.get_name(), node.module_path()))
+    .collect::<Vec<_>>();
+  actual.sort();
+  assert_eq!(
+    actual,
+    vec![
+      ("B", "file:///b.ts"),
+      ("C", "file:///b.ts"),
+      ("a", "file:///a.ts"),
+      ("main", "file:///mod.ts"),
+    ]
+  );
+
+  let namespace_def = entries
+    .iter()
+    .find(|node| node.name == "C")
+    .and_then(|node| node.namespace_def.as_ref())
+    .unwrap();
+  for element in &namespace_def.elements {
+    assert_eq!(element.module_path(), "file:///b.ts");
+  }
+}
+
+#[tokio::test]
+async fn ts_type_to_ts_string() {
+  let entries = parse_source(
+    r#"export type A = string | number;
+export type B = { x: number; y: { z: string[] } };
+export type C = <T extends Foo>(x: T) => T;
+"#,
+  )
+  .await;
+  let ts_type = |name: &str| {
+    &entries
+      .iter()
+      .find(|node| node.name == name)
+      .unwrap()
+      .type_alias_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+  };
+
+  let opts = DisplayOptions::default();
+  assert_eq!(ts_type("A").to_ts_string(opts), "string | number");
+  assert_eq!(
+    ts_type("B").to_ts_string(opts),
+    "{ x: number; y: { z: string[]; }; }"
+  );
+  assert_eq!(
+    ts_type("C").to_ts_string(opts),
+    "<T extends Foo>(x: T) => T"
+  );
+
+  let opts = DisplayOptions { max_depth: Some(2) };
+  assert_eq!(ts_type("A").to_ts_string(opts), "string | number");
+  assert_eq!(
+    ts_type("B").to_ts_string(opts),
+    "{ x: number; y: { z: ...; }; }"
+  );
+  let opts = DisplayOptions { max_depth: Some(0) };
+  assert_eq!(ts_type("B").to_ts_string(opts), "...");
+}
+
+#[tokio::test]
+async fn doc_node_signature() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"/** @module */
+
+import { a, a as b } from "./a.ts";
+import c, * as d from "./a.ts";
+
+export async function foo<T>(x: T, y = 1): Promise<void> {}
+export abstract class Bar<T> extends Baz<T> implements Qux {}
+export const x: string = "x";
+export enum E { A }
+export interface I<T> extends J<T>, K {}
+export type U<T> = T | string;
+export namespace N {}
+"#,
+      ),
+      (
+        "file:///a.ts",
+        None,
+        "export const a = 1;\nexport default 1;",
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    DocParserOptions {
+      private: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+
+  let signatures = entries
+    .iter()
+    .map(|node| node.signature())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    signatures,
+    vec![
+      r#"module "file:///mod.ts""#,
+      "async function foo<T>(x: T, y?: number): Promise<void>",
+      "abstract class Bar<T> extends Baz<T> implements Qux",
+      "const x: string",
+      "enum E",
+      "interface I<T> extends J<T>, K",
+      "type U<T> = T | string",
+      "namespace N",
+      r#"import { a } from "file:///a.ts""#,
+      r#"import { a as b } from "file:///a.ts""#,
+      r#"import c from "file:///a.ts""#,
+      r#"import * as d from "file:///a.ts""#,
+    ]
+  );
+}
+
+#[tokio::test]
+async fn collapse_object_intersection() {
+  let entries = parse_source(
+    r#"export type A = { a: number } & { b: string };
+export type B = { a: number; b?: string } & ({ a: string; b?: string });
+export type C = { a?: number } & { a: number; c?: boolean };
+export type D = { a: number } & Foo;
+"#,
+  )
+  .await;
+  let ts_type = |name: &str| {
+    &entries
+      .iter()
+      .find(|node| node.name == name)
+      .unwrap()
+      .type_alias_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+  };
+  let collapse = |name: &str| {
+    ts_type(name)
+      .collapse_object_intersection()
+      .map(|ts_type| ts_type.to_ts_string(DisplayOptions::default()))
+  };
+
+  assert_eq!(collapse("A").as_deref(), Some("{ a: number; b: string; }"));
+  assert_eq!(collapse("B").as_deref(), Some("{ a: never; b?: string; }"));
+  assert_eq!(
+    collapse("C").as_deref(),
+    Some("{ a: number; c?: boolean; }")
+  );
+  assert_eq!(collapse("D"), None);
+
+  assert_eq!(
+    &ts_type("A").simplify(SimplifyOptions::default()),
+    ts_type("A")
+  );
+  assert_eq!(
+    ts_type("A").simplify(SimplifyOptions {
+      collapse_intersections: true,
+    }),
+    ts_type("A").collapse_object_intersection().unwrap()
+  );
+}
+
+#[tokio::test]
+async fn interface_optional_members_round_trip() {
+  let entries = parse_source(
+    r#"export interface A {
+  a: string;
+  b?: number;
+  c(): void;
+  d?(): void;
+}
+"#,
+  )
+  .await;
+  let json = serde_json::to_string(&entries).unwrap();
+  let entries: Vec<DocNode> = serde_json::from_str(&json).unwrap();
+  let interface_def = entries[0].interface_def.as_ref().unwrap();
+
+  let properties = interface_def
+    .properties
+    .iter()
+    .map(|property| (property.name.as_str(), property.optional))
+    .collect::<Vec<_>>();
+  assert_eq!(properties, vec![("a", false), ("b", true)]);
+  let methods = interface_def
+    .methods
+    .iter()
+    .map(|method| (method.name.as_str(), method.optional))
+    .collect::<Vec<_>>();
+  assert_eq!(methods, vec![("c", false), ("d", true)]);
+}
+
+#[tokio::test]
+async fn parse_module_empty_export() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![("file:///mod.ts", None, "export {};\n")],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  assert!(module_doc.is_module);
+  assert!(module_doc.definitions.is_empty());
+  assert!(module_doc.reexports.is_empty());
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///script.ts",
+    vec![("file:///script.ts", None, "const a = 1;\n")],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  assert!(!module_doc.is_module);
+  // declarations of scripts are global, so they are documented
+  assert_eq!(module_doc.definitions.len(), 1);
+}
+
+#[test]
+fn deserialize_module_doc_without_optional_fields() {
+  let module_doc: crate::node::ModuleDoc = serde_json::from_value(json!({
+    "definitions": [],
+    "reexports": [],
+  }))
+  .unwrap();
+  assert!(module_doc.filename.is_empty());
+  assert!(module_doc.checksum.is_empty());
+  assert!(!module_doc.is_module);
+}
+
+#[tokio::test]
+async fn parse_module_package_doc() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * Utilities to work with colors.
+ *
+ * @packageDocumentation
+ */
+
+export function red(text: string): string {
+  return text;
+}
+
+/** Makes the text blue. */
+export function blue(text: string): string {
+  return text;
+}
+"#,
+    )],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  let package_doc = module_doc.package_doc.unwrap();
+  assert_eq!(package_doc.description, "Utilities to work with colors.");
+  assert_eq!(package_doc.tags.tags, [JsDocTag::PackageDocumentation]);
+
+  let red = module_doc
+    .definitions
+    .iter()
+    .find(|node| node.name == "red")
+    .unwrap();
+  assert!(red.js_doc.is_empty());
+  let blue = module_doc
+    .definitions
+    .iter()
+    .find(|node| node.name == "blue")
+    .unwrap();
+  assert_eq!(blue.js_doc.doc.as_deref(), Some("Makes the text blue."));
+  assert!(module_doc
+    .definitions
+    .iter()
+    .all(|node| node.kind != DocNodeKind::ModuleDoc));
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![("file:///mod.ts", None, "export const a = 1;\n")],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  assert!(module_doc.package_doc.is_none());
+}
+
+#[tokio::test]
+async fn parse_module_checksum() {
+  async fn parse_module(source: &str) -> crate::node::ModuleDoc {
+    let (graph, analyzer, specifier) =
+      setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap()
+  }
+
+  let module_doc = parse_module("export const a = 1;\n").await;
+  assert_eq!(module_doc.filename, "file:///mod.ts");
+  assert_eq!(
+    module_doc.checksum,
+    "037ecd1db38c230c248787e60fd7bfc0cb0101b187b59535b6e7483be762d350"
+  );
+  assert_eq!(
+    parse_module("export const a = 1;\n").await.checksum,
+    module_doc.checksum
+  );
+  assert_ne!(
+    parse_module("export const a = 2;\n").await.checksum,
+    module_doc.checksum
+  );
+}
+
+#[tokio::test]
+async fn tree_printer_accessor_pair() {
+  let entries = parse_source(
+    r#"export class Bar {
+  get size(): number {
+    return 0;
+  }
+  set size(value: number) {}
+}
+"#,
+  )
+  .await;
+
+  // a getter and a setter of the same name are not overloads of each other
+  assert_eq!(
+    crate::TreePrinter::new("./mod.ts", &entries, false).to_string(),
+    r#"module ./mod.ts
+└── class Bar
+    ├── get size(): number
+    └── set size(value: number): void
+"#,
+  );
+}
+
+#[tokio::test]
+async fn tree_printer() {
+  let entries = parse_source(
+    r#"/**
+ * Converts a number.
+ *
+ * @param x The input number
+ * @returns The string representation
+ */
+export function foo(x: number): string {
+  return String(x);
+}
+
+/** A greeter. */
+export class Bar {
+  constructor(name: string) {}
+  /** @deprecated Use `greet` instead. */
+  hello(): void {}
+  greet(): void {}
+  get name(): string {
+    return "";
+  }
+  #secret = 1;
+}
+
+export interface Baz {
+  a?: string;
+  b(): void;
+}
+
+export enum Qux {
+  A,
+  B,
+}
+
+export namespace Quux {
+  export const c = 1;
+}
+"#,
+  )
+  .await;
+
+  assert_eq!(
+    crate::TreePrinter::new("./mod.ts", &entries, false).to_string(),
+    include_str!("../tests/testdata/tree.txt"),
+  );
+}
+
+#[tokio::test]
+async fn doc_parser_shares_module_graph() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      "export function a(): void {}\nfunction b(): void {}\n",
+    )],
+  )
+  .await;
+
+  let parse = |options| {
+    let entries = DocParser::new(&graph, &analyzer, options)
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+    serde_json::to_value(entries).unwrap()
+  };
+  assert_eq!(
+    parse(DocParserOptions::default()),
+    parse(DocParserOptions::default())
+  );
+  assert_eq!(
+    parse(DocParserOptions::default()).as_array().unwrap().len(),
+    1
+  );
+  assert_eq!(
+    parse(DocParserOptions {
+      private: true,
+      ..Default::default()
+    })
+    .as_array()
+    .unwrap()
+    .len(),
+    2
+  );
+}
+
+#[test]
+fn doc_node_kind_predicates() {
+  let cases = [
+    (DocNodeKind::Class, false, false, true),
+    (DocNodeKind::Enum, false, true, true),
+    (DocNodeKind::Function, true, false, true),
+    (DocNodeKind::Import, false, false, false),
+    (DocNodeKind::Interface, false, true, false),
+    (DocNodeKind::ModuleDoc, false, false, false),
+    (DocNodeKind::Namespace, false, false, true),
+    (DocNodeKind::TypeAlias, false, true, false),
+    (DocNodeKind::Variable, false, false, true),
+  ];
+  for (kind, function_like, type_like, value_like) in cases {
+    let node = DocNode {
+      kind,
+      ..Default::default()
+    };
+    assert_eq!(node.is_function_like(), function_like, "{kind:?}");
+    assert_eq!(node.is_type_like(), type_like, "{kind:?}");
+    assert_eq!(node.is_value_like(), value_like, "{kind:?}");
+  }
+}
+
+#[tokio::test]
+async fn ts_type_complexity_score() {
+  let entries = parse_source(
+    r#"export type A = string;
+export type B = Foo;
+export type C = string | number | undefined;
+export type D = { x: number; y: string };
+export type E = Promise<Array<string>>;
+export type F = (a: string, b: number) => boolean;
+export type G<T> = T extends Promise<infer U>
+  ? G<U>
+  : T extends (...args: any[]) => infer R
+  ? R
+  : { [K in keyof T]: G<T[K]> };
+"#,
+  )
+  .await;
+  let score = |name: &str| {
+    entries
+      .iter()
+      .find(|node| node.name == name)
+      .unwrap()
+      .type_alias_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+      .complexity_score()
+  };
+
+  assert_eq!(score("A"), 1);
+  assert_eq!(score("B"), 2);
+  assert_eq!(score("C"), 4);
+  assert_eq!(score("D"), 5);
+  assert_eq!(score("E"), 5);
+  assert_eq!(score("F"), 5);
+  assert_eq!(score("G"), 37);
+}
+
+#[tokio::test]
+async fn parse_rest_param_tag() {
+  let entries = parse_source(
+    r#"/** @param ...items The items */
+export function collect(...items: string[]) {}
+"#,
+  )
+  .await;
+
+  assert_eq!(
+    entries[0].js_doc.tags,
+    [JsDocTag::Param {
+      name: "items".to_string(),
+      type_ref: None,
+      optional: false,
+      default: None,
+      doc: Some("The items".to_string()),
+    }]
+  );
+}
+
+#[tokio::test]
+async fn mermaid_class_diagram() {
+  let entries = parse_source(
+    r#"export interface Named {
+  name: string;
+  greet(greeting: string): string;
+}
+export abstract class Animal implements Named {
+  name: string;
+  private secret: number;
+  abstract greet(greeting: string): string;
+}
+export class Dog extends Animal {
+  static count: number;
+  get tags(): Map<string, { a: number }> {}
+  greet(greeting: string): string {}
+}
+export class Puppy<T> extends Dog {}
+"#,
+  )
+  .await;
+
+  assert_eq!(
+    crate::generate_mermaid_class_diagram(&entries),
+    r#"classDiagram
+  class Named {
+    <<interface>>
+    +name: string
+    +greet(greeting: string) string
+  }
+  class Animal {
+    <<abstract>>
+    +name: string
+    +greet(greeting: string) string*
+  }
+  class Dog {
+    +count: number$
+    +tags: Map~string, #123; a: number; #125;~
+    +greet(greeting: string) string
+  }
+  class Puppy~T~ {
+  }
+  Named <|.. Animal
+  Animal <|-- Dog
+  Dog <|-- Puppy
+"#
+  );
+}
+
+#[tokio::test]
+async fn doc_node_all_type_refs() {
+  let entries = parse_source(
+    r#"export class A<T extends Base> extends Parent<Foo> implements Iface {
+  foo: Foo;
+  bar?: Map<string, Bar>;
+  constructor(baz: Baz) {}
+  method<U>(a: Foo, b: U): Promise<Bar> {}
+  other(): Base[] {}
+}
+"#,
+  )
+  .await;
+
+  assert_eq!(
+    entries[0].all_type_refs(),
+    vec![
+      "Parent", "Foo", "Iface", "Map", "Bar", "Promise", "Base", "Baz", "U",
+    ]
+  );
+}
+
+#[test]
+fn ts_type_string_intrinsics() {
+  for name in ["Uppercase", "Lowercase", "Capitalize", "Uncapitalize"] {
+    let ts_type = parse_ts_type(&format!("{name}<string>")).unwrap();
+    let json = serde_json::to_value(&ts_type).unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "repr": name,
+        "kind": "typeRef",
+        "typeRef": {
+          "typeParams": [
+            {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
+            }
+          ],
+          "typeName": name
+        }
+      })
+    );
+    let round_tripped: crate::ts_type::TsTypeDef =
+      serde_json::from_value(json).unwrap();
+    assert_eq!(round_tripped, ts_type);
+    assert!(round_tripped.type_ref.unwrap().is_utility_type());
+  }
+
+  // the declarations of the intrinsics in the TypeScript libraries
+  let ts_type = parse_ts_type("intrinsic").unwrap();
+  assert_eq!(ts_type.kind, Some(TsTypeDefKind::Keyword));
+  assert_eq!(ts_type.keyword.as_deref(), Some("intrinsic"));
+}
+
+#[test]
+fn ts_type_is_primitive() {
+  let is_primitive = |text: &str| parse_ts_type(text).unwrap().is_primitive();
+
+  for primitive in [
+    "string",
+    "number",
+    "boolean",
+    "bigint",
+    "symbol",
+    "null",
+    "undefined",
+    "void",
+    "never",
+    "any",
+    "unknown",
+    "object",
+    r#""a""#,
+    "1",
+    "1n",
+    "true",
+  ] {
+    assert!(is_primitive(primitive), "{primitive}");
+  }
+  for non_primitive in [
+    "Foo",
+    "string[]",
+    "string | number",
+    "{ a: string }",
+    "`a${string}`",
+    "(a: string) => void",
+    "(string)",
+    "this",
+  ] {
+    assert!(!is_primitive(non_primitive), "{non_primitive}");
+  }
+}
+
+#[tokio::test]
+async fn ts_type_referenced_type_names() {
+  let entries = parse_source(
+    r#"export type A = Map<string, Promise<{ x: MyType }>>;
+export type B = string | 1 | "a";
+export type C = (a: Foo, b: Bar<Foo>) => Baz[];
+export type D<T extends Base> = T extends Array<infer U> ? U : Fallback;
+"#,
+  )
+  .await;
+  let names = |name: &str| {
+    entries
+      .iter()
+      .find(|node| node.name == name)
+      .unwrap()
+      .type_alias_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+      .referenced_type_names()
+  };
+
+  assert_eq!(names("A"), ["Map", "Promise", "MyType"]);
+  assert!(names("B").is_empty());
+  assert_eq!(names("C"), ["Foo", "Bar", "Baz"]);
+  assert_eq!(names("D"), ["T", "Array", "U", "Fallback"]);
+}
+
+#[test]
+fn ts_type_substitute_type_params() {
+  let substitutions = HashMap::from([
+    ("T".to_string(), parse_ts_type("string").unwrap()),
+    ("U".to_string(), parse_ts_type("number[]").unwrap()),
+  ]);
+  let substitute = |text: &str| {
+    parse_ts_type(text)
+      .unwrap()
+      .substitute_type_params(&substitutions)
+  };
+
+  assert_eq!(substitute("T"), parse_ts_type("string").unwrap());
+  assert_eq!(
+    substitute("Promise<T>"),
+    parse_ts_type("Promise<string>").unwrap()
+  );
+  assert_eq!(
+    substitute("Map<T, Set<U | V>>"),
+    parse_ts_type("Map<string, Set<number[] | V>>").unwrap()
+  );
+  assert_eq!(
+    substitute("{ a: T; b(x: Other): U }"),
+    parse_ts_type("{ a: string; b(x: Other): number[] }").unwrap()
+  );
+  // type parameters declared within the type shadow the substituted ones
+  assert_eq!(
+    substitute("<T>(x: T, y: U) => T"),
+    parse_ts_type("<T>(x: T, y: number[]) => T").unwrap()
+  );
+  // only within the binder that declares them
+  assert_eq!(
+    substitute("T | (<T>(x: T) => T) | Array<T>"),
+    parse_ts_type("string | (<T>(x: T) => T) | Array<string>").unwrap()
+  );
+  assert_eq!(
+    substitute("{ a: T; b<T>(x: T): U; c: T }"),
+    parse_ts_type("{ a: string; b<T>(x: T): number[]; c: string }").unwrap()
+  );
+  assert_eq!(
+    substitute("{ [T in keyof U]: T } & T"),
+    parse_ts_type("{ [T in keyof number[]]: T } & string").unwrap()
+  );
+  assert_eq!(
+    substitute("U extends Array<infer T> ? T : T"),
+    parse_ts_type("number[] extends Array<infer T> ? T : string").unwrap()
+  );
+  assert_eq!(substitute("Other"), parse_ts_type("Other").unwrap());
+}
+
+#[tokio::test]
+async fn broken_links() {
+  let entries = parse_source(
+    r#"export interface Options {
+  signal?: AbortSignal;
+  cache: Cache;
+}
+export function process<T>(input: T, options: Options): Promise<Output> {}
+export type Mapped<T> = { [K in keyof T]: T[K] extends infer U ? U : never };
+export namespace Ns {
+  export interface Inner {}
+  export const inner: Ns.Inner | Deno.Reader;
+}
+"#,
+  )
+  .await;
+
+  assert_eq!(
+    broken_links_report(&entries),
+    [
+      BrokenLink {
+        node_name: "Options".to_string(),
+        field: "properties.cache".to_string(),
+        referenced_type: "Cache".to_string(),
+      },
+      BrokenLink {
+        node_name: "process".to_string(),
+        field: "returnType".to_string(),
+        referenced_type: "Output".to_string(),
+      },
+    ]
+  );
+
+  let known_types = ["Cache", "Output", "Promise", "AbortSignal"]
+    .into_iter()
+    .map(String::from)
+    .collect();
+  assert!(
+    broken_links_report_with_known_types(&entries, &known_types).is_empty()
+  );
+}
+
+#[tokio::test]
+async fn doc_node_to_json_compact() {
+  let entries = parse_source(r#"export function foo([, b]: [string, number], c?: Map<string, number>): void {}
+export class Bar<T> extends Array<T> {
+  baz: string | null = null;
+}
+"#).await;
+
+  for entry in &entries {
+    let json = serde_json::to_string(entry).unwrap();
+    let compact = entry.to_json_compact();
+    assert!(json.contains(":null"));
+    assert!(!compact.contains(":null"));
+    assert!(compact.len() < json.len());
+
+    let round_trip: DocNode = serde_json::from_str(&compact).unwrap();
+    assert_eq!(
+      serde_json::to_value(&round_trip).unwrap(),
+      serde_json::to_value(entry).unwrap()
+    );
+  }
+
+  let mut compact = vec![];
+  write_doc_nodes_compact(&entries, &mut compact).unwrap();
+  let round_trip: Vec<DocNode> = serde_json::from_slice(&compact).unwrap();
+  assert_eq!(
+    serde_json::to_value(&round_trip).unwrap(),
+    serde_json::to_value(&entries).unwrap()
+  );
+}
+
+#[tokio::test]
+async fn merge_jsdoc_from_declaration_file() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function add(a: number, b: number): number {
+  return a + b;
+}
+/** Subtracts locally. */
+export function sub(a: number, b: number): number {
+  return a - b;
+}
+export namespace math {
+  export function mul(a: number, b: number): number {
+    return a * b;
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let mut entries =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"/** Adds two numbers. */
+export declare function add(a: number, b: number): number;
+/** Subtracts two numbers. */
+export declare function sub(a: number, b: number): number;
+export declare namespace math {
+  /**
+   * Multiplies two numbers.
+   * @deprecated use `*`
+   */
+  export function mul(a: number, b: number): number;
+}
+"#,
+    )],
+  )
+  .await;
+  let dts_entries =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+
+  crate::merge_jsdoc_from_declaration_file(&mut entries, &dts_entries);
+
+  let doc = |nodes: &[DocNode], name: &str| {
+    nodes
+      .iter()
+      .find(|node| node.name == name)
+      .unwrap()
+      .js_doc
+      .doc
+      .clone()
+  };
+  assert_eq!(doc(&entries, "add").as_deref(), Some("Adds two numbers."));
+  assert_eq!(doc(&entries, "sub").as_deref(), Some("Subtracts locally."));
+  let math = entries.iter().find(|node| node.name == "math").unwrap();
+  let elements = &math.namespace_def.as_ref().unwrap().elements;
+  assert_eq!(
+    doc(elements, "mul").as_deref(),
+    Some("Multiplies two numbers.")
+  );
+  assert_eq!(elements[0].js_doc.tags.len(), 1);
+}
+
+#[tokio::test]
+async fn merge_jsdoc_from_declaration_file_derived_fields() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function parse<T>(value: string): T {
+  return JSON.parse(value);
+}
+export namespace json {
+  export function stringify(value: unknown): string {
+    return JSON.stringify(value);
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let mut entries =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"/**
+ * Parses a value.
+ *
+ * @template T The type of the value
+ * @returns The parsed value
+ * @experimental
+ */
+export declare function parse<T>(value: string): T;
+/** @experimental */
+export declare namespace json {
+  /** Stringifies a value. */
+  export function stringify(value: unknown): string;
+}
+"#,
+    )],
+  )
+  .await;
+  let dts_entries =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+
+  crate::merge_jsdoc_from_declaration_file(&mut entries, &dts_entries);
+
+  let parse = entries.iter().find(|node| node.name == "parse").unwrap();
+  let function_def = parse.function_def.as_ref().unwrap();
+  assert_eq!(
+    function_def.return_type_doc.as_deref(),
+    Some("The parsed value")
+  );
+  assert_eq!(
+    function_def.type_params[0].description.as_deref(),
+    Some("The type of the value")
+  );
+  assert_eq!(parse.stability, crate::node::Stability::Experimental);
+  assert_eq!(
+    parse.stability_source,
+    Some(crate::node::StabilitySource::Direct)
+  );
+
+  let json = entries.iter().find(|node| node.name == "json").unwrap();
+  let stringify = &json.namespace_def.as_ref().unwrap().elements[0];
+  assert_eq!(stringify.stability, crate::node::Stability::Experimental);
+  assert_eq!(
+    stringify.stability_source,
+    Some(crate::node::StabilitySource::InheritedFromNamespace)
+  );
+}
+
+#[tokio::test]
+async fn prefer_declaration_files() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"/// <reference types="./mod.d.ts" />
+export function add(a, b) {
+  return a + b;
+}
+"#,
+      ),
+      (
+        "file:///mod.d.ts",
+        None,
+        r#"/** Adds two numbers. */
+export declare function add(a: number, b: number): number;
+"#,
+      ),
+    ],
+  )
+  .await;
+
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(entries[0].location.filename, "file:///mod.ts");
+  assert!(entries[0].js_doc.is_empty());
+
+  let parser = DocParser::new(
+    &graph,
+    &analyzer,
+    DocParserOptions {
+      prefer_declaration_files: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  for entries in [
+    parser.parse(&specifier).unwrap(),
+    parser.parse_with_reexports(&specifier).unwrap(),
+  ] {
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].location.filename, "file:///mod.d.ts");
+    assert_eq!(entries[0].js_doc.doc.as_deref(), Some("Adds two numbers."));
+    assert_eq!(
+      entries[0].to_string(),
+      "function add(a: number, b: number): number"
+    );
+  }
+}
+
+#[tokio::test]
+async fn parse_module_references() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/// <reference types="@types/node" />
+/// <reference lib="dom" />
+/// <reference path="./globals.d.ts" />
+
+export const a = 1;
+"#,
+    )],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  assert_eq!(module_doc.references, ["@types/node", "dom"]);
+}
+
+#[tokio::test]
+async fn parse_module_type_only_reexports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { type Foo, value } from "./a.ts";
+export type { Bar } from "./a.ts";
+"#,
+      ),
+      (
+        "file:///a.ts",
+        None,
+        r#"export interface Foo {}
+export interface Bar {}
+export const value = 1;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let module_doc =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse_module(&specifier)
+      .unwrap();
+  let reexports = module_doc
+    .reexports
+    .iter()
+    .map(|reexport| match &reexport.kind {
+      ReexportKind::Named(name, _) => (name.as_str(), reexport.is_type_only),
+      _ => unreachable!(),
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(reexports, [("Foo", true), ("value", false), ("Bar", true)]);
+}
+
+#[tokio::test]
+async fn parse_template_tags() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.js",
+    vec![(
+      "file:///mod.js",
+      None,
+      r#"/**
+ * @template {string} K, V
+ * @param k - The key
+ */
+export function get(k, v) {}
+
+/**
+ * @template T The type of the value.
+ */
+export class Box {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+
+  let get = entries.iter().find(|node| node.name == "get").unwrap();
+  let type_params = &get.function_def.as_ref().unwrap().type_params;
+  assert_eq!(type_params.len(), 2);
+  assert_eq!(type_params[0].name, "K");
+  assert_eq!(type_params[0].constraint.as_ref().unwrap().repr, "string");
+  assert_eq!(type_params[1].name, "V");
+  assert!(type_params[1].constraint.is_none());
+  let params = get
+    .js_doc
+    .tags
+    .iter()
+    .filter(|tag| matches!(tag, JsDocTag::Param { .. }))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    params,
+    [&JsDocTag::Param {
+      name: "k".to_string(),
+      type_ref: None,
+      optional: false,
+      default: None,
+      doc: Some("- The key".to_string()),
+    }]
+  );
+
+  let class = entries.iter().find(|node| node.name == "Box").unwrap();
+  let type_params = &class.class_def.as_ref().unwrap().type_params;
+  assert_eq!(type_params.len(), 1);
+  assert_eq!(
+    type_params[0].description.as_deref(),
+    Some("The type of the value.")
+  );
+}
+
+#[tokio::test]
+async fn doc_node_related_symbols() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export * from "./other.ts";
+
+export interface Options {
+  verbose: boolean;
+}
+
+/**
+ * Runs the task.
+ * @see {@link Report}
+ */
+export function run<T>(task: T, options: Options): void {}
+
+export function configure(options: Options): void {}
+
+export function runAll(): void {}
+
+export class Unrelated<T> {
+  value?: T;
+}
+"#,
+      ),
+      (
+        "file:///other.ts",
+        None,
+        r#"export interface Report {}
+
+export function configureReport(options: { verbose: boolean }): void {}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+  let related_names = |name: &str| {
+    let node = entries.iter().find(|node| node.name == name).unwrap();
+    node
+      .related_symbols(&entries)
+      .into_iter()
+      .map(|node| node.name.as_str())
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    related_names("run"),
+    vec!["Report", "Options", "configure", "runAll"]
+  );
+  assert_eq!(
+    related_names("configure"),
+    vec!["Options", "run", "configureReport"]
+  );
+  assert_eq!(related_names("Report"), Vec::<&str>::new());
+  assert_eq!(related_names("Unrelated"), Vec::<&str>::new());
+}
+
+#[tokio::test]
+async fn jsdoc_links_lint() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { internal as renamed } from "./other.ts";
+
+/**
+ * See {@link Client.connect}, {@linkcode Client#close}, {@link renamed},
+ * {@link Level.High | the high level} and {@link https://deno.land}.
+ *
+ * @param options Uses {@link Missing}.
+ */
+export function run(options: unknown): void {}
+
+export class Client {
+  /** Prefer {@link Client.open} and {@link Fetcher}. */
+  connect(): void {}
+  close(): void {}
+}
+
+export enum Level {
+  High,
+}
+
+/** A {@link ns.inner} and a {@link ns.missing}. */
+export namespace ns {
+  export const inner = 1;
+}
+"#,
+      ),
+      ("file:///other.ts", None, "export const internal = 1;"),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let link = |diagnostic: &DocLintDiagnostic| match &diagnostic.kind {
+    DocLintDiagnosticKind::UnresolvedLink { link } => link.clone(),
+    kind => panic!("unexpected diagnostic {kind:?}"),
+  };
+
+  let diagnostics = lint_jsdoc_links(&entries);
+  assert_eq!(
+    diagnostics
+      .iter()
+      .map(|diagnostic| {
+        (
+          diagnostic.node_name.as_str(),
+          link(diagnostic),
+          diagnostic.location.line,
+        )
+      })
+      .collect::<Vec<_>>(),
+    vec![
+      ("run", "Missing".to_string(), 9),
+      ("Client.connect", "Client.open".to_string(), 13),
+      ("Client.connect", "Fetcher".to_string(), 13),
+      ("ns", "ns.missing".to_string(), 22),
+    ]
+  );
+  assert_eq!(diagnostics[0].code(), "unresolved-link");
+  assert_eq!(
+    diagnostics[0].message(),
+    r#"link to "Missing" in the JSDoc of "run" can't be resolved"#
+  );
+
+  let known_symbols = ["Fetcher".to_string()].into_iter().collect();
+  let diagnostics =
+    lint_jsdoc_links_with_known_symbols(&entries, &known_symbols)
+      .iter()
+      .map(link)
+      .collect::<Vec<_>>();
+  assert_eq!(diagnostics, vec!["Missing", "Client.open", "ns.missing"]);
+}
+
+#[tokio::test]
+async fn shadow_builtins_lint() {
+  let entries = parse_source(
+    r#"export function Array(): void;
+export function Array(length: number): void;
+export function Array(length?: number): void {}
+export function customArray(): void {}
+export const fetch = () => {};
+export type Config = { name: string };
+class Promise {}
+"#,
+  )
+  .await;
+
+  let diagnostics = lint_shadow_builtins(&entries);
+  assert_eq!(
+    diagnostics
+      .iter()
+      .map(|diagnostic| diagnostic.node_name.as_str())
+      .collect::<Vec<_>>(),
+    ["Array", "fetch"]
+  );
+  assert_eq!(diagnostics[0].location.line, 1);
+  assert_eq!(diagnostics[0].code(), "shadowed-builtin");
+  assert_eq!(
+    diagnostics[0].message(),
+    r#"exported function "Array" shadows a built-in of the same name"#
+  );
+
+  let builtins = ["Config".to_string()].into_iter().collect();
+  let diagnostics = lint_shadow_builtins_with_builtins(&entries, &builtins)
+    .into_iter()
+    .map(|diagnostic| diagnostic.node_name)
+    .collect::<Vec<_>>();
+  assert_eq!(diagnostics, ["Config"]);
+}
+
+#[tokio::test]
+async fn interface_to_type_literal() {
+  let entries = parse_source(
+    r#"export interface Point {
+  readonly x: number;
+  y?: number;
+  move(dx: number): void;
+  move(dx: number, dy: number): void;
+}
+
+export type PointLiteral = {
+  readonly x: number;
+  y?: number;
+  move(dx: number): void;
+  move(dx: number, dy: number): void;
+};
+
+export interface Point3D extends Point {
+  z: number;
+}
+
+export interface Dictionary {
+  [key: string]: number;
+}
+"#,
+  )
+  .await;
+  let find =
+    |name: &str| entries.iter().find(|node| node.name == name).unwrap();
+
+  let type_literal = find("Point")
+    .interface_def
+    .as_ref()
+    .unwrap()
+    .to_type_literal()
+    .unwrap();
+  let expected = &find("PointLiteral")
+    .type_alias_def
+    .as_ref()
+    .unwrap()
+    .ts_type;
+  assert_eq!(type_literal.kind, Some(TsTypeDefKind::TypeLiteral));
+  assert_eq!(type_literal.to_string(), expected.to_string());
+  assert_eq!(
+    serde_json::to_value(&type_literal.type_literal).unwrap(),
+    serde_json::to_value(&expected.type_literal).unwrap()
+  );
+
+  for name in ["Point3D", "Dictionary"] {
+    let interface_def = find(name).interface_def.as_ref().unwrap();
+    assert!(interface_def.to_type_literal().is_none());
+  }
+}
+
+#[tokio::test]
+async fn parse_module_re_exported_by() {
+  let (graph, analyzer, _) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export * from "./b.ts";
+export * from "./c.ts";
+export * from "./d.ts";
+"#,
+      ),
+      ("file:///a.ts", None, "export const a = 1;"),
+      ("file:///b.ts", None, r#"export { a } from "./a.ts";"#),
+      ("file:///c.ts", None, r#"export * as ns from "./a.ts";"#),
+      (
+        "file:///d.ts",
+        None,
+        r#"import { a } from "./a.ts";
+export const d = a;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default()).unwrap();
+
+  let module_doc = parser
+    .parse_module(&ModuleSpecifier::parse("file:///a.ts").unwrap())
+    .unwrap();
+  assert_eq!(module_doc.re_exported_by, ["file:///b.ts", "file:///c.ts"]);
+
+  let module_doc = parser
+    .parse_module(&ModuleSpecifier::parse("file:///b.ts").unwrap())
+    .unwrap();
+  assert_eq!(module_doc.re_exported_by, ["file:///mod.ts"]);
+}
+
+#[tokio::test]
+async fn class_body_source() {
+  let class_source = r#"class Counter {
+  #count = 0;
+
+  /** The current count. */
+  get count(): number {
+    return this.#count;
+  }
+
+  /** Increments the counter by `by`. */
+  increment(by = 1): void {
+    this.#count += by;
+  }
+
+  /** Resets the counter. */
+  reset(): void {
+    this.#count = 0;
+  }
+}"#;
+  let source = format!(
+    "/** A counter. */\nexport {class_source}\n\n/** The default counter. */\nexport default class {{\n  value = 0;\n}}\n"
+  );
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, &source)]).await;
+
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert!(entries.iter().all(|entry| entry
+    .class_def
+    .as_ref()
+    .unwrap()
+    .body_source
+    .is_none()));
+
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    DocParserOptions {
+      include_source: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let body_source = |name: &str| {
+    let entry = entries.iter().find(|entry| entry.name == name).unwrap();
+    entry.class_def.as_ref().unwrap().body_source.clone()
+  };
+  assert_eq!(body_source("Counter").as_deref(), Some(class_source));
+  assert_eq!(
+    body_source("default").as_deref(),
+    Some("class {\n  value = 0;\n}")
+  );
+}
+
+#[tokio::test]
+async fn class_method_optional() {
+  let entries = parse_source(
+    r#"/** A partial implementation. */
+export abstract class Partial {
+  /** A required method. */
+  required(): void {}
+  /** An optional method. */
+  optional?(): void;
+}
+"#,
+  )
+  .await;
+  let methods = serde_json::to_value(entries[0].class_def.as_ref().unwrap())
+    .unwrap()["methods"]
+    .clone();
+  assert_eq!(methods[0]["name"], "required");
+  assert_eq!(methods[0]["optional"], false);
+  assert_eq!(methods[1]["name"], "optional");
+  assert_eq!(methods[1]["optional"], true);
+}
+
+#[tokio::test]
+async fn variable_kind_json() {
+  let entries = parse_source(
+    r#"/** A var. */
+export var a = 1;
+/** A let. */
+export let b = 2;
+/** A const. */
+export const c = 3;
+"#,
+  )
+  .await;
+  let kinds = entries
+    .iter()
+    .map(|entry| {
+      let json = serde_json::to_value(entry).unwrap();
+      (entry.name.clone(), json["variableDef"]["kind"].clone())
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    kinds,
+    [
+      ("a".to_string(), json!("var")),
+      ("b".to_string(), json!("let")),
+      ("c".to_string(), json!("const")),
+    ]
+  );
+}
+
+#[tokio::test]
+async fn function_body_span() {
+  let source = r#"/** An overload. */
+export function f(a: string): string;
+/** Another overload. */
+export function f(a: number): number;
+export function f(a: string | number) {
+  return a;
+}
+
+/** A class. */
+export class A {
+  /** A method. */
+  method() {
+    return "method";
+  }
+}
+"#;
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let overloads = entries
+    .iter()
+    .filter_map(|entry| entry.function_def.as_ref())
+    .collect::<Vec<_>>();
+  assert_eq!(overloads.len(), 2);
+  assert!(overloads
+    .iter()
+    .all(|function_def| function_def.body_span.is_none()));
+
+  let class_def = entries
+    .iter()
+    .find_map(|entry| entry.class_def.as_ref())
+    .unwrap();
+  let body_span = class_def.methods[0].function_def.body_span.unwrap();
+  assert_eq!(
+    &source[body_span.start..body_span.end],
+    "{\n    return \"method\";\n  }"
+  );
+}
+
+#[tokio::test]
+async fn summary_mode() {
+  /// Whether every value of `summary` is also in `full`, with the JSDoc
+  /// description reduced to a prefix and the lists of members emptied.
+  fn is_subset(summary: &serde_json::Value, full: &serde_json::Value) -> bool {
+    match (summary, full) {
+      (serde_json::Value::Object(summary), serde_json::Value::Object(full)) => {
+        summary
+          .iter()
+          .all(|(key, value)| match (key.as_str(), value) {
+            ("doc", serde_json::Value::String(doc)) => full[key]
+              .as_str()
+              .is_some_and(|full_doc| full_doc.starts_with(doc.as_str())),
+            _ => full.get(key).is_some_and(|full| is_subset(value, full)),
+          })
+      }
+      (serde_json::Value::Array(summary), serde_json::Value::Array(full)) => {
+        summary.is_empty()
+          || summary.len() == full.len()
+            && summary.iter().zip(full).all(|(a, b)| is_subset(a, b))
+      }
+      _ => summary == full,
+    }
+  }
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * Adds two numbers. The numbers must be finite.
+ *
+ * @param a The first number.
+ * @param b The second number.
+ */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+/**
+ * A point.
+ *
+ * @experimental
+ */
+export interface Point {
+  /** The x coordinate. */
+  x: number;
+  y: number;
+}
+
+/** A namespace. */
+export namespace ns {
+  /** A constant. */
+  export const value: string = "value";
+
+  /** A class. */
+  export class Shape {
+    /** The area. */
+    area(): number {
+      return 0;
+    }
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let parse = |mode| {
+    DocParser::new(
+      &graph,
+      &analyzer,
+      DocParserOptions {
+        mode,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let full = serde_json::to_value(parse(DocMode::Full)).unwrap();
+  let summary = serde_json::to_value(parse(DocMode::Summary)).unwrap();
+
+  assert!(is_subset(&summary, &full));
+  let node = |nodes: &serde_json::Value, name: &str| {
+    nodes
+      .as_array()
+      .unwrap()
+      .iter()
+      .find(|node| node["name"] == name)
+      .unwrap()
+      .clone()
+  };
+  let add = node(&summary, "add");
+  assert_eq!(add["kind"], "function");
+  assert_eq!(add["declarationKind"], "export");
+  assert_eq!(add["jsDoc"], json!({ "doc": "Adds two numbers." }));
+  assert_eq!(add["functionDef"]["params"].as_array().unwrap().len(), 2);
+  assert_eq!(add["functionDef"]["returnType"]["repr"], "number");
+  assert!(add["functionDef"].get("bodySpan").is_none());
+
+  let point = node(&summary, "Point");
+  assert_eq!(point["stability"], "experimental");
+  assert_eq!(point["interfaceDef"]["properties"], json!([]));
+
+  let ns = node(&summary, "ns");
+  let elements = &ns["namespaceDef"]["elements"];
+  assert_eq!(
+    node(elements, "value")["jsDoc"],
+    json!({ "doc": "A constant." })
+  );
+  assert_eq!(node(elements, "Shape")["classDef"]["methods"], json!([]));
+
+  // the summaries can be printed like the full nodes
+  let summary = parse(DocMode::Summary);
+  let output = DocPrinter::new(&summary, false, false).to_string();
+  assert!(output.contains("function add(a: number, b: number): number"));
+  assert!(output.contains("class Shape"));
+  assert!(!output.contains("area"));
+  let tree = crate::TreePrinter::new("./mod.ts", &summary, false).to_string();
+  assert!(tree.contains("interface Point"));
+}
+
+#[tokio::test]
+async fn class_properties_from_constructor() {
+  let entries = parse_source(
+    r#"/** A point. */
+export class Point {
+  /** The label of the point. */
+  label = "origin";
+
+  /** Creates a point. */
+  constructor(
+    public x: number,
+    protected readonly y: number,
+    private z = 0,
+  ) {}
+}
+"#,
+  )
+  .await;
+  let class_def = entries[0].class_def.as_ref().unwrap();
+  let constructor_line = class_def.constructors[0].location.line;
+  let from_constructor = class_def
+    .properties
+    .iter()
+    .filter(|property| property.from_constructor)
+    .collect::<Vec<_>>();
+  assert_eq!(
+    from_constructor
+      .iter()
+      .map(|property| property.name.as_str())
+      .collect::<Vec<_>>(),
+    ["x", "y", "z"]
+  );
+  for (i, property) in from_constructor.iter().enumerate() {
+    assert_eq!(property.location.line, constructor_line + i + 1);
+  }
+  let label = class_def
+    .properties
+    .iter()
+    .find(|property| property.name == "label")
+    .unwrap();
+  assert!(!label.from_constructor);
+}
+
+#[tokio::test]
+async fn declaration_merging() {
+  let entries = parse_source(
+    r#"/** An interface. */
+export interface A extends B {
+  a: string;
+}
+export interface A extends B, C {
+  b(): number;
+}
+
+/** A base interface. */
+export interface B {}
+/** Another base interface. */
+export interface C {}
+
+/** A color. */
+export enum Color {
+  Red,
+}
+export enum Color {
+  Green = 1,
+}
+
+/** A class with static members from a namespace. */
+export class Point {}
+/** The point namespace. */
+export namespace Point {
+  /** The origin. */
+  export const origin = new Point();
+  /** Parses a point. */
+  export function parse(text: string): Point {
+    return new Point();
+  }
+}
+
+/** A class merged with a namespace that declares types. */
+export class Shape {}
+/** The shape namespace. */
+export namespace Shape {
+  /** The kind of shapes. */
+  export type Kind = "circle" | "square";
+}
+
+/** A direction. */
+export enum Direction {
+  Up,
+  Down,
+}
+/** The direction namespace. */
+export namespace Direction {
+  /** Parses a direction. */
+  export function parse(text: string): Direction {
+    return Direction.Up;
+  }
+}
+
+/** The options namespace. */
+export namespace Options {
+  /** The default options. */
+  export const defaults: Options = {};
+}
+/** Some options. */
+export interface Options {
+  verbose?: boolean;
+}
+
+/** A function. */
+export function f() {}
+/** The function namespace. */
+export namespace f {
+  /** A value. */
+  export const value = 1;
+}
+"#,
+  )
+  .await;
+  let nodes = |name: &str| {
+    entries
+      .iter()
+      .filter(|entry| entry.name == name)
+      .collect::<Vec<_>>()
+  };
+
+  let a = nodes("A");
+  assert_eq!(a.len(), 1);
+  assert_eq!(a[0].js_doc.doc.as_deref(), Some("An interface."));
+  let interface_def = a[0].interface_def.as_ref().unwrap();
+  assert_eq!(
+    interface_def
+      .extends
+      .iter()
+      .map(|extends| extends.repr.as_str())
+      .collect::<Vec<_>>(),
+    ["B", "C"]
+  );
+  assert_eq!(interface_def.properties[0].name, "a");
+  assert_eq!(interface_def.methods[0].name, "b");
+
+  let color = nodes("Color");
+  assert_eq!(color.len(), 1);
+  assert_eq!(
+    color[0]
+      .enum_def
+      .as_ref()
+      .unwrap()
+      .members
+      .iter()
+      .map(|member| member.name.as_str())
+      .collect::<Vec<_>>(),
+    ["Red", "Green"]
+  );
+
+  let point = nodes("Point");
+  assert_eq!(point.len(), 1);
+  assert_eq!(point[0].kind, DocNodeKind::Class);
+  let class_def = point[0].class_def.as_ref().unwrap();
+  assert_eq!(class_def.properties[0].name, "origin");
+  assert!(class_def.properties[0].is_static);
+  assert!(class_def.properties[0].readonly);
+  assert_eq!(class_def.methods[0].name, "parse");
+  assert!(class_def.methods[0].is_static);
+
+  let elements = |node: &DocNode| {
+    node
+      .namespace_def
+      .as_ref()
+      .unwrap()
+      .elements
+      .iter()
+      .map(|element| element.name.clone())
+      .collect::<Vec<_>>()
+  };
+
+  let direction = nodes("Direction");
+  assert_eq!(direction.len(), 1);
+  assert_eq!(direction[0].kind, DocNodeKind::Enum);
+  assert_eq!(direction[0].js_doc.doc.as_deref(), Some("A direction."));
+  assert_eq!(direction[0].enum_def.as_ref().unwrap().members.len(), 2);
+  assert_eq!(elements(direction[0]), ["parse"]);
+
+  // the namespace is declared first, the merged node is still an interface
+  let options = nodes("Options");
+  assert_eq!(options.len(), 1);
+  assert_eq!(options[0].kind, DocNodeKind::Interface);
+  assert_eq!(options[0].js_doc.doc.as_deref(), Some("Some options."));
+  assert_eq!(
+    options[0].interface_def.as_ref().unwrap().properties[0].name,
+    "verbose"
+  );
+  assert_eq!(elements(options[0]), ["defaults"]);
+
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert!(output.contains("function parse(text: string): Direction"));
+  assert!(output.contains("const defaults: Options"));
+
+  let shape = nodes("Shape");
+  assert_eq!(
+    shape.iter().map(|node| node.kind).collect::<Vec<_>>(),
+    [DocNodeKind::Class, DocNodeKind::Namespace]
+  );
+  assert!(DocNode::merge(shape[0], shape[1]).is_none());
+
+  let f = nodes("f");
+  assert_eq!(
+    f.iter().map(|node| node.kind).collect::<Vec<_>>(),
+    [DocNodeKind::Function, DocNodeKind::Namespace]
+  );
+  assert!(DocNode::merge(f[0], f[1]).is_none());
+}
+
+#[tokio::test]
+async fn declaration_merging_namespaces() {
+  let entries = parse_source(
+    r#"/** A namespace. */
+export namespace ns {
+  /** An interface. */
+  export interface I {
+    a: string;
+  }
+}
+/** The namespace again. */
+export namespace ns {
+  export interface I {
+    b: string;
+  }
+  /** A constant. */
+  export const c = 1;
+}
+"#,
+  )
+  .await;
+  assert_eq!(entries.len(), 1);
+  let elements = &entries[0].namespace_def.as_ref().unwrap().elements;
+  assert_eq!(
+    elements
+      .iter()
+      .map(|element| element.name.as_str())
+      .collect::<Vec<_>>(),
+    ["I", "c"]
+  );
+
+  // the interfaces of merged namespaces are merged, but the constants stay
+  // separate declarations
+  let merged = DocNode::merge(&entries[0], &entries[0]).unwrap();
+  let merged_elements = merged.namespace_def.unwrap().elements;
+  assert_eq!(
+    merged_elements
+      .iter()
+      .map(|element| element.name.as_str())
+      .collect::<Vec<_>>(),
+    ["I", "c", "c"]
+  );
+  assert_eq!(
+    merged_elements[0]
+      .interface_def
+      .as_ref()
+      .unwrap()
+      .properties
+      .len(),
+    2 * elements[0].interface_def.as_ref().unwrap().properties.len()
+  );
+}
+
+#[tokio::test]
+async fn doc_filter_tagged() {
+  let entries = parse_source(
+    r#"/**
+ * Opens a connection.
+ *
+ * @tag networking
+ * @tag database access
+ */
+export function connect() {}
+
+/**
+ * Fetches a resource.
+ *
+ * @tag networking
+ */
+export function get() {}
+
+/** Adds two numbers. */
+export function add() {}
+"#,
+  )
+  .await;
+  let tags = |name: &str| {
+    entries
+      .iter()
+      .find(|entry| entry.name == name)
+      .unwrap()
+      .tags()
+  };
+  assert_eq!(tags("connect"), ["networking", "database access"]);
+  assert_eq!(tags("get"), ["networking"]);
+  assert!(tags("add").is_empty());
+
+  let names = |filter: DocFilter| {
+    filter
+      .filter(&entries)
+      .iter()
+      .map(|node| node.name.clone())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(names(DocFilter::tagged("networking")), ["connect", "get"]);
+  assert_eq!(names(DocFilter::tagged("database access")), ["connect"]);
+  assert!(names(DocFilter::tagged("database")).is_empty());
+}
+
+#[tokio::test]
+async fn max_depth() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export type Tree<T> = { value: T; children: Forest<T> };
+export type Forest<T> = Tree<T>[];
+export type Deep = { a: { b: { c: { d: string } } } };
+"#,
+    )],
+  )
+  .await;
+  let parse = |max_depth: Option<usize>| {
+    DocParser::new(
+      &graph,
+      &analyzer,
+      DocParserOptions {
+        max_depth,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let type_alias = |entries: &[DocNode], name: &str| {
+    entries
+      .iter()
+      .find(|entry| entry.name == name)
+      .unwrap()
+      .type_alias_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+      .clone()
+  };
+
+  assert_eq!(DocParserOptions::default().max_depth, Some(10));
+  let entries = parse(DocParserOptions::default().max_depth);
+  assert_eq!(
+    type_alias(&entries, "Tree").to_string(),
+    "{ value: T; children: Forest<T>; }"
+  );
+  assert_eq!(type_alias(&entries, "Forest").to_string(), "Tree<T>[]");
+  assert_eq!(
+    type_alias(&entries, "Deep").to_string(),
+    "{ a: { b: { c: { d: string; }; }; }; }"
+  );
+
+  let entries = parse(Some(2));
+  assert_eq!(
+    type_alias(&entries, "Deep").to_string(),
+    "{ a: { b: ...; }; }"
+  );
+  let truncated = type_alias(&entries, "Deep")
+    .type_literal
+    .unwrap()
+    .properties[0]
+    .ts_type
+    .clone()
+    .unwrap()
+    .type_literal
+    .unwrap()
+    .properties[0]
+    .ts_type
+    .clone()
+    .unwrap();
+  assert_eq!(truncated.kind, Some(TsTypeDefKind::TypeRef));
+  let type_ref = truncated.type_ref.unwrap();
+  assert_eq!(type_ref.type_name, "...");
+  assert_eq!(type_ref.type_params, Some(vec![]));
+
+  let entries = parse(None);
+  assert_eq!(
+    type_alias(&entries, "Deep").to_string(),
+    "{ a: { b: { c: { d: string; }; }; }; }"
+  );
+}
+
+#[tokio::test]
+async fn class_member_counts() {
+  let entries = parse_source(
+    r#"class Base {
+  inherited(): void {}
+}
+
+export class Counter extends Base {
+  static zero = 0;
+  count = 0;
+  step: number;
+
+  constructor(step: number, readonly name: string) {
+    super();
+    this.step = step;
+  }
+
+  increment(): void {}
+  decrement(): void {}
+  reset(): void {}
+
+  static create(): Counter {
+    return new Counter(1, "counter");
+  }
+  static from(count: number): Counter {
+    return new Counter(count, "counter");
+  }
+}
+"#,
+  )
+  .await;
+  let class_def = entries
+    .iter()
+    .find(|entry| entry.name == "Counter")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(class_def.method_count(), 5);
+  assert_eq!(class_def.static_method_count(), 2);
+  assert_eq!(class_def.property_count(), 4);
+  assert_eq!(class_def.total_member_count(), 10);
+}
+
+#[tokio::test]
+async fn compute_overrides_qualified() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///base.ts",
+        None,
+        r#"export class Base {
+  start(): void {}
+  stop(): void {}
+}
+export namespace shapes {
+  export class Shape {
+    draw(): void {}
+  }
+}
+"#,
+      ),
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Base as Parent, shapes as geometry } from "./base.ts";
+
+export class Task extends Parent {
+  override start(): void {}
+}
+export namespace tasks {
+  export class Job extends Parent {
+    override stop(): void {}
+  }
+}
+export class Circle extends geometry.Shape {
+  override draw(): void {}
+}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, &analyzer, DocParserOptions::default()).unwrap();
+  let mut entries = parser
+    .parse(&ModuleSpecifier::parse("file:///base.ts").unwrap())
+    .unwrap();
+  entries.extend(parser.parse(&specifier).unwrap());
+  crate::compute_overrides(&mut entries);
+
+  let methods = |class_def: &ClassDef| {
+    class_def
+      .methods
+      .iter()
+      .map(|method| (method.name.clone(), method.overridden_by.clone()))
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(
+    methods(entries[0].class_def.as_ref().unwrap()),
+    [
+      ("start".to_string(), vec!["Task".to_string()]),
+      ("stop".to_string(), vec!["tasks.Job".to_string()]),
+    ]
+  );
+  let shape = &entries[1].namespace_def.as_ref().unwrap().elements[0];
+  assert_eq!(
+    methods(shape.class_def.as_ref().unwrap()),
+    [("draw".to_string(), vec!["Circle".to_string()])]
+  );
+}
+
+#[tokio::test]
+async fn doc_node_matches_overload() {
+  let entries = parse_source(
+    r#"export function a(x: string): string {}
+export function b(x: string | number, y?: boolean): string {}
+export function c(x: number, y?: boolean, ...rest: string[]): string {}
+export function d(x: boolean): string {}
+export function e(x: string, y: boolean): string {}
+export function f(x: string): number {}
+export function g<T>(x: string): string {}
+export function h(x): string {}
+"#,
+  )
+  .await;
+  let node =
+    |name: &str| entries.iter().find(|node| node.name == name).unwrap();
+  // the signatures, as overloads of the same function
+  let overload = |name: &str| DocNode {
+    name: "f".to_string(),
+    ..node(name).clone()
+  };
+  let matches = |a: &str, b: &str| {
+    let matches = overload(a).matches_overload(&overload(b));
+    assert_eq!(
+      matches,
+      overload(b).matches_overload(&overload(a)),
+      "{a} {b}"
+    );
+    matches
+  };
+
+  assert!(matches("a", "a"));
+  assert!(matches("a", "b"));
+  assert!(matches("b", "c"));
+  assert!(matches("a", "h"));
+  // the types are not compatible
+  assert!(!matches("a", "c"));
+  assert!(!matches("a", "d"));
+  // `y` is required
+  assert!(!matches("a", "e"));
+  // the return types differ
+  assert!(!matches("a", "f"));
+  // the type parameters differ
+  assert!(!matches("a", "g"));
+
+  // the names differ
+  assert!(node("a").matches_overload(node("a")));
+  assert!(!node("a").matches_overload(node("b")));
+  assert!(!node("a").matches_overload(node("h")));
+}
+
+#[tokio::test]
+async fn doc_node_build() {
+  let entries = parse_source(
+    r#"/** Adds two numbers. */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+"#,
+  )
+  .await;
+
+  let location = |line, col, byte_index| Location {
+    filename: "file:///mod.ts".to_string(),
+    line,
+    col,
+    byte_index,
+  };
+  let param = |name: &str| ParamDef {
+    pattern: ParamPatternDef::Identifier {
+      name: name.to_string(),
+      optional: false,
+    },
+    decorators: vec![],
+    ts_type: Some(TsTypeDef::keyword("number")),
+    default_value_text: None,
+  };
+  let function_def = FunctionDef {
+    def_name: None,
+    params: vec![param("a"), param("b")],
+    this_type: None,
+    return_type: Some(TsTypeDef::keyword("number")),
+    return_type_doc: None,
+    has_body: true,
+    body_span: Some(BodySpan { start: 75, end: 94 }),
+    is_overload: false,
+    is_async: false,
+    is_generator: false,
+    type_params: vec![],
+    decorators: vec![],
+  };
+  let node = DocNode {
+    kind: DocNodeKind::Function,
+    function_def: Some(function_def),
+    ..Default::default()
+  };
+
+  assert_eq!(
+    node.clone().build().unwrap_err(),
+    DocBuildError::MissingName
+  );
+  assert_eq!(
+    node.clone().with_name("add").build().unwrap_err(),
+    DocBuildError::MissingLocation
+  );
+  assert_eq!(
+    DocNode {
+      function_def: None,
+      ..node.clone()
+    }
+    .with_name("add")
+    .with_location(location(2, 0, 25))
+    .build()
+    .unwrap_err(),
+    DocBuildError::MissingDef(DocNodeKind::Function)
+  );
+
+  let built = node
+    .with_name("add")
+    .with_location(location(2, 0, 25))
+    .with_declaration_kind(DeclarationKind::Export)
+    .with_js_doc(JsDoc {
+      doc: Some("Adds two numbers.".to_string()),
+      tags: vec![],
+    })
+    .build()
+    .unwrap();
+  assert_eq!(
+    serde_json::to_value(&built).unwrap(),
+    serde_json::to_value(&entries[0]).unwrap()
+  );
+}
+
+#[tokio::test]
+async fn class_interface_impls_check() {
+  let entries = parse_source(
+    r#"export interface Reader {
+  readonly size: number;
+  label?: string;
+  read(p: Uint8Array): number;
+  read(p: Uint8Array, offset: number): number;
+  close(): void;
+  peek?(): number;
+}
+
+export interface Seeker {
+  seek(offset: number): number;
+}
+
+export class File implements Reader, Seeker {
+  get size(): number {
+    return 0;
+  }
+  read(p: Uint8Array, offset?: number): number {
+    return 0;
+  }
+  static close(): void {}
+  seek(offset: number): number {
+    return offset;
+  }
+}
+
+export class Stream implements Reader, Seeker {
+  size = 0;
+  read(p: Uint8Array): number {
+    return 0;
+  }
+  close(): void {}
+  seek(offset: number): number {
+    return offset;
+  }
+}
+"#,
+  )
+  .await;
+  let def = |name: &str| entries.iter().find(|node| node.name == name).unwrap();
+  let interfaces =
+    ["Reader", "Seeker"].map(|name| def(name).interface_def.clone().unwrap());
+  let file_def = def("File").class_def.as_ref().unwrap();
+  let stream_def = def("Stream").class_def.as_ref().unwrap();
+
+  assert_eq!(
+    file_def.interface_impls_check(&interfaces),
+    [UnimplementedMember {
+      interface_name: "Reader".to_string(),
+      name: "close",
+      kind: UnimplementedMemberKind::Method,
+    }]
+  );
+  assert!(stream_def.interface_impls_check(&interfaces).is_empty());
+
+  let interfaces = interfaces.iter().collect::<Vec<_>>();
+  assert_eq!(
+    file_def.check_implements(&interfaces),
+    ImplementsCheckResult {
+      fully_implemented: false,
+      missing_members: vec!["close".to_string()],
+    }
+  );
+  assert_eq!(
+    stream_def.check_implements(&interfaces),
+    ImplementsCheckResult {
+      fully_implemented: true,
+      missing_members: vec![],
+    }
+  );
+}
+
+#[test]
+fn doc_node_kind_str() {
+  assert_eq!(crate::DOC_NODE_KINDS.len(), 9);
+  for (label, kind) in crate::DOC_NODE_KINDS {
+    assert_eq!(kind.as_str(), *label);
+    assert_eq!(serde_json::to_value(kind).unwrap(), json!(label));
+    assert_eq!(
+      serde_json::from_value::<DocNodeKind>(json!(label)).unwrap(),
+      *kind
+    );
+  }
+
+  let node = DocNode::module_doc(
+    crate::Location {
+      filename: "file:///mod.ts".to_string(),
+      line: 1,
+      col: 0,
+      byte_index: 0,
+    },
+    Default::default(),
+  );
+  assert_eq!(node.kind_str(), "moduleDoc");
+  assert_eq!(
+    serde_json::to_value(&node).unwrap()["kind"],
+    json!(node.kind_str())
+  );
+}
+
+#[tokio::test]
+async fn function_def_to_ts_type() {
+  let entries = parse_source(
+    r#"export function parse(text: string): number {
+  return text.length;
+}
+export function log(this: Console, message) {}
+"#,
+  )
+  .await;
+  let function_type = |name: &str| {
+    crate::ts_type::TsTypeDef::from(
+      entries
+        .iter()
+        .find(|entry| entry.name == name)
+        .unwrap()
+        .function_def
+        .as_ref()
+        .unwrap(),
+    )
+  };
+
+  let ts_type = function_type("parse");
+  assert_eq!(ts_type.kind, Some(TsTypeDefKind::FnOrConstructor));
+  assert_eq!(ts_type.to_string(), "(text: string) => number");
+  let json = serde_json::to_value(&ts_type).unwrap();
+  assert_eq!(json["kind"], "fnOrConstructor");
+  assert_eq!(
+    serde_json::from_value::<crate::ts_type::TsTypeDef>(json).unwrap(),
+    ts_type
+  );
+
+  assert_eq!(
+    function_type("log").to_string(),
+    "(this: Console, message) => void"
+  );
+}
+
+#[tokio::test]
+async fn type_stubs() {
+  async fn parse(specifier: &str, source: &str) -> Vec<DocNode> {
+    let (graph, analyzer, specifier) =
+      setup(specifier, vec![(specifier, None, source)]).await;
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap()
+  }
+
+  let source = r#"/** Adds numbers. */
+export function add(a: number, b = 1, ...rest: number[]): number {
+  return a + b + rest.length;
+}
+export function first<T>({ items }: { items: T[] }): T | undefined {
+  return items[0];
+}
+export const VERSION = "1.0.0";
+export let count: number = 0;
+
+interface Options {
+  verbose?: boolean;
+}
+
+export abstract class Base<T> implements Iterable<T> {
+  static instances = 0;
+  protected abstract readonly id: string;
+  #secret = 1;
+  private cache: Map<string, T> = new Map();
+
+  constructor(public name: string, options?: Options) {}
+
+  get size(): number {
+    return 0;
+  }
+  set size(value: number) {}
+
+  abstract next(): T;
+  [Symbol.iterator](): Iterator<T> {
+    throw new Error();
+  }
+  private reset(): void {}
+}
+
+export interface Headers extends Iterable<string> {
+  "content-type"?: string;
+  (name: string): string;
+  [key: number]: string;
+  get(name: string): string | null;
+  get(name: string, fallback: string): string;
+}
+
+export type Handler<T = unknown> = (request: T) => Promise<void>;
+
+export enum Level {
+  Low = 1,
+  High,
+}
+
+export namespace Util {
+  export function noop(): void {}
+  export const empty = "";
+}
+
+export default class Client extends Base<string> {
+  next(): string {
+    return "";
+  }
+}
+"#;
+  let stubs =
+    crate::generate_type_stubs(&parse("file:///mod.ts", source).await);
+  assert_eq!(
+    stubs,
+    r#"export declare function add(a: number, b?: number, ...rest: number[]): number;
+export declare function first<T>({items}: { items: T[]; }): T | undefined;
+export declare const VERSION: "1.0.0";
+export declare let count: number;
+export declare abstract class Base<T> implements Iterable<T> {
+  constructor(name: string, options?: Options);
+  static instances: number;
+  protected abstract readonly id: string;
+  private cache;
+  public name: string;
+  private reset;
+  get size(): number;
+  set size(value: number);
+  abstract next(): T;
+  [Symbol.iterator](): Iterator<T>;
+}
+export declare interface Headers extends Iterable<string> {
+  (name: string): string;
+  "content-type"?: string;
+  get(name: string): string | null;
+  get(name: string, fallback: string): string;
+  [key: number]: string;
+}
+export declare type Handler<T = unknown> = (request: T) => Promise<void>;
+export declare enum Level {
+  Low = 1,
+  High,
+}
+export declare namespace Util {
+  export function noop(): void;
+  export const empty: "";
+}
+export default class Client extends Base<string> {
+  next(): string;
+}
+declare interface Options {
+  verbose?: boolean;
+}
+"#
+  );
+
+  // the stubs document the same API
+  let round_trip =
+    crate::generate_type_stubs(&parse("file:///mod.d.ts", &stubs).await);
+  assert_eq!(round_trip, stubs);
+}
+
+#[tokio::test]
+async fn doc_node_to_rust_doc_comment() {
+  let source = r#"/**
+ * Creates a connection to the server.
+ *
+ * Connections are pooled.
+ *
+ * @param host The host name.
+ * @param {number} port The port.
+ * @param options
+ * @returns The open connection.
+ * @throws {TypeError} if the port is not an integer.
+ * @example
+ * ```
+ * const conn = connect("localhost", 80);
+ * ```
+ * @example connect("localhost", 443, { tls: true });
+ * @deprecated Use `open` instead.
+ */
+export function connect(host: string, port: number, options?: object) {}
+
+export const undocumented = 1;
+"#;
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+
+  assert_eq!(
+    entries[0].to_rust_doc_comment(),
+    r#"/// Creates a connection to the server.
+///
+/// Connections are pooled.
+///
+/// # Arguments
+///
+/// * `host` - The host name.
+/// * `port` - The port.
+/// * `options`
+///
+/// # Returns
+///
+/// The open connection.
+///
+/// # Errors
+///
+/// Throws `TypeError` if the port is not an integer.
+///
+/// # Examples
+///
+/// ```ts
+/// const conn = connect("localhost", 80);
+/// ```
+///
+/// ```ts
+/// connect("localhost", 443, { tls: true });
+/// ```
+"#
+  );
+  assert_eq!(entries[1].to_rust_doc_comment(), "");
+}
+
+#[tokio::test]
+async fn api_report() {
+  let source = r#"/** @experimental */
+export function a(value: number): void {}
+export function b<T>(first: T, second: T): T {
+  return first;
+}
+/**
+ * Does nothing.
+ * @deprecated
+ */
+export function c(): void {}
+export function d(name: string): void;
+export function d(name: string, count: number): void;
+export function d(name: string, count?: number) {}
+/** @experimental */
+export function e(...args: unknown[]) {}
+/** A class. */
+export class F<T> {
+  value?: T;
+  protected reset(): void {}
+  private cache = new Map<string, T>();
+}
+/** @experimental */
+export class G {}
+export interface H {
+  value: string;
+}
+"#;
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+
+  assert_eq!(
+    crate::generate_api_report(&entries),
+    crate::ApiReport {
+      total_symbols: 8,
+      by_kind: HashMap::from([
+        (DocNodeKind::Function, 5),
+        (DocNodeKind::Class, 2),
+        (DocNodeKind::Interface, 1),
+      ]),
+      generics_count: 2,
+      deprecated_count: 1,
+      unstable_count: 3,
+      // a, b, c, the longest signature of d, and e
+      avg_params_per_function: 6.0 / 5.0,
+      // c and F, the others only have tags
+      documented_fraction: 2.0 / 8.0,
+      class_api_surfaces: HashMap::from([
+        ("F".to_string(), 2),
+        ("G".to_string(), 0)
+      ]),
+    }
+  );
+  assert_eq!(
+    crate::generate_api_report(&[]),
+    crate::ApiReport {
+      total_symbols: 0,
+      by_kind: HashMap::new(),
+      generics_count: 0,
+      deprecated_count: 0,
+      unstable_count: 0,
+      avg_params_per_function: 0.0,
+      documented_fraction: 0.0,
+      class_api_surfaces: HashMap::new(),
+    }
+  );
+}
+
+#[tokio::test]
+async fn namespace_parents() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export * as lib from "./lib.ts";
+/** Outer. */
+export namespace Outer {
+  /** Middle. */
+  export namespace Middle {
+    /** Inner. */
+    export namespace Inner {
+      /** A value. */
+      export const value = 1;
+    }
+  }
+}
+"#,
+      ),
+      (
+        "file:///lib.ts",
+        None,
+        r#"/** Util. */
+export namespace Util {
+  /** Nested. */
+  export namespace Nested {}
+}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  // the qualified names of the namespaces, depth first
+  fn qualified_names(doc_nodes: &[DocNode], names: &mut Vec<String>) {
+    for doc_node in doc_nodes {
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        names.push(namespace_def.qualified_name(&doc_node.name));
+        qualified_names(&namespace_def.elements, names);
+      }
+    }
+  }
+  let mut names = vec![];
+  qualified_names(&entries, &mut names);
+  assert_eq!(
+    names,
+    [
+      "lib",
+      "lib.Util",
+      "lib.Util.Nested",
+      "Outer",
+      "Outer.Middle",
+      "Outer.Middle.Inner"
+    ]
+  );
+
+  let outer = entries.iter().find(|node| node.name == "Outer").unwrap();
+  let outer_def = outer.namespace_def.as_ref().unwrap();
+  assert_eq!(outer_def.parent, None);
+  let middle_def = outer_def.elements[0].namespace_def.as_ref().unwrap();
+  assert_eq!(middle_def.parent.as_deref(), Some("Outer"));
+  let inner_def = middle_def.elements[0].namespace_def.as_ref().unwrap();
+  assert_eq!(inner_def.parent.as_deref(), Some("Outer.Middle"));
+}
+
+#[tokio::test]
+async fn export_all_reexport_source() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      ("file:///mod.ts", None, r#"export * from "./impl.ts";"#),
+      (
+        "file:///impl.ts",
+        None,
+        r#"/** A function. */
+export function a() {}
+/** A class. */
+export class B {}
+/** An interface. */
+export interface C {}
+/** A namespace. */
+export namespace D {
+  /** A value. */
+  export const e = 1;
+}
+/** Not exported. */
+function f() {}
+/** The default export is not re-exported by `export *`. */
+export default 1;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let mut names = entries
+    .iter()
+    .map(|node| (node.name.as_str(), node.re_export_source.as_deref()))
+    .collect::<Vec<_>>();
+  names.sort();
+  assert_eq!(
+    names,
+    [
+      ("B", Some("file:///impl.ts")),
+      ("C", Some("file:///impl.ts")),
+      ("D", Some("file:///impl.ts")),
+      ("a", Some("file:///impl.ts")),
+    ]
+  );
+  // the elements of a re-exported namespace are declared by the namespace
+  let namespace_def = entries
+    .iter()
+    .find(|node| node.name == "D")
+    .unwrap()
+    .namespace_def
+    .as_ref()
+    .unwrap();
+  assert_eq!(namespace_def.elements[0].re_export_source, None);
+}
+
+#[tokio::test]
+async fn isolated_declarations() {
+  async fn parse(source: &str) -> (Vec<DocNode>, Vec<(String, usize)>) {
+    let (graph, analyzer, specifier) =
+      setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+    let parser = DocParser::new(
+      &graph,
+      &analyzer,
+      DocParserOptions {
+        diagnostics: true,
+        isolated_declarations: true,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let entries = parser.parse(&specifier).unwrap();
+    let diagnostics = parser
+      .take_diagnostics()
+      .into_iter()
+      .map(|diagnostic| {
+        let code = deno_ast::diagnostics::Diagnostic::code(&diagnostic);
+        (code.to_string(), diagnostic.location.line)
+      })
+      .collect();
+    (entries, diagnostics)
+  }
+
+  let (entries, diagnostics) = parse(
+    r#"/** A version. */
+export const VERSION: string = "1.0.0";
+/** Runs. */
+export function run(times: number = 1): void {}
+/** A counter. */
+export class Counter {
+  /** The count. */
+  count: number = 0;
+}
+"#,
+  )
+  .await;
+  assert_eq!(diagnostics, []);
+  let variable_def = entries[0].variable_def.as_ref().unwrap();
+  assert_eq!(variable_def.ts_type, Some(TsTypeDef::keyword("string")));
+
+  let (entries, diagnostics) = parse(
+    r#"/** A version. */
+export const VERSION = "1.0.0";
+/** Runs. */
+export function run(times = 1) {}
+/** A counter. */
+export class Counter {
+  /** The count. */
+  count = 0;
+}
+"#,
+  )
+  .await;
+  assert_eq!(
+    diagnostics,
+    [
+      ("missing-explicit-type".to_string(), 2),
+      ("missing-return-type".to_string(), 4),
+      ("missing-explicit-type".to_string(), 8),
+    ]
+  );
+  // nothing is inferred
+  assert_eq!(entries[0].variable_def.as_ref().unwrap().ts_type, None);
+  let function_def = entries[1].function_def.as_ref().unwrap();
+  assert_eq!(function_def.return_type, None);
+  let ParamPatternDef::Assign { left, .. } = &function_def.params[0].pattern
+  else {
+    panic!("expected a parameter with a default value");
+  };
+  assert_eq!(left.ts_type, None);
+}
+
+#[tokio::test]
+async fn class_def_accessibility_counts() {
+  let source = r#"/** A class. */
+export class A {
+  constructor(value: string);
+  constructor(value: number);
+  constructor(value: string | number) {}
+  [key: string]: unknown;
+  a = 1;
+  public b = 2;
+  protected c = 3;
+  private d = 4;
+  e(): void {}
+  protected f(): void {}
+  private g(): void {}
+  private static h(): void {}
+}
+"#;
+  let (graph, analyzer, specifier) =
+    setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let class_def = entries[0].class_def.as_ref().unwrap();
+
+  // the constructor and its overloads, the index signature, a, b and e
+  assert_eq!(class_def.public_count(), 7);
+  assert_eq!(class_def.protected_count(), 2);
+  assert_eq!(class_def.private_count(), 3);
+  assert_eq!(
+    class_def.public_count()
+      + class_def.protected_count()
+      + class_def.private_count(),
+    class_def.total_member_count()
+  );
+}
+
+#[tokio::test]
+async fn variable_reexport_source() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { REMOTE } from "./remote.ts";
+/** Declared here. */
+export const LOCAL = 1;
+"#,
+      ),
+      (
+        "file:///remote.ts",
+        None,
+        r#"/** Declared in another module. */
+export const REMOTE = 2;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, &analyzer, DocParserOptions::default())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let local = entries.iter().find(|node| node.name == "LOCAL").unwrap();
+  assert_eq!(local.kind, DocNodeKind::Variable);
+  assert_eq!(local.re_export_source, None);
+  let remote = entries.iter().find(|node| node.name == "REMOTE").unwrap();
+  assert_eq!(remote.kind, DocNodeKind::Variable);
+  assert_eq!(
+    remote.re_export_source.as_deref(),
+    Some("file:///remote.ts")
+  );
+}
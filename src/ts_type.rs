@@ -2,44 +2,60 @@
 
 use crate::colors;
 use crate::display::display_computed;
+use crate::display::display_nested;
 use crate::display::display_optional;
 use crate::display::display_readonly;
+use crate::display::with_display_options;
+use crate::display::DisplayOptions;
 use crate::display::SliceDisplayer;
 use crate::interface::expr_to_name;
 use crate::params::param_to_param_def;
+use crate::params::params_to_param_defs;
 use crate::params::pat_to_param_def;
 use crate::params::prop_name_to_string;
+use crate::params::signature_params;
 use crate::params::ts_fn_param_to_param_def;
+use crate::params::ts_fn_params_to_param_defs;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::util::swc::is_false;
 use crate::ParamDef;
 
 use deno_ast::swc::ast::*;
+use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
 impl TsTypeDef {
-  fn ts_lit_type(parsed_source: &ParsedSource, other: &TsLitType) -> Self {
+  fn ts_lit_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsLitType,
+  ) -> Self {
     match &other.lit {
       TsLit::Number(num) => TsTypeDef::number_literal(num),
       TsLit::Str(str_) => TsTypeDef::string_literal(str_),
       TsLit::Tpl(tpl) => {
-        TsTypeDef::tpl_literal(parsed_source, &tpl.types, &tpl.quasis)
+        TsTypeDef::tpl_literal(parsed_source, options, &tpl.types, &tpl.quasis)
       }
       TsLit::Bool(bool_) => TsTypeDef::bool_literal(bool_),
       TsLit::BigInt(bigint_) => TsTypeDef::bigint_literal(bigint_),
     }
   }
 
-  fn ts_array_type(parsed_source: &ParsedSource, other: &TsArrayType) -> Self {
-    let ts_type_def = TsTypeDef::new(parsed_source, &other.elem_type);
+  fn ts_array_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsArrayType,
+  ) -> Self {
+    let ts_type_def = TsTypeDef::new(parsed_source, options, &other.elem_type);
 
     TsTypeDef {
       array: Some(Box::new(ts_type_def)),
@@ -48,11 +64,15 @@ impl TsTypeDef {
     }
   }
 
-  fn ts_tuple_type(parsed_source: &ParsedSource, other: &TsTupleType) -> Self {
+  fn ts_tuple_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsTupleType,
+  ) -> Self {
     let type_defs = other
       .elem_types
       .iter()
-      .map(|type_box| TsTypeDef::new(parsed_source, &type_box.ty))
+      .map(|type_box| TsTypeDef::new(parsed_source, options, &type_box.ty))
       .collect::<Vec<_>>();
 
     TsTypeDef {
@@ -64,6 +84,7 @@ impl TsTypeDef {
 
   fn ts_union_or_intersection_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsUnionOrIntersectionType,
   ) -> Self {
     use deno_ast::swc::ast::TsUnionOrIntersectionType::*;
@@ -73,7 +94,7 @@ impl TsTypeDef {
         let types_union = union_type
           .types
           .iter()
-          .map(|ts_type| TsTypeDef::new(parsed_source, ts_type))
+          .map(|ts_type| TsTypeDef::new(parsed_source, options, ts_type))
           .collect::<Vec<_>>();
 
         TsTypeDef {
@@ -86,7 +107,7 @@ impl TsTypeDef {
         let types_intersection = intersection_type
           .types
           .iter()
-          .map(|ts_type| TsTypeDef::new(parsed_source, ts_type))
+          .map(|ts_type| TsTypeDef::new(parsed_source, options, ts_type))
           .collect::<Vec<_>>();
 
         TsTypeDef {
@@ -125,9 +146,10 @@ impl TsTypeDef {
 
   fn ts_type_operator(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsTypeOperator,
   ) -> Self {
-    let ts_type = TsTypeDef::new(parsed_source, &other.type_ann);
+    let ts_type = TsTypeDef::new(parsed_source, options, &other.type_ann);
     let type_operator_def = TsTypeOperatorDef {
       operator: other.op.as_str().to_string(),
       ts_type,
@@ -142,9 +164,10 @@ impl TsTypeDef {
 
   fn ts_parenthesized_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsParenthesizedType,
   ) -> Self {
-    let ts_type = TsTypeDef::new(parsed_source, &other.type_ann);
+    let ts_type = TsTypeDef::new(parsed_source, options, &other.type_ann);
 
     TsTypeDef {
       parenthesized: Some(Box::new(ts_type)),
@@ -153,8 +176,12 @@ impl TsTypeDef {
     }
   }
 
-  fn ts_rest_type(parsed_source: &ParsedSource, other: &TsRestType) -> Self {
-    let ts_type = TsTypeDef::new(parsed_source, &other.type_ann);
+  fn ts_rest_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsRestType,
+  ) -> Self {
+    let ts_type = TsTypeDef::new(parsed_source, options, &other.type_ann);
 
     TsTypeDef {
       rest: Some(Box::new(ts_type)),
@@ -165,9 +192,10 @@ impl TsTypeDef {
 
   fn ts_optional_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsOptionalType,
   ) -> Self {
-    let ts_type = TsTypeDef::new(parsed_source, &other.type_ann);
+    let ts_type = TsTypeDef::new(parsed_source, options, &other.type_ann);
 
     TsTypeDef {
       optional: Some(Box::new(ts_type)),
@@ -187,6 +215,7 @@ impl TsTypeDef {
 
   fn ts_type_predicate(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsTypePredicate,
   ) -> Self {
     let pred = TsTypePredicateDef {
@@ -195,7 +224,7 @@ impl TsTypeDef {
       r#type: other
         .type_ann
         .as_ref()
-        .map(|t| Box::new(TsTypeDef::new(parsed_source, &t.type_ann))),
+        .map(|t| Box::new(TsTypeDef::new(parsed_source, options, &t.type_ann))),
     };
     TsTypeDef {
       repr: pred.to_string(),
@@ -221,14 +250,18 @@ impl TsTypeDef {
     }
   }
 
-  fn ts_type_ref(parsed_source: &ParsedSource, other: &TsTypeRef) -> Self {
+  fn ts_type_ref(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsTypeRef,
+  ) -> Self {
     let type_name = ts_entity_name_to_name(&other.type_name);
 
     let type_params = if let Some(type_params_inst) = &other.type_params {
       let ts_type_defs = type_params_inst
         .params
         .iter()
-        .map(|ts_type| TsTypeDef::new(parsed_source, ts_type))
+        .map(|ts_type| TsTypeDef::new(parsed_source, options, ts_type))
         .collect::<Vec<_>>();
 
       Some(ts_type_defs)
@@ -249,15 +282,16 @@ impl TsTypeDef {
 
   pub fn ts_expr_with_type_args(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsExprWithTypeArgs,
   ) -> Self {
-    let type_name = expr_to_name(&other.expr);
+    let type_name = entity_expr_to_name(&other.expr);
 
     let type_params = if let Some(type_params_inst) = &other.type_args {
       let ts_type_defs = type_params_inst
         .params
         .iter()
-        .map(|ts_type| TsTypeDef::new(parsed_source, ts_type))
+        .map(|ts_type| TsTypeDef::new(parsed_source, options, ts_type))
         .collect::<Vec<_>>();
 
       Some(ts_type_defs)
@@ -278,13 +312,22 @@ impl TsTypeDef {
 
   fn ts_indexed_access_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsIndexedAccessType,
   ) -> Self {
-    TsTypeDef::new(parsed_source, &other.obj_type);
+    TsTypeDef::new(parsed_source, options, &other.obj_type);
     let indexed_access_def = TsIndexedAccessDef {
       readonly: other.readonly,
-      obj_type: Box::new(TsTypeDef::new(parsed_source, &other.obj_type)),
-      index_type: Box::new(TsTypeDef::new(parsed_source, &other.index_type)),
+      obj_type: Box::new(TsTypeDef::new(
+        parsed_source,
+        options,
+        &other.obj_type,
+      )),
+      index_type: Box::new(TsTypeDef::new(
+        parsed_source,
+        options,
+        &other.index_type,
+      )),
     };
 
     TsTypeDef {
@@ -296,23 +339,25 @@ impl TsTypeDef {
 
   fn ts_mapped_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsMappedType,
   ) -> Self {
     let mapped_type_def = TsMappedTypeDef {
       readonly: other.readonly,
       type_param: Box::new(TsTypeParamDef::new(
         parsed_source,
+        options,
         &other.type_param,
       )),
       name_type: other
         .name_type
         .as_ref()
-        .map(|nt| Box::new(TsTypeDef::new(parsed_source, nt))),
+        .map(|nt| Box::new(TsTypeDef::new(parsed_source, options, nt))),
       optional: other.optional,
       ts_type: other
         .type_ann
         .as_ref()
-        .map(|a| Box::new(TsTypeDef::new(parsed_source, a))),
+        .map(|a| Box::new(TsTypeDef::new(parsed_source, options, a))),
     };
 
     TsTypeDef {
@@ -322,7 +367,11 @@ impl TsTypeDef {
     }
   }
 
-  fn ts_type_lit(parsed_source: &ParsedSource, other: &TsTypeLit) -> Self {
+  fn ts_type_lit(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsTypeLit,
+  ) -> Self {
     let mut methods = vec![];
     let mut properties = vec![];
     let mut call_signatures = vec![];
@@ -336,17 +385,19 @@ impl TsTypeDef {
           let mut params = vec![];
 
           for param in &ts_method_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let maybe_return_type = ts_method_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_method_sig.type_params.as_deref(),
           );
           let name = expr_to_name(&ts_method_sig.key);
@@ -365,7 +416,7 @@ impl TsTypeDef {
           let maybe_return_type = ts_getter_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let name = expr_to_name(&ts_getter_sig.key);
           let method_def = LiteralMethodDef {
@@ -384,6 +435,7 @@ impl TsTypeDef {
 
           let params = vec![ts_fn_param_to_param_def(
             parsed_source,
+            options,
             &ts_setter_sig.param,
           )];
 
@@ -404,16 +456,19 @@ impl TsTypeDef {
           let params = ts_prop_sig
             .params
             .iter()
-            .map(|param| ts_fn_param_to_param_def(parsed_source, param))
+            .map(|param| {
+              ts_fn_param_to_param_def(parsed_source, options, param)
+            })
             .collect();
 
           let ts_type = ts_prop_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_prop_sig.type_params.as_deref(),
           );
           let prop_def = LiteralPropertyDef {
@@ -430,17 +485,19 @@ impl TsTypeDef {
         TsCallSignatureDecl(ts_call_sig) => {
           let mut params = vec![];
           for param in &ts_call_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let ts_type = ts_call_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_call_sig.type_params.as_deref(),
           );
 
@@ -454,14 +511,15 @@ impl TsTypeDef {
         TsIndexSignature(ts_index_sig) => {
           let mut params = vec![];
           for param in &ts_index_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let ts_type = ts_index_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let index_sig_def = LiteralIndexSignatureDef {
             readonly: ts_index_sig.readonly,
@@ -473,19 +531,21 @@ impl TsTypeDef {
         TsConstructSignatureDecl(ts_construct_sig) => {
           let mut params = vec![];
           for param in &ts_construct_sig.params {
-            let param_def = ts_fn_param_to_param_def(parsed_source, param);
+            let param_def =
+              ts_fn_param_to_param_def(parsed_source, options, param);
             params.push(param_def);
           }
 
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             ts_construct_sig.type_params.as_deref(),
           );
 
           let maybe_return_type = ts_construct_sig
             .type_ann
             .as_ref()
-            .map(|rt| TsTypeDef::new(parsed_source, &rt.type_ann));
+            .map(|rt| TsTypeDef::new(parsed_source, options, &rt.type_ann));
 
           let construct_sig_def = LiteralMethodDef {
             name: "new".to_string(),
@@ -518,16 +578,30 @@ impl TsTypeDef {
 
   fn ts_conditional_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsConditionalType,
   ) -> Self {
     let conditional_type_def = TsConditionalDef {
-      check_type: Box::new(TsTypeDef::new(parsed_source, &other.check_type)),
+      check_type: Box::new(TsTypeDef::new(
+        parsed_source,
+        options,
+        &other.check_type,
+      )),
       extends_type: Box::new(TsTypeDef::new(
         parsed_source,
+        options,
         &other.extends_type,
       )),
-      true_type: Box::new(TsTypeDef::new(parsed_source, &other.true_type)),
-      false_type: Box::new(TsTypeDef::new(parsed_source, &other.false_type)),
+      true_type: Box::new(TsTypeDef::new(
+        parsed_source,
+        options,
+        &other.true_type,
+      )),
+      false_type: Box::new(TsTypeDef::new(
+        parsed_source,
+        options,
+        &other.false_type,
+      )),
     };
 
     TsTypeDef {
@@ -537,10 +611,15 @@ impl TsTypeDef {
     }
   }
 
-  fn ts_infer_type(parsed_source: &ParsedSource, other: &TsInferType) -> Self {
+  fn ts_infer_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsInferType,
+  ) -> Self {
     let infer = TsInferDef {
       type_param: Box::new(TsTypeParamDef::new(
         parsed_source,
+        options,
         &other.type_param,
       )),
     };
@@ -554,13 +633,14 @@ impl TsTypeDef {
 
   fn ts_import_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsImportType,
   ) -> Self {
     let type_params = if let Some(type_params_inst) = &other.type_args {
       let ts_type_defs = type_params_inst
         .params
         .iter()
-        .map(|param| TsTypeDef::new(parsed_source, param))
+        .map(|param| TsTypeDef::new(parsed_source, options, param))
         .collect::<Vec<_>>();
 
       Some(ts_type_defs)
@@ -583,28 +663,34 @@ impl TsTypeDef {
 
   fn ts_fn_or_constructor_type(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     other: &TsFnOrConstructorType,
   ) -> Self {
     use deno_ast::swc::ast::TsFnOrConstructorType::*;
 
     let fn_def = match other {
       TsFnType(ts_fn_type) => {
-        let mut params = vec![];
-
-        for param in &ts_fn_type.params {
-          let param_def = ts_fn_param_to_param_def(parsed_source, param);
-          params.push(param_def);
-        }
+        let (this_type, params) = ts_fn_params_to_param_defs(
+          parsed_source,
+          options,
+          &ts_fn_type.params,
+        );
 
         let type_params = maybe_type_param_decl_to_type_param_defs(
           parsed_source,
+          options,
           ts_fn_type.type_params.as_deref(),
         );
 
         TsFnOrConstructorDef {
           constructor: false,
-          ts_type: TsTypeDef::new(parsed_source, &ts_fn_type.type_ann.type_ann),
+          ts_type: TsTypeDef::new(
+            parsed_source,
+            options,
+            &ts_fn_type.type_ann.type_ann,
+          ),
           params,
+          this_type,
           type_params,
         }
       }
@@ -612,18 +698,25 @@ impl TsTypeDef {
         let mut params = vec![];
 
         for param in &ctor_type.params {
-          let param_def = ts_fn_param_to_param_def(parsed_source, param);
+          let param_def =
+            ts_fn_param_to_param_def(parsed_source, options, param);
           params.push(param_def);
         }
 
         let type_params = maybe_type_param_decl_to_type_param_defs(
           parsed_source,
+          options,
           ctor_type.type_params.as_deref(),
         );
         TsFnOrConstructorDef {
           constructor: true,
-          ts_type: TsTypeDef::new(parsed_source, &ctor_type.type_ann.type_ann),
+          ts_type: TsTypeDef::new(
+            parsed_source,
+            options,
+            &ctor_type.type_ann.type_ann,
+          ),
           params,
+          this_type: None,
           type_params,
         }
       }
@@ -636,7 +729,40 @@ impl TsTypeDef {
     }
   }
 
-  pub fn new(parsed_source: &ParsedSource, other: &TsType) -> Self {
+  pub fn new(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsType,
+  ) -> Self {
+    if options
+      .max_depth
+      .is_some_and(|max_depth| options.depth >= max_depth)
+    {
+      return TsTypeDef::truncated();
+    }
+
+    TsTypeDef::from_ts_type(parsed_source, options.nested(), other)
+  }
+
+  /// The `...` type reference that stands for the types nested deeper than
+  /// the maximum depth, see [`crate::DocParserOptions::max_depth`].
+  pub fn truncated() -> Self {
+    TsTypeDef {
+      repr: "...".to_string(),
+      kind: Some(TsTypeDefKind::TypeRef),
+      type_ref: Some(TsTypeRefDef {
+        type_params: Some(vec![]),
+        type_name: "...".to_string(),
+      }),
+      ..Default::default()
+    }
+  }
+
+  fn from_ts_type(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    other: &TsType,
+  ) -> Self {
     use deno_ast::swc::ast::TsType::*;
 
     match other {
@@ -647,59 +773,101 @@ impl TsTypeDef {
         TsTypeDef::ts_this_type(parsed_source, this_type)
       }
       TsFnOrConstructorType(fn_or_con_type) => {
-        TsTypeDef::ts_fn_or_constructor_type(parsed_source, fn_or_con_type)
+        TsTypeDef::ts_fn_or_constructor_type(
+          parsed_source,
+          options,
+          fn_or_con_type,
+        )
+      }
+      TsTypeRef(type_ref) => {
+        TsTypeDef::ts_type_ref(parsed_source, options, type_ref)
       }
-      TsTypeRef(type_ref) => TsTypeDef::ts_type_ref(parsed_source, type_ref),
       TsTypeQuery(type_query) => {
         TsTypeDef::ts_type_query(parsed_source, type_query)
       }
       TsTypeLit(type_literal) => {
-        TsTypeDef::ts_type_lit(parsed_source, type_literal)
+        TsTypeDef::ts_type_lit(parsed_source, options, type_literal)
       }
       TsArrayType(array_type) => {
-        TsTypeDef::ts_array_type(parsed_source, array_type)
+        TsTypeDef::ts_array_type(parsed_source, options, array_type)
       }
       TsTupleType(tuple_type) => {
-        TsTypeDef::ts_tuple_type(parsed_source, tuple_type)
+        TsTypeDef::ts_tuple_type(parsed_source, options, tuple_type)
       }
       TsOptionalType(optional_type) => {
-        TsTypeDef::ts_optional_type(parsed_source, optional_type)
+        TsTypeDef::ts_optional_type(parsed_source, options, optional_type)
       }
       TsRestType(rest_type) => {
-        TsTypeDef::ts_rest_type(parsed_source, rest_type)
+        TsTypeDef::ts_rest_type(parsed_source, options, rest_type)
       }
       TsUnionOrIntersectionType(union_or_inter) => {
-        TsTypeDef::ts_union_or_intersection_type(parsed_source, union_or_inter)
+        TsTypeDef::ts_union_or_intersection_type(
+          parsed_source,
+          options,
+          union_or_inter,
+        )
       }
       TsConditionalType(conditional_type) => {
-        TsTypeDef::ts_conditional_type(parsed_source, conditional_type)
+        TsTypeDef::ts_conditional_type(parsed_source, options, conditional_type)
       }
       TsInferType(infer_type) => {
-        TsTypeDef::ts_infer_type(parsed_source, infer_type)
+        TsTypeDef::ts_infer_type(parsed_source, options, infer_type)
       }
       TsParenthesizedType(paren_type) => {
-        TsTypeDef::ts_parenthesized_type(parsed_source, paren_type)
+        TsTypeDef::ts_parenthesized_type(parsed_source, options, paren_type)
       }
       TsTypeOperator(type_op_type) => {
-        TsTypeDef::ts_type_operator(parsed_source, type_op_type)
+        TsTypeDef::ts_type_operator(parsed_source, options, type_op_type)
       }
       TsIndexedAccessType(indexed_access_type) => {
-        TsTypeDef::ts_indexed_access_type(parsed_source, indexed_access_type)
+        TsTypeDef::ts_indexed_access_type(
+          parsed_source,
+          options,
+          indexed_access_type,
+        )
       }
       TsMappedType(mapped_type) => {
-        TsTypeDef::ts_mapped_type(parsed_source, mapped_type)
+        TsTypeDef::ts_mapped_type(parsed_source, options, mapped_type)
       }
-      TsLitType(lit_type) => TsTypeDef::ts_lit_type(parsed_source, lit_type),
-      TsTypePredicate(type_predicate_type) => {
-        TsTypeDef::ts_type_predicate(parsed_source, type_predicate_type)
+      TsLitType(lit_type) => {
+        TsTypeDef::ts_lit_type(parsed_source, options, lit_type)
       }
+      TsTypePredicate(type_predicate_type) => TsTypeDef::ts_type_predicate(
+        parsed_source,
+        options,
+        type_predicate_type,
+      ),
       TsImportType(import_type) => {
-        TsTypeDef::ts_import_type(parsed_source, import_type)
+        TsTypeDef::ts_import_type(parsed_source, options, import_type)
       }
     }
   }
 }
 
+/// The options that apply to the types being built, which are passed down
+/// to the nested ones.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TsTypeDefOptions {
+  /// The number of nested type levels to build, see
+  /// [`DocParserOptions::max_depth`](crate::DocParserOptions::max_depth).
+  pub max_depth: Option<usize>,
+  /// The number of types the one being built is nested in.
+  pub depth: usize,
+  /// Whether the types of declarations without a type annotation are left
+  /// out instead of being inferred from their initializer or body, see
+  /// [`DocParserOptions::isolated_declarations`](crate::DocParserOptions::isolated_declarations).
+  pub isolated_declarations: bool,
+}
+
+impl TsTypeDefOptions {
+  fn nested(self) -> Self {
+    Self {
+      depth: self.depth + 1,
+      ..self
+    }
+  }
+}
+
 fn ts_entity_name_to_name(entity_name: &TsEntityName) -> String {
   use deno_ast::swc::ast::TsEntityName::*;
 
@@ -713,6 +881,19 @@ fn ts_entity_name_to_name(entity_name: &TsEntityName) -> String {
   }
 }
 
+/// The name of an entity referenced by an expression, like the `a.B` of
+/// `implements a.B`.
+fn entity_expr_to_name(expr: &Expr) -> String {
+  match expr {
+    Expr::Member(MemberExpr {
+      obj,
+      prop: MemberProp::Ident(prop),
+      ..
+    }) => format!("{}.{}", entity_expr_to_name(obj), prop.sym),
+    _ => expr_to_name(expr),
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypeRefDef {
@@ -720,6 +901,157 @@ pub struct TsTypeRefDef {
   pub type_name: String,
 }
 
+/// Declares the utility types and the known global types, which include them,
+/// from a single list.
+macro_rules! known_types {
+  (utility: [$($utility:literal,)*] other: [$($other:literal,)*]) => {
+    /// The utility types which are built into TypeScript, either as
+    /// intrinsic types, eg. `NoInfer` or `Uppercase`, or as types of its
+    /// standard library.
+    const UTILITY_TYPES: &[&str] = &[$($utility,)*];
+
+    /// The global types that are assumed to be known by
+    /// [`broken_links_report`](crate::broken_links_report), from the
+    /// TypeScript standard library and the web platform. References to
+    /// members of the `Deno` namespace are always known.
+    #[cfg_attr(not(feature = "rust"), allow(dead_code))]
+    pub const DEFAULT_KNOWN_TYPES: &[&str] = &[$($utility,)* $($other,)*];
+  };
+}
+
+known_types! {
+  utility: [
+    "Awaited",
+    "Capitalize",
+    "ConstructorParameters",
+    "Exclude",
+    "Extract",
+    "InstanceType",
+    "Lowercase",
+    "NoInfer",
+    "NonNullable",
+    "Omit",
+    "OmitThisParameter",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "Readonly",
+    "Record",
+    "Required",
+    "ReturnType",
+    "ThisParameterType",
+    "ThisType",
+    "Uncapitalize",
+    "Uppercase",
+  ]
+  other: [
+    // TypeScript
+    "AbortController",
+    "AbortSignal",
+    "Array",
+    "ArrayBuffer",
+    "ArrayBufferLike",
+    "ArrayBufferView",
+    "ArrayLike",
+    "AsyncGenerator",
+    "AsyncIterable",
+    "AsyncIterableIterator",
+    "AsyncIterator",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Generator",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Iterable",
+    "IterableIterator",
+    "Iterator",
+    "IteratorResult",
+    "JSON",
+    "Map",
+    "Math",
+    "Number",
+    "Object",
+    "Promise",
+    "PromiseLike",
+    "PropertyDescriptor",
+    "PropertyKey",
+    "RangeError",
+    "ReadonlyArray",
+    "ReadonlyMap",
+    "ReadonlySet",
+    "ReferenceError",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TemplateStringsArray",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+    // web platform
+    "Blob",
+    "BodyInit",
+    "BufferSource",
+    "CustomEvent",
+    "Document",
+    "DOMException",
+    "Element",
+    "Event",
+    "EventListener",
+    "EventListenerOrEventListenerObject",
+    "EventTarget",
+    "File",
+    "FormData",
+    "Headers",
+    "HeadersInit",
+    "HTMLElement",
+    "MessageEvent",
+    "Node",
+    "ReadableStream",
+    "Request",
+    "RequestInfo",
+    "RequestInit",
+    "Response",
+    "ResponseInit",
+    "TextDecoder",
+    "TextEncoder",
+    "TransformStream",
+    "URL",
+    "URLSearchParams",
+    "WebSocket",
+    "Window",
+    "Worker",
+    "WritableStream",
+  ]
+}
+
+impl TsTypeRefDef {
+  /// Whether the type is one of the utility types of TypeScript, eg.
+  /// `Partial<T>` or `NoInfer<T>`, assuming it isn't shadowed by a type of
+  /// the same name.
+  pub fn is_utility_type(&self) -> bool {
+    UTILITY_TYPES.contains(&self.type_name.as_str())
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum LiteralDefKind {
@@ -761,23 +1093,40 @@ pub struct TsFnOrConstructorDef {
   pub constructor: bool,
   pub ts_type: TsTypeDef,
   pub params: Vec<ParamDef>,
+  /// The type of the `this` pseudo-parameter, eg. `MyClass` for
+  /// `(this: MyClass) => void`, which is not included in `params`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub this_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
 }
 
 impl TsFnOrConstructorDef {
-  fn arrow_expr(parsed_source: &ParsedSource, expr: &ArrowExpr) -> Self {
+  /// The parameters as written in the signature, ie. preceded by the `this`
+  /// pseudo-parameter when the function type declares its type.
+  pub fn signature_params(&self) -> Vec<ParamDef> {
+    signature_params(self.this_type.as_ref(), &self.params)
+  }
+
+  fn arrow_expr(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    expr: &ArrowExpr,
+  ) -> Self {
     let params = expr
       .params
       .iter()
-      .map(|pat| pat_to_param_def(parsed_source, pat))
+      .map(|pat| pat_to_param_def(parsed_source, options, pat))
       .collect();
     let ts_type = expr
       .return_type
       .as_deref()
-      .map(|return_type| TsTypeDef::new(parsed_source, &return_type.type_ann))
+      .map(|return_type| {
+        TsTypeDef::new(parsed_source, options, &return_type.type_ann)
+      })
       .unwrap_or_else(|| TsTypeDef::keyword("unknown"));
     let type_params = maybe_type_param_decl_to_type_param_defs(
       parsed_source,
+      options,
       expr.type_params.as_deref(),
     );
 
@@ -785,25 +1134,29 @@ impl TsFnOrConstructorDef {
       constructor: false,
       ts_type,
       params,
+      this_type: None,
       type_params,
     }
   }
 
-  fn fn_expr(parsed_source: &ParsedSource, expr: &FnExpr) -> Self {
-    let params = expr
-      .function
-      .params
-      .iter()
-      .map(|param| pat_to_param_def(parsed_source, &param.pat))
-      .collect();
+  fn fn_expr(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    expr: &FnExpr,
+  ) -> Self {
+    let (this_type, params) =
+      params_to_param_defs(parsed_source, options, &expr.function.params);
     let ts_type = expr
       .function
       .return_type
       .as_deref()
-      .map(|return_type| TsTypeDef::new(parsed_source, &return_type.type_ann))
+      .map(|return_type| {
+        TsTypeDef::new(parsed_source, options, &return_type.type_ann)
+      })
       .unwrap_or_else(|| TsTypeDef::keyword("unknown"));
     let type_params = maybe_type_param_decl_to_type_param_defs(
       parsed_source,
+      options,
       expr.function.type_params.as_deref(),
     );
 
@@ -811,6 +1164,7 @@ impl TsFnOrConstructorDef {
       constructor: false,
       ts_type,
       params,
+      this_type,
       type_params,
     }
   }
@@ -919,7 +1273,13 @@ pub struct LiteralPropertyDef {
 
 impl Display for LiteralPropertyDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-    write!(f, "{}", self.name)?;
+    write!(
+      f,
+      "{}{}{}",
+      display_readonly(self.readonly),
+      self.name,
+      display_optional(self.optional)
+    )?;
     if let Some(ts_type) = &self.ts_type {
       write!(f, ": {}", ts_type)?;
     }
@@ -976,6 +1336,17 @@ pub struct TsTypeLiteralDef {
   pub index_signatures: Vec<LiteralIndexSignatureDef>,
 }
 
+impl TsTypeLiteralDef {
+  /// Whether this is the `{}` type, which is any value but `null` and
+  /// `undefined`.
+  pub fn is_empty(&self) -> bool {
+    self.methods.is_empty()
+      && self.properties.is_empty()
+      && self.call_signatures.is_empty()
+      && self.index_signatures.is_empty()
+  }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum TsTypeDefKind {
@@ -1206,12 +1577,13 @@ impl TsTypeDef {
 
   pub fn tpl_literal(
     parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
     types: &[Box<TsType>],
     quasis: &[TplElement],
   ) -> Self {
     let mut ts_types: Vec<(SourceRange, Self, String)> = Vec::new();
     for ts_type in types {
-      let t = TsTypeDef::new(parsed_source, ts_type);
+      let t = TsTypeDef::new(parsed_source, options, ts_type);
       let repr = format!("${{{}}}", t);
       ts_types.push((get_range_from_type(ts_type), t, repr))
     }
@@ -1336,25 +1708,601 @@ impl TsTypeDef {
       ..Default::default()
     }
   }
+
+  /// Applies the simplifications enabled in `opts`, returning the type
+  /// unchanged if none of them apply.
+  pub fn simplify(&self, opts: SimplifyOptions) -> TsTypeDef {
+    if opts.collapse_intersections {
+      if let Some(collapsed) = self.collapse_object_intersection() {
+        return collapsed;
+      }
+    }
+    self.clone()
+  }
+
+  /// Merges an intersection of object type literals, eg.
+  /// `{ a: number } & { b: string }`, into a single type literal. Returns
+  /// `None` if the type is not an intersection or any of its members is not
+  /// an object type literal.
+  ///
+  /// A property that is declared with different types in several members is
+  /// typed as `never`, and it is only optional if it is optional in every
+  /// member.
+  pub fn collapse_object_intersection(&self) -> Option<TsTypeDef> {
+    if self.kind != Some(TsTypeDefKind::Intersection) {
+      return None;
+    }
+
+    let mut merged = TsTypeLiteralDef::default();
+    for ts_type in self.intersection.as_ref()? {
+      let mut ts_type = ts_type;
+      while ts_type.kind == Some(TsTypeDefKind::Parenthesized) {
+        ts_type = ts_type.parenthesized.as_ref()?;
+      }
+      if ts_type.kind != Some(TsTypeDefKind::TypeLiteral) {
+        return None;
+      }
+      let type_literal = ts_type.type_literal.as_ref()?;
+
+      merged.methods.extend(type_literal.methods.iter().cloned());
+      merged
+        .call_signatures
+        .extend(type_literal.call_signatures.iter().cloned());
+      merged
+        .index_signatures
+        .extend(type_literal.index_signatures.iter().cloned());
+      for property in &type_literal.properties {
+        let existing = merged.properties.iter_mut().find(|existing| {
+          existing.name == property.name
+            && existing.computed == property.computed
+        });
+        if let Some(existing) = existing {
+          existing.optional &= property.optional;
+          existing.readonly &= property.readonly;
+          if existing.ts_type != property.ts_type {
+            existing.ts_type = Some(TsTypeDef::keyword("never"));
+          }
+        } else {
+          merged.properties.push(property.clone());
+        }
+      }
+    }
+
+    Some(TsTypeDef {
+      repr: "".to_string(),
+      kind: Some(TsTypeDefKind::TypeLiteral),
+      type_literal: Some(merged),
+      ..Default::default()
+    })
+  }
+
+  /// A heuristic of how hard the type is to read, eg. `1` for `string`, `2`
+  /// for a reference without type arguments, growing with every member of
+  /// unions, object literals and generics, with conditional and mapped types
+  /// weighing the most.
+  pub fn complexity_score(&self) -> u32 {
+    let Some(kind) = &self.kind else {
+      return 1;
+    };
+    match kind {
+      TsTypeDefKind::Keyword
+      | TsTypeDefKind::Literal
+      | TsTypeDefKind::This
+      | TsTypeDefKind::TypeQuery => 1,
+      TsTypeDefKind::TypeRef => {
+        let type_ref = self.type_ref.as_ref().unwrap();
+        2 + types_complexity_score(type_ref.type_params.iter().flatten())
+      }
+      TsTypeDefKind::ImportType => {
+        let import_type = self.import_type.as_ref().unwrap();
+        2 + types_complexity_score(import_type.type_params.iter().flatten())
+      }
+      TsTypeDefKind::Union => {
+        1 + types_complexity_score(self.union.as_ref().unwrap())
+      }
+      TsTypeDefKind::Intersection => {
+        1 + types_complexity_score(self.intersection.as_ref().unwrap())
+      }
+      TsTypeDefKind::Tuple => {
+        1 + types_complexity_score(self.tuple.as_ref().unwrap())
+      }
+      TsTypeDefKind::Array => {
+        1 + self.array.as_ref().unwrap().complexity_score()
+      }
+      TsTypeDefKind::TypeOperator => {
+        1 + self
+          .type_operator
+          .as_ref()
+          .unwrap()
+          .ts_type
+          .complexity_score()
+      }
+      TsTypeDefKind::Parenthesized => {
+        self.parenthesized.as_ref().unwrap().complexity_score()
+      }
+      TsTypeDefKind::Rest => self.rest.as_ref().unwrap().complexity_score(),
+      TsTypeDefKind::Optional => {
+        self.optional.as_ref().unwrap().complexity_score()
+      }
+      TsTypeDefKind::FnOrConstructor => {
+        let fn_or_constructor = self.fn_or_constructor.as_ref().unwrap();
+        2 + params_complexity_score(&fn_or_constructor.signature_params())
+          + fn_or_constructor.ts_type.complexity_score()
+          + type_params_complexity_score(&fn_or_constructor.type_params)
+      }
+      TsTypeDefKind::Conditional => {
+        let conditional = self.conditional_type.as_ref().unwrap();
+        3 + conditional.check_type.complexity_score()
+          + conditional.extends_type.complexity_score()
+          + conditional.true_type.complexity_score()
+          + conditional.false_type.complexity_score()
+      }
+      TsTypeDefKind::Infer => {
+        let infer = self.infer.as_ref().unwrap();
+        1 + type_params_complexity_score(std::slice::from_ref(
+          &infer.type_param,
+        ))
+      }
+      TsTypeDefKind::IndexedAccess => {
+        let indexed_access = self.indexed_access.as_ref().unwrap();
+        1 + indexed_access.obj_type.complexity_score()
+          + indexed_access.index_type.complexity_score()
+      }
+      TsTypeDefKind::Mapped => {
+        let mapped_type = self.mapped_type.as_ref().unwrap();
+        3 + type_params_complexity_score(std::slice::from_ref(
+          &mapped_type.type_param,
+        )) + types_complexity_score(mapped_type.name_type.as_deref())
+          + types_complexity_score(mapped_type.ts_type.as_deref())
+      }
+      TsTypeDefKind::TypeLiteral => {
+        let type_literal = self.type_literal.as_ref().unwrap();
+        let methods = type_literal.methods.iter().map(|method| {
+          1 + params_complexity_score(&method.params)
+            + types_complexity_score(&method.return_type)
+            + type_params_complexity_score(&method.type_params)
+        });
+        let properties = type_literal.properties.iter().map(|property| {
+          1 + params_complexity_score(&property.params)
+            + types_complexity_score(&property.ts_type)
+            + type_params_complexity_score(&property.type_params)
+        });
+        let call_signatures =
+          type_literal.call_signatures.iter().map(|call_signature| {
+            1 + params_complexity_score(&call_signature.params)
+              + types_complexity_score(&call_signature.ts_type)
+              + type_params_complexity_score(&call_signature.type_params)
+          });
+        let index_signatures =
+          type_literal.index_signatures.iter().map(|index_signature| {
+            1 + params_complexity_score(&index_signature.params)
+              + types_complexity_score(&index_signature.ts_type)
+          });
+        1 + methods
+          .chain(properties)
+          .chain(call_signatures)
+          .chain(index_signatures)
+          .sum::<u32>()
+      }
+      TsTypeDefKind::TypePredicate => {
+        let type_predicate = self.type_predicate.as_ref().unwrap();
+        1 + types_complexity_score(type_predicate.r#type.as_deref())
+      }
+    }
+  }
+
+  /// Whether the type is one of the primitive keywords, eg. `string`, `null`
+  /// or `unknown`, or a string, number, bigint or boolean literal.
+  pub fn is_primitive(&self) -> bool {
+    match self.kind {
+      Some(TsTypeDefKind::Keyword) => matches!(
+        self.keyword.as_deref(),
+        Some(
+          "string"
+            | "number"
+            | "boolean"
+            | "bigint"
+            | "symbol"
+            | "null"
+            | "undefined"
+            | "void"
+            | "never"
+            | "any"
+            | "unknown"
+            | "object"
+        )
+      ),
+      Some(TsTypeDefKind::Literal) => !matches!(
+        self.literal.as_ref().map(|literal| &literal.kind),
+        None | Some(LiteralDefKind::Template)
+      ),
+      _ => false,
+    }
+  }
+
+  /// The type without its `null` and `undefined` members, like the
+  /// `NonNullable` utility type, eg. `string` for `string | undefined`.
+  pub(crate) fn non_nullable(self) -> TsTypeDef {
+    let Some(union) = &self.union else {
+      return self;
+    };
+    let mut members = union
+      .iter()
+      .filter(|member| {
+        !matches!(member.keyword.as_deref(), Some("null" | "undefined"))
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+    match members.len() {
+      0 => self,
+      1 => members.remove(0),
+      _ => TsTypeDef {
+        union: Some(members),
+        ..self
+      },
+    }
+  }
+
+  /// The names of all the types referenced at any depth of the type, in the
+  /// order they first appear, eg. `["Map", "Promise", "MyType"]` for
+  /// `Map<string, Promise<{ x: MyType }>>`. Keywords and literals are not
+  /// included, while references to type parameters are.
+  pub fn referenced_type_names(&self) -> Vec<String> {
+    let mut names = vec![];
+    self.visit(&mut |ts_type| {
+      if let Some(type_ref) = &ts_type.type_ref {
+        if !names.contains(&type_ref.type_name) {
+          names.push(type_ref.type_name.clone());
+        }
+      }
+    });
+    names
+  }
+
+  /// The names of the type parameters declared within the type, eg. `["U"]`
+  /// for `T extends Array<infer U> ? U : T`, or `["T"]` for `<T>(x: T) => T`.
+  pub(crate) fn inner_type_param_names(&self) -> Vec<String> {
+    let mut names = vec![];
+    self.visit(&mut |ts_type| {
+      let mut type_params: Vec<&TsTypeParamDef> = vec![];
+      if let Some(infer) = &ts_type.infer {
+        type_params.push(&infer.type_param);
+      }
+      if let Some(mapped_type) = &ts_type.mapped_type {
+        type_params.push(&mapped_type.type_param);
+      }
+      if let Some(fn_or_constructor) = &ts_type.fn_or_constructor {
+        type_params.extend(&fn_or_constructor.type_params);
+      }
+      if let Some(type_literal) = &ts_type.type_literal {
+        for method in &type_literal.methods {
+          type_params.extend(&method.type_params);
+        }
+        for property in &type_literal.properties {
+          type_params.extend(&property.type_params);
+        }
+        for call_signature in &type_literal.call_signatures {
+          type_params.extend(&call_signature.type_params);
+        }
+      }
+      names.extend(type_params.into_iter().map(|param| param.name.clone()));
+    });
+    names
+  }
+
+  /// Replaces the references to the type parameters that are keys of
+  /// `substitutions` with the corresponding types, at any depth, eg.
+  /// `Promise<string>` for `Promise<T>` with `T` substituted by `string`.
+  /// Type parameters that are declared within the type shadow the
+  /// substituted ones where they are in scope only, eg. `T` is left
+  /// unchanged in `<T>(x: T) => T` but not in `T | (<T>(x: T) => T)`.
+  pub fn substitute_type_params(
+    &self,
+    substitutions: &HashMap<String, TsTypeDef>,
+  ) -> TsTypeDef {
+    let mut ts_type = self.clone();
+    ts_type.visit_in_scope_mut(&mut vec![], &mut |ts_type, scope| {
+      let Some(type_ref) = &ts_type.type_ref else {
+        return;
+      };
+      if type_ref.type_params.is_some() || scope.contains(&type_ref.type_name) {
+        return;
+      }
+      if let Some(substitution) = substitutions.get(&type_ref.type_name) {
+        *ts_type = substitution.clone();
+      }
+    });
+    ts_type
+  }
+}
+
+/// Calls `$visit` with the type parameters in scope on every type nested in
+/// `$ts_type`, which is borrowed mutably when followed by `mut`. The type
+/// parameters of function types and of the members of type literals are in
+/// scope in their signatures, the ones of mapped types in their value and
+/// name types, and the ones declared by `infer` in the extends clause and the
+/// true branch of their conditional type.
+macro_rules! visit_nested_types {
+  (
+    $ts_type:ident, $visit:ident, $scope:ident, $visitor:ident $(, $mut:tt)?
+  ) => {
+    macro_rules! visit_types {
+      ($types:expr) => {
+        for ts_type in & $($mut)? $types {
+          ts_type.$visit($scope, $visitor);
+        }
+      };
+    }
+    macro_rules! visit_params {
+      ($params:expr) => {
+        for param in & $($mut)? $params {
+          visit_types!(param.ts_type);
+        }
+      };
+    }
+    macro_rules! visit_type_params {
+      ($type_params:expr) => {
+        for type_param in & $($mut)? $type_params {
+          visit_types!(type_param.constraint);
+          visit_types!(type_param.default);
+        }
+      };
+    }
+    macro_rules! in_scope {
+      ($type_params:expr, $visit_scoped:block) => {
+        let len = $scope.len();
+        $scope.extend($type_params.iter().map(|param| param.name.clone()));
+        $visit_scoped
+        $scope.truncate(len);
+      };
+    }
+
+    match $ts_type.kind {
+      None
+      | Some(TsTypeDefKind::Keyword)
+      | Some(TsTypeDefKind::Literal)
+      | Some(TsTypeDefKind::This)
+      | Some(TsTypeDefKind::TypeQuery) => {}
+      Some(TsTypeDefKind::TypeRef) => {
+        for type_ref in & $($mut)? $ts_type.type_ref {
+          for type_params in & $($mut)? type_ref.type_params {
+            visit_types!(*type_params);
+          }
+        }
+      }
+      Some(TsTypeDefKind::ImportType) => {
+        for import_type in & $($mut)? $ts_type.import_type {
+          for type_params in & $($mut)? import_type.type_params {
+            visit_types!(*type_params);
+          }
+        }
+      }
+      Some(TsTypeDefKind::Union) => {
+        for union in & $($mut)? $ts_type.union {
+          visit_types!(*union);
+        }
+      }
+      Some(TsTypeDefKind::Intersection) => {
+        for intersection in & $($mut)? $ts_type.intersection {
+          visit_types!(*intersection);
+        }
+      }
+      Some(TsTypeDefKind::Tuple) => {
+        for tuple in & $($mut)? $ts_type.tuple {
+          visit_types!(*tuple);
+        }
+      }
+      Some(TsTypeDefKind::Array) => visit_types!($ts_type.array),
+      Some(TsTypeDefKind::TypeOperator) => {
+        for type_operator in & $($mut)? $ts_type.type_operator {
+          type_operator.ts_type.$visit($scope, $visitor);
+        }
+      }
+      Some(TsTypeDefKind::Parenthesized) => {
+        visit_types!($ts_type.parenthesized)
+      }
+      Some(TsTypeDefKind::Rest) => visit_types!($ts_type.rest),
+      Some(TsTypeDefKind::Optional) => visit_types!($ts_type.optional),
+      Some(TsTypeDefKind::FnOrConstructor) => {
+        for fn_or_constructor in & $($mut)? $ts_type.fn_or_constructor {
+          in_scope!(fn_or_constructor.type_params, {
+            visit_type_params!(fn_or_constructor.type_params);
+            visit_params!(fn_or_constructor.params);
+            visit_types!(fn_or_constructor.this_type);
+            fn_or_constructor.ts_type.$visit($scope, $visitor);
+          });
+        }
+      }
+      Some(TsTypeDefKind::Conditional) => {
+        for conditional in & $($mut)? $ts_type.conditional_type {
+          conditional.check_type.$visit($scope, $visitor);
+          let inferred = conditional.extends_type.inferred_type_params();
+          in_scope!(inferred, {
+            conditional.extends_type.$visit($scope, $visitor);
+            conditional.true_type.$visit($scope, $visitor);
+          });
+          conditional.false_type.$visit($scope, $visitor);
+        }
+      }
+      Some(TsTypeDefKind::Infer) => {
+        for infer in & $($mut)? $ts_type.infer {
+          visit_types!(infer.type_param.constraint);
+          visit_types!(infer.type_param.default);
+        }
+      }
+      Some(TsTypeDefKind::IndexedAccess) => {
+        for indexed_access in & $($mut)? $ts_type.indexed_access {
+          indexed_access.obj_type.$visit($scope, $visitor);
+          indexed_access.index_type.$visit($scope, $visitor);
+        }
+      }
+      Some(TsTypeDefKind::Mapped) => {
+        for mapped_type in & $($mut)? $ts_type.mapped_type {
+          visit_types!(mapped_type.type_param.constraint);
+          visit_types!(mapped_type.type_param.default);
+          in_scope!(std::slice::from_ref(&*mapped_type.type_param), {
+            visit_types!(mapped_type.name_type);
+            visit_types!(mapped_type.ts_type);
+          });
+        }
+      }
+      Some(TsTypeDefKind::TypeLiteral) => {
+        for type_literal in & $($mut)? $ts_type.type_literal {
+          for method in & $($mut)? type_literal.methods {
+            in_scope!(method.type_params, {
+              visit_type_params!(method.type_params);
+              visit_params!(method.params);
+              visit_types!(method.return_type);
+            });
+          }
+          for property in & $($mut)? type_literal.properties {
+            in_scope!(property.type_params, {
+              visit_type_params!(property.type_params);
+              visit_params!(property.params);
+              visit_types!(property.ts_type);
+            });
+          }
+          for call_signature in & $($mut)? type_literal.call_signatures {
+            in_scope!(call_signature.type_params, {
+              visit_type_params!(call_signature.type_params);
+              visit_params!(call_signature.params);
+              visit_types!(call_signature.ts_type);
+            });
+          }
+          for index_signature in & $($mut)? type_literal.index_signatures {
+            visit_params!(index_signature.params);
+            visit_types!(index_signature.ts_type);
+          }
+        }
+      }
+      Some(TsTypeDefKind::TypePredicate) => {
+        for type_predicate in & $($mut)? $ts_type.type_predicate {
+          visit_types!(type_predicate.r#type);
+        }
+      }
+    }
+  };
+}
+
+impl TsTypeDef {
+  /// Calls the visitor with the type and then with every type nested in it.
+  fn visit(&self, visitor: &mut dyn FnMut(&TsTypeDef)) {
+    self.visit_in_scope(&mut vec![], &mut |ts_type, _| visitor(ts_type));
+  }
+
+  /// Calls the visitor with the type and then with every type nested in it,
+  /// along with the names of the type parameters in scope.
+  fn visit_in_scope(
+    &self,
+    scope: &mut Vec<String>,
+    visitor: &mut dyn FnMut(&TsTypeDef, &[String]),
+  ) {
+    visitor(self, scope);
+    visit_nested_types!(self, visit_in_scope, scope, visitor);
+  }
+
+  /// Calls the visitor with every type nested in the type and then with the
+  /// type itself, so that the visitor may replace it, along with the names
+  /// of the type parameters in scope.
+  fn visit_in_scope_mut(
+    &mut self,
+    scope: &mut Vec<String>,
+    visitor: &mut dyn FnMut(&mut TsTypeDef, &[String]),
+  ) {
+    visit_nested_types!(self, visit_in_scope_mut, scope, visitor, mut);
+    visitor(self, scope);
+  }
+
+  /// The type parameters declared by the `infer` types within the type, eg.
+  /// `U` for `Array<infer U>`.
+  fn inferred_type_params(&self) -> Vec<TsTypeParamDef> {
+    let mut type_params = vec![];
+    self.visit(&mut |ts_type| {
+      if let Some(infer) = &ts_type.infer {
+        type_params.push((*infer.type_param).clone());
+      }
+    });
+    type_params
+  }
+}
+
+fn types_complexity_score<'a>(
+  types: impl IntoIterator<Item = &'a TsTypeDef>,
+) -> u32 {
+  types.into_iter().map(TsTypeDef::complexity_score).sum()
+}
+
+fn params_complexity_score(params: &[ParamDef]) -> u32 {
+  types_complexity_score(
+    params.iter().filter_map(|param| param.ts_type.as_ref()),
+  )
+}
+
+fn type_params_complexity_score(type_params: &[TsTypeParamDef]) -> u32 {
+  type_params
+    .iter()
+    .map(|type_param| {
+      types_complexity_score(&type_param.constraint)
+        + types_complexity_score(&type_param.default)
+    })
+    .sum()
+}
+
+/// Options for [`TsTypeDef::simplify`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SimplifyOptions {
+  /// Merge intersections of object type literals into a single type literal,
+  /// see [`TsTypeDef::collapse_object_intersection`].
+  pub collapse_intersections: bool,
+}
+
+/// Parses a type written in JSDoc, eg. the one of a `@proxy` tag.
+pub fn parse_ts_type(text: &str) -> Option<TsTypeDef> {
+  let parsed_source = deno_ast::parse_module(deno_ast::ParseParams {
+    specifier: ModuleSpecifier::parse("file:///jsdoc_type.ts").unwrap(),
+    text_info: deno_ast::SourceTextInfo::from_string(format!(
+      "type T = {text};"
+    )),
+    media_type: deno_ast::MediaType::TypeScript,
+    capture_tokens: false,
+    scope_analysis: false,
+    maybe_syntax: None,
+  })
+  .ok()?;
+  let Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(type_alias)))) =
+    parsed_source.module().body.first()
+  else {
+    return None;
+  };
+  // reject text that isn't a single type, like `string; const x = 1`
+  if parsed_source.module().body.len() != 1 {
+    return None;
+  }
+  Some(TsTypeDef::new(
+    &parsed_source,
+    TsTypeDefOptions::default(),
+    &type_alias.type_ann,
+  ))
 }
 
 pub fn infer_ts_type_from_expr(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   expr: &Expr,
   is_const: bool,
 ) -> Option<TsTypeDef> {
   match expr {
     Expr::Array(arr_lit) => {
       // e.g.) const n = ["a", 1];
-      infer_ts_type_from_arr_lit(parsed_source, arr_lit, false)
+      infer_ts_type_from_arr_lit(parsed_source, options, arr_lit, false)
     }
     Expr::Arrow(expr) => {
       // e.g.) const f = (a: string): void => {};
-      infer_ts_type_from_arrow_expr(parsed_source, expr)
+      infer_ts_type_from_arrow_expr(parsed_source, options, expr)
     }
     Expr::Fn(expr) => {
       // e.g.) const f = function a(a:string): void {};
-      infer_ts_type_from_fn_expr(parsed_source, expr)
+      infer_ts_type_from_fn_expr(parsed_source, options, expr)
     }
     Expr::Lit(lit) => {
       // e.g.) const n = 100;
@@ -1362,15 +2310,20 @@ pub fn infer_ts_type_from_expr(
     }
     Expr::New(expr) => {
       // e.g.) const d = new Date()
-      infer_ts_type_from_new_expr(parsed_source, expr)
+      infer_ts_type_from_new_expr(parsed_source, options, expr)
     }
     Expr::Tpl(tpl) => {
       // e.g.) const s = `hello`;
-      Some(infer_ts_type_from_tpl(parsed_source, tpl, is_const))
+      Some(infer_ts_type_from_tpl(
+        parsed_source,
+        options,
+        tpl,
+        is_const,
+      ))
     }
     Expr::TsConstAssertion(assertion) => {
       // e.g.) const s = [] as const;
-      infer_ts_type_from_const_assertion(parsed_source, assertion)
+      infer_ts_type_from_const_assertion(parsed_source, options, assertion)
     }
     Expr::Call(expr) => {
       // e.g.) const value = Number(123);
@@ -1378,7 +2331,32 @@ pub fn infer_ts_type_from_expr(
     }
     Expr::Object(obj) => {
       // e.g.) const value = {foo: "bar"};
-      infer_ts_type_from_obj(parsed_source, obj)
+      infer_ts_type_from_obj(parsed_source, options, obj)
+    }
+    Expr::TsSatisfies(satisfies) => {
+      // e.g.) const value = { foo: "bar" } satisfies Foo;
+      // the type of the expression is kept by `satisfies`, so only fall back
+      // to the constraint when it can't be inferred
+      infer_ts_type_from_expr(parsed_source, options, &satisfies.expr, is_const)
+        .or_else(|| {
+          Some(TsTypeDef::new(parsed_source, options, &satisfies.type_ann))
+        })
+    }
+    Expr::TsAs(as_expr) => {
+      // e.g.) const value = input as Foo;
+      Some(TsTypeDef::new(parsed_source, options, &as_expr.type_ann))
+    }
+    Expr::TsTypeAssertion(assertion) => {
+      // e.g.) const value = <Foo>input;
+      Some(TsTypeDef::new(parsed_source, options, &assertion.type_ann))
+    }
+    Expr::TsNonNull(non_null) => {
+      // e.g.) const value = (input as Foo | undefined)!;
+      infer_ts_type_from_expr(parsed_source, options, &non_null.expr, is_const)
+        .map(TsTypeDef::non_nullable)
+    }
+    Expr::Paren(paren) => {
+      infer_ts_type_from_expr(parsed_source, options, &paren.expr, is_const)
     }
     _ => None,
   }
@@ -1386,11 +2364,20 @@ pub fn infer_ts_type_from_expr(
 
 pub fn infer_simple_ts_type_from_var_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   decl: &VarDeclarator,
   is_const: bool,
 ) -> Option<TsTypeDef> {
+  if options.isolated_declarations {
+    return None;
+  }
   if let Some(init_expr) = &decl.init {
-    infer_ts_type_from_expr(parsed_source, init_expr.as_ref(), is_const)
+    infer_ts_type_from_expr(
+      parsed_source,
+      options,
+      init_expr.as_ref(),
+      is_const,
+    )
   } else {
     None
   }
@@ -1398,6 +2385,7 @@ pub fn infer_simple_ts_type_from_var_decl(
 
 fn infer_ts_type_from_arr_lit(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   arr_lit: &ArrayLit,
   is_const: bool,
 ) -> Option<TsTypeDef> {
@@ -1405,7 +2393,7 @@ fn infer_ts_type_from_arr_lit(
   for expr in arr_lit.elems.iter().flatten() {
     if expr.spread.is_none() {
       if let Some(ts_type) =
-        infer_ts_type_from_expr(parsed_source, &expr.expr, is_const)
+        infer_ts_type_from_expr(parsed_source, options, &expr.expr, is_const)
       {
         if !defs.contains(&ts_type) {
           defs.push(ts_type);
@@ -1454,12 +2442,14 @@ fn infer_ts_type_from_arr_lit(
 
 fn infer_ts_type_from_arrow_expr(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   expr: &ArrowExpr,
 ) -> Option<TsTypeDef> {
   Some(TsTypeDef {
     kind: Some(TsTypeDefKind::FnOrConstructor),
     fn_or_constructor: Some(Box::new(TsFnOrConstructorDef::arrow_expr(
       parsed_source,
+      options,
       expr,
     ))),
     ..Default::default()
@@ -1468,12 +2458,14 @@ fn infer_ts_type_from_arrow_expr(
 
 fn infer_ts_type_from_fn_expr(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   expr: &FnExpr,
 ) -> Option<TsTypeDef> {
   Some(TsTypeDef {
     kind: Some(TsTypeDefKind::FnOrConstructor),
     fn_or_constructor: Some(Box::new(TsFnOrConstructorDef::fn_expr(
       parsed_source,
+      options,
       expr,
     ))),
     ..Default::default()
@@ -1482,15 +2474,56 @@ fn infer_ts_type_from_fn_expr(
 
 fn infer_ts_type_from_const_assertion(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   assertion: &TsConstAssertion,
 ) -> Option<TsTypeDef> {
   match &*assertion.expr {
     Expr::Array(arr_lit) => {
       // e.g.) const n = ["a", 1] as const;
-      infer_ts_type_from_arr_lit(parsed_source, arr_lit, true)
+      infer_ts_type_from_const_arr_lit(parsed_source, options, arr_lit).or_else(
+        || infer_ts_type_from_arr_lit(parsed_source, options, arr_lit, true),
+      )
+    }
+    _ => infer_ts_type_from_expr(parsed_source, options, &assertion.expr, true),
+  }
+}
+
+/// The readonly tuple type of an array literal with a const assertion, eg.
+/// `readonly ["a", 1]` for `["a", 1] as const`, or `None` if it contains
+/// spread elements, holes or elements whose type can not be inferred.
+fn infer_ts_type_from_const_arr_lit(
+  parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
+  arr_lit: &ArrayLit,
+) -> Option<TsTypeDef> {
+  let mut elements = Vec::new();
+  for element in &arr_lit.elems {
+    let element = element.as_ref()?;
+    if element.spread.is_some() {
+      return None;
     }
-    _ => infer_ts_type_from_expr(parsed_source, &assertion.expr, true),
+    let ts_type = match &*element.expr {
+      Expr::Array(arr_lit) => {
+        infer_ts_type_from_const_arr_lit(parsed_source, options, arr_lit)?
+      }
+      expr => infer_ts_type_from_expr(parsed_source, options, expr, true)?,
+    };
+    elements.push(ts_type);
   }
+
+  let tuple = TsTypeDef {
+    kind: Some(TsTypeDefKind::Tuple),
+    tuple: Some(elements),
+    ..Default::default()
+  };
+  Some(TsTypeDef {
+    kind: Some(TsTypeDefKind::TypeOperator),
+    type_operator: Some(Box::new(TsTypeOperatorDef {
+      operator: "readonly".to_string(),
+      ts_type: tuple,
+    })),
+    ..Default::default()
+  })
 }
 
 fn infer_ts_type_from_lit(lit: &Lit, is_const: bool) -> Option<TsTypeDef> {
@@ -1530,6 +2563,7 @@ fn infer_ts_type_from_lit(lit: &Lit, is_const: bool) -> Option<TsTypeDef> {
 
 fn infer_ts_type_from_new_expr(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   new_expr: &NewExpr,
 ) -> Option<TsTypeDef> {
   match new_expr.callee.as_ref() {
@@ -1538,7 +2572,11 @@ fn infer_ts_type_from_new_expr(
       kind: Some(TsTypeDefKind::TypeRef),
       type_ref: Some(TsTypeRefDef {
         type_params: new_expr.type_args.as_ref().map(|init| {
-          maybe_type_param_instantiation_to_type_defs(parsed_source, Some(init))
+          maybe_type_param_instantiation_to_type_defs(
+            parsed_source,
+            options,
+            Some(init),
+          )
         }),
         type_name: ident.sym.to_string(),
       }),
@@ -1574,9 +2612,11 @@ fn infer_ts_type_from_call_expr(call_expr: &CallExpr) -> Option<TsTypeDef> {
 
 fn infer_ts_type_from_obj(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   obj: &ObjectLit,
 ) -> Option<TsTypeDef> {
-  let (methods, properties) = infer_ts_type_from_obj_inner(parsed_source, obj);
+  let (methods, properties) =
+    infer_ts_type_from_obj_inner(parsed_source, options, obj);
   if methods.is_empty() && properties.is_empty() {
     None
   } else {
@@ -1586,6 +2626,7 @@ fn infer_ts_type_from_obj(
 
 fn infer_ts_type_from_obj_inner(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   obj: &ObjectLit,
 ) -> (Vec<LiteralMethodDef>, Vec<LiteralPropertyDef>) {
   let mut methods = Vec::<LiteralMethodDef>::new();
@@ -1613,7 +2654,12 @@ fn infer_ts_type_from_obj_inner(
             readonly: false,
             computed: kv.key.is_computed(),
             optional: false,
-            ts_type: infer_ts_type_from_expr(parsed_source, &kv.value, false),
+            ts_type: infer_ts_type_from_expr(
+              parsed_source,
+              options,
+              &kv.value,
+              false,
+            ),
             type_params: vec![],
           });
         }
@@ -1621,10 +2667,9 @@ fn infer_ts_type_from_obj_inner(
         Prop::Getter(getter) => {
           let name = prop_name_to_string(parsed_source, &getter.key);
           let computed = getter.key.is_computed();
-          let return_type = getter
-            .type_ann
-            .as_ref()
-            .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+          let return_type = getter.type_ann.as_ref().map(|type_ann| {
+            TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
+          });
           methods.push(LiteralMethodDef {
             name,
             kind: MethodKind::Getter,
@@ -1638,7 +2683,8 @@ fn infer_ts_type_from_obj_inner(
         Prop::Setter(setter) => {
           let name = prop_name_to_string(parsed_source, &setter.key);
           let computed = setter.key.is_computed();
-          let param = pat_to_param_def(parsed_source, setter.param.as_ref());
+          let param =
+            pat_to_param_def(parsed_source, options, setter.param.as_ref());
           methods.push(LiteralMethodDef {
             name,
             kind: MethodKind::Setter,
@@ -1656,14 +2702,15 @@ fn infer_ts_type_from_obj_inner(
             .function
             .params
             .iter()
-            .map(|param| param_to_param_def(parsed_source, param))
+            .map(|param| param_to_param_def(parsed_source, options, param))
             .collect();
           let return_type =
             method.function.return_type.as_ref().map(|type_ann| {
-              TsTypeDef::new(parsed_source, &type_ann.type_ann)
+              TsTypeDef::new(parsed_source, options, &type_ann.type_ann)
             });
           let type_params = maybe_type_param_decl_to_type_param_defs(
             parsed_source,
+            options,
             method.function.type_params.as_deref(),
           );
           methods.push(LiteralMethodDef {
@@ -1680,7 +2727,7 @@ fn infer_ts_type_from_obj_inner(
       PropOrSpread::Spread(spread) => {
         if let Expr::Object(obj) = &*spread.expr {
           let (spread_methods, spread_properties) =
-            infer_ts_type_from_obj_inner(parsed_source, obj);
+            infer_ts_type_from_obj_inner(parsed_source, options, obj);
           methods.extend(spread_methods);
           properties.extend(spread_properties);
         }
@@ -1692,6 +2739,7 @@ fn infer_ts_type_from_obj_inner(
 
 fn infer_ts_type_from_tpl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   tpl: &Tpl,
   is_const: bool,
 ) -> TsTypeDef {
@@ -1699,14 +2747,20 @@ fn infer_ts_type_from_tpl(
   // ts_type or can be trivially inferred, it should be passed to the
   // tp_literal
   if tpl.quasis.len() == 1 && is_const {
-    TsTypeDef::tpl_literal(parsed_source, &[], &tpl.quasis)
+    TsTypeDef::tpl_literal(parsed_source, options, &[], &tpl.quasis)
   } else {
     TsTypeDef::string_with_repr("string")
   }
 }
 
-impl Display for TsTypeDef {
-  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+impl TsTypeDef {
+  /// Renders the type as plain TypeScript source text, without any terminal
+  /// colors, eliding nested types beyond `opts.max_depth` as `...`.
+  pub fn to_ts_string(&self, opts: DisplayOptions) -> String {
+    with_display_options(opts, || self.to_string())
+  }
+
+  fn fmt_type(&self, f: &mut Formatter<'_>) -> FmtResult {
     if self.kind.is_none() {
       return write!(f, "{}", colors::red("[UNSUPPORTED]"));
     }
@@ -1755,13 +2809,28 @@ impl Display for TsTypeDef {
         let fn_or_constructor = self.fn_or_constructor.as_ref().unwrap();
         write!(
           f,
-          "{}({}) => {}",
+          "{}",
           colors::magenta(if fn_or_constructor.constructor {
             "new "
           } else {
             ""
-          }),
-          SliceDisplayer::new(&fn_or_constructor.params, ", ", false),
+          })
+        )?;
+        if !fn_or_constructor.type_params.is_empty() {
+          write!(
+            f,
+            "<{}>",
+            SliceDisplayer::new(&fn_or_constructor.type_params, ", ", false)
+          )?;
+        }
+        write!(
+          f,
+          "({}) => {}",
+          SliceDisplayer::new(
+            &fn_or_constructor.signature_params(),
+            ", ",
+            false
+          ),
           &fn_or_constructor.ts_type,
         )
       }
@@ -1884,6 +2953,9 @@ impl Display for TsTypeDef {
       }
       TsTypeDefKind::TypeLiteral => {
         let type_literal = self.type_literal.as_ref().unwrap();
+        if type_literal.is_empty() {
+          return write!(f, "{{}}");
+        }
         write!(
           f,
           "{{ {}{}{}{}}}",
@@ -1903,7 +2975,11 @@ impl Display for TsTypeDef {
       TsTypeDefKind::TypeRef => {
         let type_ref = self.type_ref.as_ref().unwrap();
         write!(f, "{}", colors::intense_blue(&type_ref.type_name))?;
-        if let Some(type_params) = &type_ref.type_params {
+        if let Some(type_params) = type_ref
+          .type_params
+          .as_ref()
+          .filter(|type_params| !type_params.is_empty())
+        {
           write!(f, "<{}>", SliceDisplayer::new(type_params, ", ", false))?;
         }
         Ok(())
@@ -1920,15 +2996,22 @@ impl Display for TsTypeDef {
   }
 }
 
+impl Display for TsTypeDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    display_nested(f, |f| self.fmt_type(f))
+  }
+}
+
 pub fn maybe_type_param_instantiation_to_type_defs(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   maybe_type_param_instantiation: Option<&TsTypeParamInstantiation>,
 ) -> Vec<TsTypeDef> {
   if let Some(type_param_instantiation) = maybe_type_param_instantiation {
     type_param_instantiation
       .params
       .iter()
-      .map(|type_param| TsTypeDef::new(parsed_source, type_param))
+      .map(|type_param| TsTypeDef::new(parsed_source, options, type_param))
       .collect::<Vec<TsTypeDef>>()
   } else {
     vec![]
@@ -1,5 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::ts_type::parse_ts_type;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefOptions;
+use crate::util::swc::is_false;
 use deno_ast::swc::ast::TsTypeParam;
 use deno_ast::swc::ast::TsTypeParamDecl;
 use deno_ast::ParsedSource;
@@ -11,15 +16,42 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 pub struct TsTypeParamDef {
   pub name: String,
 
+  /// Set for `const` type parameters, eg. `<const T>`, for which literal
+  /// types are inferred.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_const: bool,
+
+  /// Set for type parameters with an `in` variance annotation, eg. `<in T>`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_in: bool,
+
+  /// Set for type parameters with an `out` variance annotation, eg. `<out T>`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub is_out: bool,
+
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub constraint: Option<TsTypeDef>,
 
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub default: Option<TsTypeDef>,
+
+  /// The description of the type parameter from the `@template` tag of the
+  /// JSDoc of its declaration.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub description: Option<String>,
 }
 
 impl Display for TsTypeParamDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if self.is_const {
+      write!(f, "const ")?;
+    }
+    if self.is_in {
+      write!(f, "in ")?;
+    }
+    if self.is_out {
+      write!(f, "out ")?;
+    }
     write!(f, "{}", self.name)?;
     if let Some(constraint) = &self.constraint {
       write!(f, " extends {}", constraint)?;
@@ -32,36 +64,80 @@ impl Display for TsTypeParamDef {
 }
 
 impl TsTypeParamDef {
-  pub fn new(parsed_source: &ParsedSource, param: &TsTypeParam) -> Self {
+  pub fn new(
+    parsed_source: &ParsedSource,
+    options: TsTypeDefOptions,
+    param: &TsTypeParam,
+  ) -> Self {
     let name = param.name.sym.to_string();
     let constraint = param
       .constraint
       .as_ref()
-      .map(|constraint| TsTypeDef::new(parsed_source, constraint));
+      .map(|constraint| TsTypeDef::new(parsed_source, options, constraint));
     let default = param
       .default
       .as_ref()
-      .map(|default| TsTypeDef::new(parsed_source, default));
+      .map(|default| TsTypeDef::new(parsed_source, options, default));
 
     TsTypeParamDef {
       name,
+      is_const: param.is_const,
+      is_in: param.is_in,
+      is_out: param.is_out,
       constraint,
       default,
+      description: None,
     }
   }
 }
 
 pub fn maybe_type_param_decl_to_type_param_defs(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   maybe_type_param_decl: Option<&TsTypeParamDecl>,
 ) -> Vec<TsTypeParamDef> {
   if let Some(type_params_decl) = maybe_type_param_decl {
     type_params_decl
       .params
       .iter()
-      .map(|type_param| TsTypeParamDef::new(parsed_source, type_param))
+      .map(|type_param| TsTypeParamDef::new(parsed_source, options, type_param))
       .collect::<Vec<_>>()
   } else {
     vec![]
   }
 }
+
+/// Sets the description of the type parameters from the `@template` tags of
+/// the JSDoc. A declaration without type parameters, as in JavaScript, gets
+/// the ones declared by the tags.
+pub(crate) fn apply_template_tags(
+  js_doc: &JsDoc,
+  type_params: &mut Vec<TsTypeParamDef>,
+) {
+  let declares_type_params = !type_params.is_empty();
+  for tag in &js_doc.tags {
+    let JsDocTag::Template {
+      name,
+      type_ref,
+      doc,
+    } = tag
+    else {
+      continue;
+    };
+    if let Some(type_param) = type_params.iter_mut().find(|p| p.name == *name) {
+      if type_param.description.is_none() {
+        type_param.description = doc.clone();
+      }
+    } else if !declares_type_params {
+      type_params.push(TsTypeParamDef {
+        name: name.clone(),
+        is_const: false,
+        is_in: false,
+        is_out: false,
+        constraint: type_ref.as_deref().and_then(parse_ts_type),
+        default: None,
+        description: doc.clone(),
+      });
+    }
+  }
+}
@@ -1,5 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::ts_type::parse_ts_type;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeDefOptions;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use deno_ast::ParsedSource;
@@ -14,12 +19,15 @@ pub struct TypeAliasDef {
 
 pub fn get_doc_for_ts_type_alias_decl(
   parsed_source: &ParsedSource,
+  options: TsTypeDefOptions,
   type_alias_decl: &deno_ast::swc::ast::TsTypeAliasDecl,
 ) -> (String, TypeAliasDef) {
   let alias_name = type_alias_decl.id.sym.to_string();
-  let ts_type = TsTypeDef::new(parsed_source, &type_alias_decl.type_ann);
+  let ts_type =
+    TsTypeDef::new(parsed_source, options, &type_alias_decl.type_ann);
   let type_params = maybe_type_param_decl_to_type_param_defs(
     parsed_source,
+    options,
     type_alias_decl.type_params.as_deref(),
   );
   let type_alias_def = TypeAliasDef {
@@ -29,3 +37,86 @@ pub fn get_doc_for_ts_type_alias_decl(
 
   (alias_name, type_alias_def)
 }
+
+/// Builds the function type alias declared by a `@callback` tag, using the
+/// `@param` and `@returns` tags of the same JSDoc block for its signature,
+/// eg. `(x: unknown) => boolean` for
+/// `@callback Predicate @param x - item @returns {boolean} whether it matches`.
+pub fn callback_to_type_alias_def(
+  js_doc: &JsDoc,
+) -> Option<(String, TypeAliasDef)> {
+  let name = js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::Callback { name, .. } => Some(name.clone()),
+    _ => None,
+  })?;
+
+  // types which can't be parsed are replaced by `unknown`, so that a single
+  // invalid type does not discard the whole signature
+  let type_text = |type_ref: Option<&str>| match type_ref {
+    Some(type_ref) if parse_ts_type(type_ref).is_some() => type_ref.to_string(),
+    _ => "unknown".to_string(),
+  };
+  let array_type_text = |type_ref: &str| match parse_ts_type(type_ref) {
+    Some(TsTypeDef {
+      kind:
+        Some(
+          TsTypeDefKind::Keyword
+          | TsTypeDefKind::TypeRef
+          | TsTypeDefKind::Array,
+        ),
+      ..
+    }) => format!("{type_ref}[]"),
+    Some(_) => format!("Array<{type_ref}>"),
+    None => "unknown[]".to_string(),
+  };
+
+  let mut params = vec![];
+  let mut return_type = "void".to_string();
+  for tag in &js_doc.tags {
+    match tag {
+      // properties of a parameter, eg. `@param options.name`
+      JsDocTag::Param { name, .. } if name.contains('.') => {}
+      JsDocTag::Param {
+        name,
+        type_ref,
+        optional,
+        default,
+        ..
+      } => {
+        let type_ref = type_ref.as_deref().map(str::trim);
+        let param = match type_ref {
+          Some(type_ref) if type_ref.starts_with("...") => {
+            format!("...{name}: {}", array_type_text(&type_ref[3..]))
+          }
+          Some(type_ref) if type_ref.ends_with('=') => format!(
+            "{name}?: {}",
+            type_text(Some(&type_ref[..type_ref.len() - 1]))
+          ),
+          _ => {
+            let optional = if *optional || default.is_some() {
+              "?"
+            } else {
+              ""
+            };
+            format!("{name}{optional}: {}", type_text(type_ref))
+          }
+        };
+        params.push(param);
+      }
+      JsDocTag::Return { type_ref, .. } => {
+        return_type = type_text(type_ref.as_deref());
+      }
+      _ => {}
+    }
+  }
+
+  let ts_type =
+    parse_ts_type(&format!("({}) => {return_type}", params.join(", ")))?;
+  Some((
+    name,
+    TypeAliasDef {
+      ts_type,
+      type_params: vec![],
+    },
+  ))
+}
@@ -16,6 +16,9 @@ use crate::node::Location;
 
 lazy_static! {
   static ref JS_DOC_RE: Regex = Regex::new(r"\s*\* ?").unwrap();
+  static ref TRIPLE_SLASH_REFERENCE_RE: Regex =
+    Regex::new(r#"^/\s*<reference\s+(?:types|lib)\s*=\s*["']([^"']+)["']"#)
+      .unwrap();
 }
 
 pub(crate) fn is_false(b: &bool) -> bool {
@@ -44,7 +47,13 @@ pub(crate) fn js_doc_for_range_include_ignore(
   if let Some(js_doc_comment) = comments.iter().rev().find(|comment| {
     comment.kind == CommentKind::Block && comment.text.starts_with('*')
   }) {
-    parse_js_doc(js_doc_comment)
+    let js_doc = parse_js_doc(js_doc_comment);
+    // the package documentation doesn't belong to the symbol that follows it
+    if js_doc.tags.contains(&JsDocTag::PackageDocumentation) {
+      JsDoc::default()
+    } else {
+      js_doc
+    }
   } else {
     JsDoc::default()
   }
@@ -62,6 +71,51 @@ pub(crate) fn js_doc_for_range(
   }
 }
 
+/// All the JSDoc comments of the source, in source order, with their range.
+pub(crate) fn js_docs_for_source(
+  parsed_source: &ParsedSource,
+) -> Vec<(JsDoc, SourceRange)> {
+  let mut comments = parsed_source
+    .comments()
+    .iter_unstable()
+    .filter(|comment| {
+      comment.kind == CommentKind::Block && comment.text.starts_with('*')
+    })
+    .collect::<Vec<_>>();
+  comments.sort_by_key(|comment| comment.range().start);
+  comments
+    .into_iter()
+    .map(|comment| (parse_js_doc(comment), comment.range()))
+    .collect()
+}
+
+/// The first JSDoc comment of the source with a `@packageDocumentation` tag.
+pub(crate) fn package_js_doc_for_source(
+  parsed_source: &ParsedSource,
+) -> Option<JsDoc> {
+  js_docs_for_source(parsed_source)
+    .into_iter()
+    .map(|(js_doc, _)| js_doc)
+    .find(|js_doc| js_doc.tags.contains(&JsDocTag::PackageDocumentation))
+}
+
+/// The packages and libraries referenced by the `/// <reference types="..." />`
+/// and `/// <reference lib="..." />` directives of the source.
+pub(crate) fn module_references_for_source(
+  parsed_source: &ParsedSource,
+) -> Vec<String> {
+  parsed_source
+    .get_leading_comments()
+    .into_iter()
+    .flatten()
+    .filter(|comment| comment.kind == CommentKind::Line)
+    .filter_map(|comment| {
+      let caps = TRIPLE_SLASH_REFERENCE_RE.captures(&comment.text)?;
+      Some(caps.get(1).unwrap().as_str().to_string())
+    })
+    .collect()
+}
+
 /// Inspects leading comments in the source and returns the first JSDoc comment
 /// with a `@module` tag along with its associated range, otherwise returns
 /// `None`.
@@ -1,13 +1,20 @@
 // Copyright 2020-2023 the Deno authors. All rights reserved. MIT license.
 
+use std::collections::HashSet;
+
+use deno_ast::swc::ast::ExportSpecifier;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::ModuleItem;
 use deno_ast::ModuleSpecifier;
 use deno_graph::symbols::ModuleInfoRef;
+use deno_graph::symbols::ResolvedExportOrReExportAllPath;
 use deno_graph::symbols::Symbol;
 use deno_graph::symbols::SymbolDecl;
 
 use crate::parser::DocError;
 use crate::util::swc::has_ignorable_js_doc_tag;
 use crate::util::swc::js_doc_for_range_include_ignore;
+use crate::util::swc::module_export_name_value;
 
 pub fn get_module_info<'a>(
   root_symbol: &'a deno_graph::symbols::RootSymbol,
@@ -52,3 +59,45 @@ fn decl_has_ignorable_js_doc_tag(
   let js_doc = js_doc_for_range_include_ignore(module.source(), &decl.range);
   has_ignorable_js_doc_tag(&js_doc)
 }
+
+/// Checks if the export was reached through an `export type * from "..."`
+/// declaration, in which case only its type meaning is exported.
+pub fn is_type_only_reexport(export: &ResolvedExportOrReExportAllPath) -> bool {
+  export.iter().any(|path| match path {
+    ResolvedExportOrReExportAllPath::Export(_) => false,
+    ResolvedExportOrReExportAllPath::ReExportAllPath(re_export) => re_export
+      .referrer_module
+      .re_export_all_nodes()
+      .into_iter()
+      .flatten()
+      .any(|export_all| {
+        export_all.type_only && export_all.src.value == *re_export.specifier
+      }),
+  })
+}
+
+/// Collects the names the module exports through a type-only specifier, eg.
+/// `export { type Foo } from "./mod.ts"` or `export type { Foo }`, for which
+/// only the type meaning is exported.
+pub fn type_only_named_exports(module: ModuleInfoRef) -> HashSet<String> {
+  let Some(module) = module.esm() else {
+    return HashSet::new();
+  };
+  let mut names = HashSet::new();
+  for item in &module.source().module().body {
+    let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) = item
+    else {
+      continue;
+    };
+    for specifier in &named_export.specifiers {
+      let ExportSpecifier::Named(specifier) = specifier else {
+        continue;
+      };
+      if named_export.type_only || specifier.is_type_only {
+        let name = specifier.exported.as_ref().unwrap_or(&specifier.orig);
+        names.insert(module_export_name_value(name));
+      }
+    }
+  }
+  names
+}
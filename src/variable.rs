@@ -1,6 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::Pat;
+use deno_ast::swc::ast::Prop;
+use deno_ast::swc::ast::PropName;
+use deno_ast::swc::ast::PropOrSpread;
+use deno_ast::swc::ast::VarDeclKind;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use deno_graph::symbols::EsModuleInfo;
@@ -8,20 +13,94 @@ use deno_graph::symbols::SymbolNodeRef;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::interface::expr_to_name;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
 use crate::ts_type::infer_simple_ts_type_from_var_decl;
+use crate::ts_type::infer_ts_type_from_expr;
+use crate::ts_type::parse_ts_type;
 use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeDefOptions;
+use crate::util::swc::is_false;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct VariableDef {
   pub ts_type: Option<TsTypeDef>,
   pub kind: deno_ast::swc::ast::VarDeclKind,
+  /// Set for `using` and `await using` declarations, in which case `kind` is
+  /// `const`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub using: Option<UsingDeclKind>,
+  /// Set for default exports that are Deno Deploy handlers, ie. objects with a
+  /// `fetch`, `scheduled` or `email` method, or ones tagged `@deployHandler`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub deploy_handler: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum UsingDeclKind {
+  /// `using resource = getResource();`
+  Using,
+  /// `await using resource = getAsyncResource();`
+  AwaitUsing,
+}
+
+/// The type given by a `@proxy` JSDoc tag, which documents variables holding
+/// a `Proxy`, as their shape can't be inferred.
+pub fn ts_type_from_proxy_tag(js_doc: &JsDoc) -> Option<TsTypeDef> {
+  js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::Proxy { type_ref, .. } => parse_ts_type(type_ref),
+    _ => None,
+  })
 }
 
 pub fn get_docs_for_var_declarator(
   module_info: &EsModuleInfo,
+  options: TsTypeDefOptions,
   var_decl: &deno_ast::swc::ast::VarDecl,
   var_declarator: &deno_ast::swc::ast::VarDeclarator,
+) -> Vec<(String, VariableDef, Option<SourceRange>)> {
+  get_docs_for_declarator(
+    module_info,
+    options,
+    var_decl.kind,
+    None,
+    var_declarator,
+  )
+}
+
+/// SWC represents `using` declarations with their own `UsingDecl` node
+/// instead of a `VarDeclKind`, but their declarators are the same as the ones
+/// of a `const` declaration.
+pub fn get_docs_for_using_declarator(
+  module_info: &EsModuleInfo,
+  options: TsTypeDefOptions,
+  using_decl: &deno_ast::swc::ast::UsingDecl,
+  var_declarator: &deno_ast::swc::ast::VarDeclarator,
+) -> Vec<(String, VariableDef, Option<SourceRange>)> {
+  let using = if using_decl.is_await {
+    UsingDeclKind::AwaitUsing
+  } else {
+    UsingDeclKind::Using
+  };
+  get_docs_for_declarator(
+    module_info,
+    options,
+    VarDeclKind::Const,
+    Some(using),
+    var_declarator,
+  )
+}
+
+fn get_docs_for_declarator(
+  module_info: &EsModuleInfo,
+  options: TsTypeDefOptions,
+  kind: VarDeclKind,
+  using: Option<UsingDeclKind>,
+  var_declarator: &deno_ast::swc::ast::VarDeclarator,
 ) -> Vec<(String, VariableDef, Option<SourceRange>)> {
   let mut items = Vec::<(String, VariableDef, Option<SourceRange>)>::new();
   let ref_name: Option<deno_ast::swc::ast::Id> =
@@ -36,10 +115,11 @@ pub fn get_docs_for_var_declarator(
   let maybe_ts_type_ann = match &var_declarator.name {
     Pat::Ident(ident) => ident.type_ann.as_ref(),
     Pat::Object(pat) => pat.type_ann.as_ref(),
+    Pat::Array(pat) => pat.type_ann.as_ref(),
     _ => None,
   };
   let maybe_ts_type = maybe_ts_type_ann
-    .map(|def| TsTypeDef::new(module_info.source(), &def.type_ann))
+    .map(|def| TsTypeDef::new(module_info.source(), options, &def.type_ann))
     .or_else(|| {
       if let Some(ref_name) = ref_name {
         module_info.symbol_from_swc(&ref_name).and_then(|symbol| {
@@ -53,6 +133,7 @@ pub fn get_docs_for_var_declarator(
                 if let Some(type_ann) = &ident.type_ann {
                   return Some(TsTypeDef::new(
                     module_info.source(),
+                    options,
                     &type_ann.type_ann,
                   ));
                 }
@@ -60,8 +141,9 @@ pub fn get_docs_for_var_declarator(
             }
             let maybe_type_ann = infer_simple_ts_type_from_var_decl(
               module_info.source(),
+              options,
               var_declarator,
-              var_decl.kind == deno_ast::swc::ast::VarDeclKind::Const,
+              kind == VarDeclKind::Const,
             );
             if let Some(type_ann) = maybe_type_ann {
               return Some(type_ann);
@@ -76,8 +158,9 @@ pub fn get_docs_for_var_declarator(
     .or_else(|| {
       infer_simple_ts_type_from_var_decl(
         module_info.source(),
+        options,
         var_declarator,
-        var_decl.kind == deno_ast::swc::ast::VarDeclKind::Const,
+        kind == VarDeclKind::Const,
       )
     });
 
@@ -86,51 +169,227 @@ pub fn get_docs_for_var_declarator(
       let var_name = ident.id.sym.to_string();
       let variable_def = VariableDef {
         ts_type: maybe_ts_type,
-        kind: var_decl.kind,
+        kind,
+        using,
+        deploy_handler: false,
       };
       items.push((var_name, variable_def, Some(var_declarator.range())));
     }
     deno_ast::swc::ast::Pat::Object(pat) => {
       for prop in &pat.props {
-        let (name, reassign_name, maybe_range) = match prop {
-          deno_ast::swc::ast::ObjectPatProp::KeyValue(kv) => (
-            crate::params::prop_name_to_string(module_info.source(), &kv.key),
-            match &*kv.value {
+        let (name, reassign_name, maybe_range, maybe_default) = match prop {
+          deno_ast::swc::ast::ObjectPatProp::KeyValue(kv) => {
+            let (reassign_name, maybe_default) = match &*kv.value {
               deno_ast::swc::ast::Pat::Ident(ident) => {
-                Some(ident.sym.to_string())
+                (Some(ident.sym.to_string()), None)
               }
-              _ => None, // TODO: properly implement
-            },
-            None,
-          ),
+              // `const { a: b = "default" } = obj`
+              deno_ast::swc::ast::Pat::Assign(assign) => match &*assign.left {
+                deno_ast::swc::ast::Pat::Ident(ident) => {
+                  (Some(ident.sym.to_string()), Some(&*assign.right))
+                }
+                _ => (None, None), // TODO: properly implement
+              },
+              _ => (None, None), // TODO: properly implement
+            };
+            (
+              crate::params::prop_name_to_string(module_info.source(), &kv.key),
+              reassign_name,
+              None,
+              maybe_default,
+            )
+          }
           deno_ast::swc::ast::ObjectPatProp::Assign(assign) => {
-            (assign.key.sym.to_string(), None, Some(assign.range()))
+            (assign.key.sym.to_string(), None, Some(assign.range()), None)
           }
           deno_ast::swc::ast::ObjectPatProp::Rest(_) => {
-            ("".to_string(), None, None)
+            ("".to_string(), None, None, None)
           } // TODO: properly implement
         };
 
-        let ts_type = maybe_ts_type.as_ref().and_then(|ts_type| {
-          ts_type.type_literal.as_ref().and_then(|type_literal| {
-            type_literal.properties.iter().find_map(|property| {
-              if property.name == name {
-                property.ts_type.clone()
-              } else {
-                None
-              }
+        // the properties of type literals with a computed key are named
+        // after the expression of the key, eg. `KEY` for `[KEY]: string`
+        let (key, computed) = match prop {
+          deno_ast::swc::ast::ObjectPatProp::KeyValue(kv) => match &kv.key {
+            PropName::Computed(computed) => {
+              (expr_to_name(&computed.expr), true)
+            }
+            _ => (name.clone(), false),
+          },
+          _ => (name.clone(), false),
+        };
+        let ts_type = maybe_ts_type
+          .as_ref()
+          .and_then(|ts_type| {
+            ts_type.type_literal.as_ref().and_then(|type_literal| {
+              type_literal.properties.iter().find_map(|property| {
+                if property.name == key && property.computed == computed {
+                  property.ts_type.clone()
+                } else {
+                  None
+                }
+              })
             })
           })
-        });
+          .or_else(|| {
+            // the default value is only used when the property is missing,
+            // so its literal type is widened
+            maybe_default
+              .filter(|_| !options.isolated_declarations)
+              .and_then(|default| {
+                infer_ts_type_from_expr(
+                  module_info.source(),
+                  options,
+                  default,
+                  false,
+                )
+              })
+          });
 
         let variable_def = VariableDef {
           ts_type,
-          kind: var_decl.kind,
+          kind,
+          using,
+          deploy_handler: false,
         };
         items.push((reassign_name.unwrap_or(name), variable_def, maybe_range));
       }
     }
+    deno_ast::swc::ast::Pat::Array(pat) => {
+      for (index, elem) in pat.elems.iter().enumerate() {
+        let (ident, maybe_default) = match elem {
+          Some(deno_ast::swc::ast::Pat::Ident(ident)) => (ident, None),
+          // `const [a = "default"] = arr`
+          Some(deno_ast::swc::ast::Pat::Assign(assign)) => {
+            match &*assign.left {
+              deno_ast::swc::ast::Pat::Ident(ident) => {
+                (ident, Some(&*assign.right))
+              }
+              _ => continue, // TODO: properly implement
+            }
+          }
+          _ => continue, // TODO: properly implement
+        };
+
+        let ts_type = maybe_ts_type
+          .as_ref()
+          .and_then(|ts_type| element_ts_type(ts_type, index))
+          .or_else(|| {
+            maybe_default
+              .filter(|_| !options.isolated_declarations)
+              .and_then(|default| {
+                infer_ts_type_from_expr(
+                  module_info.source(),
+                  options,
+                  default,
+                  false,
+                )
+              })
+          });
+
+        let variable_def = VariableDef {
+          ts_type,
+          kind,
+          using,
+          deploy_handler: false,
+        };
+        items.push((
+          ident.id.sym.to_string(),
+          variable_def,
+          Some(ident.range()),
+        ));
+      }
+    }
     _ => (),
   }
   items
 }
+
+/// The type of the element at the index of an array or tuple type, eg.
+/// `number` for the second element of `[string, number]`. For a union, the
+/// members that are neither arrays nor tuples, such as `undefined`, are
+/// ignored, and the type is only known if it is the same in all the others.
+fn element_ts_type(ts_type: &TsTypeDef, index: usize) -> Option<TsTypeDef> {
+  if let Some(ts_type) = &ts_type.parenthesized {
+    element_ts_type(ts_type, index)
+  } else if let Some(element) = &ts_type.array {
+    Some((**element).clone())
+  } else if let Some(elements) = &ts_type.tuple {
+    elements
+      .get(index)
+      .filter(|element| element.rest.is_none())
+      .cloned()
+  } else if let Some(members) = &ts_type.union {
+    let mut element_types = members
+      .iter()
+      .filter(|member| {
+        matches!(
+          member.kind,
+          Some(
+            TsTypeDefKind::Array
+              | TsTypeDefKind::Tuple
+              | TsTypeDefKind::Parenthesized
+          )
+        )
+      })
+      .map(|member| element_ts_type(member, index));
+    let first = element_types.next()??;
+    element_types
+      .all(|element_type| element_type.as_ref() == Some(&first))
+      .then_some(first)
+  } else {
+    None
+  }
+}
+
+/// Checks whether a default exported expression is a Deno Deploy handler, eg.
+/// `export default { fetch(req) { ... } }`.
+pub fn is_deploy_handler(js_doc: &JsDoc, expr: &Expr) -> bool {
+  if js_doc
+    .tags
+    .iter()
+    .any(|tag| matches!(tag, JsDocTag::DeployHandler))
+  {
+    return true;
+  }
+
+  // `export default { ... } satisfies Deno.ServeDefaultExport`
+  let mut expr = expr;
+  loop {
+    expr = match expr {
+      Expr::Paren(paren) => &paren.expr,
+      Expr::TsAs(ts_as) => &ts_as.expr,
+      Expr::TsSatisfies(ts_satisfies) => &ts_satisfies.expr,
+      Expr::TsConstAssertion(assertion) => &assertion.expr,
+      _ => break,
+    };
+  }
+  let Expr::Object(object) = expr else {
+    return false;
+  };
+
+  object.props.iter().any(|prop| {
+    let PropOrSpread::Prop(prop) = prop else {
+      return false;
+    };
+    let name = match &**prop {
+      Prop::Method(method) => match &method.key {
+        PropName::Ident(ident) => &ident.sym,
+        _ => return false,
+      },
+      Prop::KeyValue(key_value)
+        if matches!(&*key_value.value, Expr::Fn(_) | Expr::Arrow(_)) =>
+      {
+        match &key_value.key {
+          PropName::Ident(ident) => &ident.sym,
+          _ => return false,
+        }
+      }
+      Prop::Shorthand(ident) => &ident.sym,
+      _ => return false,
+    };
+    DEPLOY_HANDLER_METHODS.contains(&&**name)
+  })
+}
+
+const DEPLOY_HANDLER_METHODS: [&str; 3] = ["fetch", "scheduled", "email"];
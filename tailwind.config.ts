@@ -20,6 +20,7 @@ const extraColors = {
   "protected": TAG_PURPLE,
   "private": TAG_PURPLE,
   "optional": TAG_CYAN,
+  "override": TAG_CYAN,
   "permissions": TAG_CYAN,
   "other": "#57534E", // stone 600
 };
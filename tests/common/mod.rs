@@ -0,0 +1,162 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::ModuleSpecifier;
+use deno_doc::html::*;
+use deno_doc::DocParser;
+use deno_doc::DocParserOptions;
+use deno_graph::source::MemoryLoader;
+use deno_graph::source::Source;
+use deno_graph::BuildOptions;
+use deno_graph::CapturingModuleAnalyzer;
+use deno_graph::GraphKind;
+use deno_graph::ModuleGraph;
+use indexmap::IndexMap;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Whether the tests are run with `UPDATE_SNAPSHOTS=1`, or `UPDATE=1`, to
+/// write the snapshots instead of comparing against them.
+fn update_snapshots() -> bool {
+  std::env::var("UPDATE_SNAPSHOTS").is_ok() || std::env::var("UPDATE").is_ok()
+}
+
+/// Asserts that `actual` matches the snapshot file at `path`. Run the tests
+/// with `UPDATE_SNAPSHOTS=1` to write `actual` to the file instead.
+pub fn assert_snapshot(path: &Path, actual: &str) {
+  if update_snapshots() {
+    std::fs::write(path, actual).unwrap();
+  }
+  let expected = std::fs::read_to_string(path).unwrap();
+  pretty_assertions::assert_eq!(
+    actual,
+    expected,
+    "Should be same as {}",
+    path.display()
+  );
+}
+
+/// Documents each `.ts` file of `fixtures_dir` on its own, and compares the
+/// JSON of its doc nodes and the HTML of its page to the `.json` and `.html`
+/// snapshot files of the same name, see [`assert_snapshot`].
+pub async fn generate_test_snapshots(fixtures_dir: &Path) {
+  let mut fixtures = std::fs::read_dir(fixtures_dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| path.extension().is_some_and(|ext| ext == "ts"))
+    .collect::<Vec<_>>();
+  fixtures.sort();
+  assert!(
+    !fixtures.is_empty(),
+    "no fixtures in {}",
+    fixtures_dir.display()
+  );
+
+  for fixture in fixtures {
+    let file_name = fixture.file_name().unwrap().to_str().unwrap();
+    // the fixtures are loaded from memory, so that the snapshots don't
+    // depend on where the repository is checked out
+    let specifier =
+      ModuleSpecifier::parse(&format!("file:///{file_name}")).unwrap();
+    let source = std::fs::read_to_string(&fixture).unwrap();
+    let mut loader = MemoryLoader::new(
+      vec![(
+        specifier.to_string(),
+        Source::Module {
+          specifier: specifier.to_string(),
+          maybe_headers: None,
+          content: source,
+        },
+      )],
+      vec![],
+    );
+    let analyzer = CapturingModuleAnalyzer::default();
+    let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+    graph
+      .build(
+        vec![specifier.clone()],
+        &mut loader,
+        BuildOptions {
+          module_analyzer: Some(&analyzer),
+          module_parser: Some(&analyzer),
+          ..Default::default()
+        },
+      )
+      .await;
+    let doc_nodes =
+      DocParser::new(&graph, &analyzer, DocParserOptions::default())
+        .unwrap()
+        .parse_with_reexports(&specifier)
+        .unwrap();
+
+    let json = serde_json::to_string_pretty(&doc_nodes).unwrap();
+    assert_snapshot(&fixture.with_extension("json"), &format!("{json}\n"));
+
+    let mut doc_nodes_by_url = IndexMap::new();
+    doc_nodes_by_url.insert(specifier, doc_nodes);
+    let files = generate(snapshot_options(), &doc_nodes_by_url).unwrap();
+    assert_snapshot(
+      &fixture.with_extension("html"),
+      files.get("./~/index.html").unwrap(),
+    );
+  }
+}
+
+/// The options of the HTML snapshots, which document all the symbols of a
+/// fixture on its module page. The usages are left out, so that the snapshots
+/// don't depend on the syntax highlighter.
+fn snapshot_options() -> GenerateOptions {
+  GenerateOptions {
+    package_name: None,
+    main_entrypoint: None,
+    href_resolver: Rc::new(EmptyResolver {}),
+    usage_composer: Some(Rc::new(|_, _, _| IndexMap::new())),
+    rewrite_map: None,
+    hide_module_doc_title: false,
+    sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    syntax_theme: Default::default(),
+    og_base_url: None,
+    og_image: None,
+    sitemap_base_url: None,
+    base_path: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: false,
+    collapse_type_threshold: None,
+  }
+}
+
+pub struct EmptyResolver {}
+
+impl HrefResolver for EmptyResolver {
+  fn resolve_global_symbol(&self, _symbol: &[String]) -> Option<String> {
+    None
+  }
+
+  fn resolve_import_href(
+    &self,
+    _symbol: &[String],
+    _src: &str,
+  ) -> Option<String> {
+    None
+  }
+
+  fn resolve_usage(
+    &self,
+    _current_specifier: &ModuleSpecifier,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    Some(
+      current_file
+        .map(|current_file| current_file.as_str().to_string())
+        .unwrap_or_default(),
+    )
+  }
+
+  fn resolve_source(&self, _location: &deno_doc::Location) -> Option<String> {
+    None
+  }
+}
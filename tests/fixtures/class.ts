@@ -0,0 +1,28 @@
+/** An animal. */
+export abstract class Animal<T = string> implements Iterable<T> {
+  /** The number of animals. */
+  static count = 0;
+
+  /** Creates an animal. */
+  constructor(public readonly name: string, protected age: number) {}
+
+  /** The sound of the animal. */
+  abstract speak(): T;
+
+  /** The size of the animal. */
+  get size(): number {
+    return 1;
+  }
+  set size(value: number) {}
+
+  /** Iterates the sounds. */
+  *[Symbol.iterator](): Iterator<T> {}
+}
+
+/** A dog. */
+export class Dog extends Animal {
+  /** Barks. */
+  override speak(): string {
+    return "woof";
+  }
+}
@@ -0,0 +1,27 @@
+/**
+ * Adds two numbers.
+ *
+ * @param a The first number
+ * @param b The second number
+ * @returns The sum of both numbers
+ */
+export function add(a: number, b = 0): number {
+  return a + b;
+}
+
+/** Parses a value. */
+export function parse(value: string): number;
+/** Parses a value, with a fallback. */
+export function parse(value: string, fallback: number): number;
+export function parse(value: string, fallback?: number): number {
+  return Number(value) || fallback || 0;
+}
+
+/**
+ * Returns the first item of a list.
+ *
+ * @template T The type of the items
+ */
+export async function first<T>(items: T[]): Promise<T | undefined> {
+  return items[0];
+}
@@ -0,0 +1,16 @@
+/** The options of a request. */
+export interface RequestOptions {
+  /** The method of the request. */
+  method?: "GET" | "POST";
+  /** The headers of the request. */
+  readonly headers: Record<string, string>;
+  /** Called when the request is done. */
+  onDone(status: number): void;
+  [key: string]: unknown;
+}
+
+/** A handler of requests. */
+export type Handler<T = unknown> = (
+  request: T,
+  options: RequestOptions,
+) => Promise<void>;
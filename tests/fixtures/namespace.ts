@@ -0,0 +1,18 @@
+/** The levels of logging. */
+export enum Level {
+  /** Debug messages. */
+  Debug = 1,
+  Info,
+}
+
+/** The utilities of the module. */
+export namespace Util {
+  /** The version of the utilities. */
+  export const VERSION = "1.0.0";
+
+  /** Logs a message. */
+  export function log(level: Level, message: string): void {}
+}
+
+/** The default level. */
+export let level: Level = Level.Info;
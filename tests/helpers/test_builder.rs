@@ -2,6 +2,7 @@
 
 use deno_ast::ModuleSpecifier;
 use deno_doc::DocDiagnostic;
+use deno_doc::DocMode;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
 use deno_doc::DocPrinter;
@@ -67,6 +68,11 @@ impl TestBuilder {
       DocParserOptions {
         private: self.private,
         diagnostics: true,
+        prefer_declaration_files: false,
+        include_source: false,
+        mode: DocMode::Full,
+        max_depth: Some(10),
+        isolated_declarations: false,
       },
     )
     .unwrap();
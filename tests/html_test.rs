@@ -1,5 +1,6 @@
 use deno_ast::ModuleSpecifier;
 use deno_doc::html::*;
+use deno_doc::DocMode;
 use deno_doc::DocNode;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
@@ -17,6 +18,12 @@ use std::borrow::Cow;
 use std::fs;
 use std::rc::Rc;
 
+use crate::common::assert_snapshot;
+use crate::common::generate_test_snapshots;
+use crate::common::EmptyResolver;
+
+mod common;
+
 struct SourceFileLoader {}
 
 impl Loader for SourceFileLoader {
@@ -43,39 +50,52 @@ impl Loader for SourceFileLoader {
   }
 }
 
-struct EmptyResolver {}
-
-impl HrefResolver for EmptyResolver {
-  fn resolve_global_symbol(&self, _symbol: &[String]) -> Option<String> {
-    None
-  }
-
-  fn resolve_import_href(
-    &self,
-    _symbol: &[String],
-    _src: &str,
-  ) -> Option<String> {
-    None
-  }
-
-  fn resolve_usage(
-    &self,
-    _current_specifier: &ModuleSpecifier,
-    current_file: Option<&ShortPath>,
-  ) -> Option<String> {
-    Some(
-      current_file
-        .map(|current_file| current_file.as_str().to_string())
-        .unwrap_or_default(),
-    )
-  }
-
-  fn resolve_source(&self, _location: &deno_doc::Location) -> Option<String> {
-    None
+/// The options of the tests, which override the ones they exercise.
+fn generate_options() -> GenerateOptions {
+  GenerateOptions {
+    package_name: None,
+    main_entrypoint: None,
+    href_resolver: Rc::new(EmptyResolver {}),
+    usage_composer: None,
+    rewrite_map: None,
+    hide_module_doc_title: false,
+    sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    syntax_theme: Default::default(),
+    og_base_url: None,
+    og_image: None,
+    sitemap_base_url: None,
+    base_path: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: true,
+    collapse_type_threshold: None,
   }
 }
 
 async fn get_files(subpath: &str) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
+  get_files_with_options(
+    subpath,
+    DocParserOptions {
+      diagnostics: false,
+      private: false,
+      prefer_declaration_files: false,
+      include_source: false,
+      mode: DocMode::Full,
+      max_depth: Some(10),
+      isolated_declarations: false,
+    },
+  )
+  .await
+}
+
+async fn get_files_with_options(
+  subpath: &str,
+  options: DocParserOptions,
+) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
   let files = fs::read_dir(
     std::env::current_dir()
       .unwrap()
@@ -108,15 +128,7 @@ async fn get_files(subpath: &str) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
     )
     .await;
 
-  let parser = DocParser::new(
-    &graph,
-    &analyzer,
-    DocParserOptions {
-      diagnostics: false,
-      private: false,
-    },
-  )
-  .unwrap();
+  let parser = DocParser::new(&graph, &analyzer, options).unwrap();
 
   let mut source_files = source_files.clone();
   source_files.sort();
@@ -131,19 +143,7 @@ async fn get_files(subpath: &str) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
 
 #[tokio::test]
 async fn html_doc_files() {
-  let files = generate(
-    GenerateOptions {
-      package_name: None,
-      main_entrypoint: None,
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
-      rewrite_map: None,
-      hide_module_doc_title: false,
-      sidebar_flatten_namespaces: false,
-    },
-    &get_files("single").await,
-  )
-  .unwrap();
+  let files = generate(generate_options(), &get_files("single").await).unwrap();
 
   let mut file_names = files.keys().collect::<Vec<_>>();
   file_names.sort();
@@ -186,13 +186,8 @@ async fn html_doc_files_rewrite() {
 
   let files = generate(
     GenerateOptions {
-      package_name: None,
-      main_entrypoint: None,
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
       rewrite_map: Some(rewrite_map),
-      hide_module_doc_title: false,
-      sidebar_flatten_namespaces: false,
+      ..generate_options()
     },
     &get_files("multiple").await,
   )
@@ -222,6 +217,617 @@ async fn html_doc_files_rewrite() {
   );
 }
 
+#[tokio::test]
+async fn html_doc_files_custom_head() {
+  let files = generate(
+    GenerateOptions {
+      custom_css: Some(
+        ".ddoc > h1 { color: red; }</style><script>alert(1)</script>"
+          .to_string(),
+      ),
+      custom_head_html: Some(
+        r#"<script src="https://example.com/analytics.js"></script>"#
+          .to_string(),
+      ),
+      ..generate_options()
+    },
+    &get_files("single").await,
+  )
+  .unwrap();
+
+  for file_name in ["./index.html", "./all_symbols.html", "./~/Foo.html"] {
+    let page = files.get(file_name).unwrap();
+    let head = &page[..page.find("</head>").unwrap()];
+    assert!(
+      head.contains(
+        "<style>.ddoc > h1 { color: red; }<\\/style><script>alert(1)<\\/script></style>"
+      ),
+      "{file_name} should contain the escaped custom css",
+    );
+    assert!(
+      head.contains(
+        r#"<script src="https://example.com/analytics.js"></script>"#
+      ),
+      "{file_name} should contain the custom head html",
+    );
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_syntax_theme() {
+  let doc_nodes_by_url = get_files("single").await;
+  let get_stylesheet = |syntax_theme: SyntaxTheme| {
+    let files = generate(
+      GenerateOptions {
+        syntax_theme,
+        ..generate_options()
+      },
+      &doc_nodes_by_url,
+    )
+    .unwrap();
+    files.get("styles.css").unwrap().clone()
+  };
+
+  let default = get_stylesheet(SyntaxTheme::Default);
+  assert_eq!(default, deno_doc::html::STYLESHEET);
+
+  let github_dark = get_stylesheet(SyntaxTheme::GithubDark);
+  let dracula = get_stylesheet(SyntaxTheme::Dracula);
+  assert_ne!(github_dark, default);
+  assert_ne!(github_dark, dracula);
+  assert!(github_dark.ends_with(SyntaxTheme::GithubDark.css()));
+  assert!(dracula.ends_with(SyntaxTheme::Dracula.css()));
+}
+
+#[tokio::test]
+async fn html_doc_files_open_graph() {
+  let files = generate(
+    GenerateOptions {
+      og_base_url: Some("https://example.com/docs/".to_string()),
+      og_image: Some("https://example.com/logo.png".to_string()),
+      ..generate_options()
+    },
+    &get_files("multiple").await,
+  )
+  .unwrap();
+
+  let page = files.get("a.ts/~/Foo.html").unwrap();
+  let head = &page[..page.find("</head>").unwrap()];
+  for (property, content) in [
+    ("og:type", "website"),
+    ("og:title", "Foo"),
+    ("og:description", "some Foo docs"),
+    ("og:url", "https://example.com/docs/a.ts/~/Foo.html"),
+    ("og:image", "https://example.com/logo.png"),
+  ] {
+    let meta = format!(
+      r#"<meta property="{property}" content="{}">"#,
+      html_escape::encode_safe(content)
+    );
+    assert!(head.contains(&meta), "head should contain {meta}");
+  }
+
+  let index = files.get("./index.html").unwrap();
+  assert!(!index.contains("og:title"));
+}
+
+#[tokio::test]
+async fn html_doc_files_sitemap() {
+  let files = generate(
+    GenerateOptions {
+      sitemap_base_url: Some("https://example.com/docs/".to_string()),
+      ..generate_options()
+    },
+    &get_files("multiple").await,
+  )
+  .unwrap();
+
+  let sitemap = files.get("sitemap.xml").unwrap();
+  assert!(sitemap.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+  assert!(sitemap.contains(
+    r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#
+  ));
+  assert!(sitemap.trim_end().ends_with("</urlset>"));
+  assert_eq!(
+    sitemap.matches("<url>").count(),
+    sitemap.matches("</url>").count()
+  );
+  assert_eq!(
+    sitemap.matches("<url>").count(),
+    sitemap.matches("<changefreq>monthly</changefreq>").count()
+  );
+  assert!(sitemap.contains("<lastmod>"));
+
+  let mut locs = sitemap
+    .lines()
+    .filter_map(|line| {
+      line
+        .trim()
+        .strip_prefix("<loc>https://example.com/docs/")?
+        .strip_suffix("</loc>")
+    })
+    .map(|loc| loc.replace("&amp;", "&"))
+    .collect::<Vec<_>>();
+  locs.sort();
+
+  let mut pages = files
+    .keys()
+    .filter(|file_name| file_name.ends_with(".html"))
+    .map(|file_name| file_name.trim_start_matches("./").to_string())
+    .collect::<Vec<_>>();
+  pages.sort();
+
+  assert_eq!(locs, pages);
+}
+
+#[tokio::test]
+async fn standalone_sitemap() {
+  let doc_nodes_by_url = get_files("single").await;
+  let files = generate(generate_options(), &doc_nodes_by_url).unwrap();
+  let nodes = doc_nodes_by_url
+    .values()
+    .flatten()
+    .cloned()
+    .collect::<Vec<_>>();
+  let sitemap = generate_sitemap_xml("https://example.com/docs/", &nodes);
+
+  let mut locs = sitemap
+    .lines()
+    .filter_map(|line| {
+      line
+        .trim()
+        .strip_prefix("<loc>https://example.com/docs/")?
+        .strip_suffix("</loc>")
+    })
+    .collect::<Vec<_>>();
+  locs.sort();
+
+  let mut pages = files
+    .keys()
+    .filter(|file_name| file_name.ends_with(".html"))
+    .map(|file_name| file_name.trim_start_matches("./"))
+    .collect::<Vec<_>>();
+  pages.sort();
+
+  assert_eq!(locs, pages);
+}
+
+#[tokio::test]
+async fn html_doc_files_base_path() {
+  let files = generate(
+    GenerateOptions {
+      base_path: Some("/myproject/docs".to_string()),
+      ..generate_options()
+    },
+    &get_files("multiple").await,
+  )
+  .unwrap();
+
+  for (file_name, content) in &files {
+    if !file_name.ends_with(".html") {
+      continue;
+    }
+
+    let content = html_escape::decode_html_entities(content);
+    assert!(
+      content.contains(r#"<meta name="doc-root" content="/myproject/docs/">"#),
+      "{file_name} should contain the base path as its root"
+    );
+
+    let links = content
+      .split(r#"href=""#)
+      .skip(1)
+      .chain(content.split(r#"src=""#).skip(1))
+      .map(|rest| &rest[..rest.find('"').unwrap()])
+      .filter(|link| !link.starts_with('#') && !link.contains("://"))
+      .collect::<Vec<_>>();
+    assert!(!links.is_empty());
+    for link in links {
+      assert!(
+        link.starts_with("/myproject/docs/"),
+        "{file_name} contains the link {link} without the base path"
+      );
+    }
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_class_type_params() {
+  let files =
+    generate(generate_options(), &get_files("generic").await).unwrap();
+
+  let page = files.get("./~/Pair.html").unwrap();
+  let header = page
+    .split(r#"<span class="font-bold">Pair</span>"#)
+    .nth(1)
+    .unwrap();
+  let header = &header[..header.find("</div>").unwrap()];
+  let text = html_escape::decode_html_entities(&strip_tags(header)).to_string();
+  assert_eq!(text.trim(), "<A, B extends A>");
+}
+
+#[tokio::test]
+async fn html_doc_files_class_extends_type_args() {
+  let files =
+    generate(generate_options(), &get_files("generic").await).unwrap();
+
+  let page = files.get("./~/NumberPair.html").unwrap();
+  let subtitle = page
+    .split(r#"<span class="text-stone-400 italic"> extends </span>"#)
+    .nth(1)
+    .unwrap();
+  let subtitle = &subtitle[..subtitle.find("</div>").unwrap()];
+  let text =
+    html_escape::decode_html_entities(&strip_tags(subtitle)).to_string();
+  assert_eq!(text.trim(), "Pair<number, number>");
+}
+
+#[tokio::test]
+async fn html_doc_files_utility_type_link() {
+  let files =
+    generate(generate_options(), &get_files("generic").await).unwrap();
+
+  let page = files.get("./~/first.html").unwrap();
+  let page = html_escape::decode_html_entities(page);
+  assert!(page.contains(
+    r#"<a href="https://www.typescriptlang.org/docs/handbook/utility-types.html" class="link">NoInfer</a>"#
+  ));
+}
+
+#[tokio::test]
+async fn html_doc_files_class_decorators() {
+  let files =
+    generate(generate_options(), &get_files("decorators").await).unwrap();
+
+  let decorators = |page: &str| {
+    let page = files.get(page).unwrap();
+    let decorators = page
+      .split(r#"<div class="text-sm leading-4 text-stone-500 font-mono">"#)
+      .nth(1)
+      .unwrap();
+    let decorators = &decorators[..decorators.find("</div></div>").unwrap()];
+    decorators
+      .split("<div>")
+      .skip(1)
+      .map(|decorator| {
+        html_escape::decode_html_entities(&strip_tags(decorator)).to_string()
+      })
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(decorators("./~/Service.html"), ["@Injectable()"]);
+  assert_eq!(
+    decorators("./~/AppComponent.html"),
+    ["@sealed", r#"@Component({ selector: "app" })"#]
+  );
+}
+
+#[tokio::test]
+async fn html_doc_files_events() {
+  let files = generate(generate_options(), &get_files("events").await).unwrap();
+
+  let page = files.get("./~/Connection.html").unwrap();
+  assert!(page.contains(r#"id="event_MyEvent""#));
+  assert!(page.contains(r#"id="event_OtherEvent""#));
+  let events = strip_tags(page.split("Events").nth(1).unwrap());
+  assert!(events.contains("When something happens"));
+  assert!(!events.contains("- When"));
+}
+
+#[tokio::test]
+async fn html_doc_files_import_type() {
+  let files =
+    generate(generate_options(), &get_files("import_type").await).unwrap();
+
+  let page = files.get("./~/connect.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page)).to_string();
+  assert!(page.contains(r#"options: import("./mod.d.ts").Options"#));
+  assert!(page.contains(r#"Promise<import("./mod.d.ts").Options>"#));
+}
+
+#[tokio::test]
+async fn html_doc_files_tags() {
+  let files = generate(generate_options(), &get_files("tags").await).unwrap();
+
+  let page = files.get("./~/connect.html").unwrap();
+  assert!(page.contains(">Networking</div>"));
+  assert!(page.contains(">Database Access</div>"));
+  let page = files.get("./~/add.html").unwrap();
+  assert!(!page.contains("Networking"));
+}
+
+#[tokio::test]
+async fn html_doc_files_deploy_handler() {
+  let files =
+    generate(generate_options(), &get_files("deploy_handler").await).unwrap();
+
+  let page = files.get("./~/default.html").unwrap();
+  assert!(page.contains(">Deploy Handler</div>"));
+}
+
+#[tokio::test]
+async fn html_doc_files_mapped_type() {
+  let files =
+    generate(generate_options(), &get_files("mapped_type").await).unwrap();
+
+  let page = files.get("./~/Flags.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page)).to_string();
+  assert!(page.contains("T extends string"));
+  assert!(page.contains("[K in T]: boolean"));
+
+  let page = files.get("./~/Getters.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page)).to_string();
+  assert!(
+    page.contains("readonly [K in keyof T as `get${Capitalize<string & K>}`]?")
+  );
+}
+
+#[tokio::test]
+async fn html_doc_files_union_break_threshold() {
+  for union_break_threshold in [4, 10] {
+    let files = generate(
+      GenerateOptions {
+        union_break_threshold,
+        ..generate_options()
+      },
+      &get_files("union").await,
+    )
+    .unwrap();
+
+    let direction = files.get("./~/Direction.html").unwrap();
+    let axis = files.get("./~/Axis.html").unwrap();
+    let member_lines = r#"<div><span> | </span><span>"#;
+    if union_break_threshold == 4 {
+      assert_eq!(direction.matches(member_lines).count(), 6);
+    } else {
+      assert_eq!(direction.matches(member_lines).count(), 0);
+    }
+    assert_eq!(axis.matches(member_lines).count(), 0);
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_collapse_type_threshold() {
+  let collapsed = r#"<details class="inline-block">"#;
+  for collapse_type_threshold in [None, Some(10)] {
+    let files = generate(
+      GenerateOptions {
+        collapse_type_threshold,
+        ..generate_options()
+      },
+      &get_files("collapse_types").await,
+    )
+    .unwrap();
+
+    let name = files.get("./~/Name.html").unwrap();
+    let deep_partial = files.get("./~/DeepPartial.html").unwrap();
+    let point = files.get("./~/Point.html").unwrap();
+    assert!(!name.contains(collapsed));
+    if collapse_type_threshold.is_some() {
+      assert_eq!(deep_partial.matches(collapsed).count(), 1);
+      // only the type of the `transform` property is complex enough
+      assert_eq!(point.matches(collapsed).count(), 1);
+    } else {
+      assert!(!deep_partial.contains(collapsed));
+      assert!(!point.contains(collapsed));
+    }
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_class_source() {
+  let files = generate(
+    generate_options(),
+    &get_files_with_options(
+      "single",
+      DocParserOptions {
+        diagnostics: false,
+        private: false,
+        prefer_declaration_files: false,
+        include_source: true,
+        mode: DocMode::Full,
+        max_depth: Some(10),
+        isolated_declarations: false,
+      },
+    )
+    .await,
+  )
+  .unwrap();
+
+  let page = files.get("./~/Foo.html").unwrap();
+  assert!(page.contains(r#"<details id="source" class="group">"#));
+  let page = html_escape::decode_html_entities(&strip_tags(page)).to_string();
+  assert!(page.contains("View source"));
+  assert!(page.contains("class Foo {"));
+
+  let files = generate(generate_options(), &get_files("single").await).unwrap();
+  assert!(!files.get("./~/Foo.html").unwrap().contains("View source"));
+}
+
+#[tokio::test]
+async fn html_doc_files_rest_param_doc() {
+  let files =
+    generate(generate_options(), &get_files("rest_param").await).unwrap();
+
+  let page = files.get("./~/collect.html").unwrap();
+  let parameters = strip_tags(page.split("Parameters").nth(1).unwrap());
+  assert!(parameters.contains("...items"));
+  assert!(parameters.contains("The items"));
+}
+
+#[tokio::test]
+async fn html_doc_files_exact_optional_property_types() {
+  for exact_optional_property_types in [false, true] {
+    let files = generate(
+      GenerateOptions {
+        exact_optional_property_types,
+        ..generate_options()
+      },
+      &get_files("optional").await,
+    )
+    .unwrap();
+
+    for page in ["./~/Options.html", "./~/Connection.html"] {
+      let page = files.get(page).unwrap();
+      assert!(page.contains(">optional<"));
+      assert_eq!(
+        page.contains(">exact optional<"),
+        exact_optional_property_types
+      );
+    }
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_class_diagram() {
+  let files = generate(
+    GenerateOptions {
+      class_diagrams: true,
+      ..generate_options()
+    },
+    &get_files("events").await,
+  )
+  .unwrap();
+
+  let index =
+    html_escape::decode_html_entities(files.get("./index.html").unwrap())
+      .to_string();
+  assert!(index.contains(r#"<pre class="mermaid">classDiagram"#));
+  assert!(index.contains("EventTarget <|-- Connection"));
+}
+
+#[tokio::test]
+async fn html_doc_files_re_exported_by() {
+  let files =
+    generate(generate_options(), &get_files("reexport").await).unwrap();
+
+  let page = files.get("a.ts/~/index.html").unwrap();
+  let re_exported_by = page.split("Re-exported by").nth(1).unwrap();
+  let re_exported_by = &re_exported_by[..re_exported_by.find("</p>").unwrap()];
+  let text = strip_tags(re_exported_by);
+  let mut links = text.trim().split(", ").collect::<Vec<_>>();
+  links.sort();
+  assert_eq!(links, ["b.ts", "c.ts"]);
+
+  let page = files.get("b.ts/~/index.html").unwrap();
+  assert!(!page.contains("Re-exported by"));
+}
+
+#[tokio::test]
+async fn html_doc_files_wildcard_module() {
+  let files =
+    generate(generate_options(), &get_files("wildcard").await).unwrap();
+
+  let page = files.get("./~/*.svg.html").unwrap();
+  let subtitle = page
+    .split(r#"<span class="text-stone-400 italic"> declare module </span>"#)
+    .nth(1)
+    .unwrap();
+  let subtitle = &subtitle[..subtitle.find("</div>").unwrap()];
+  let text =
+    html_escape::decode_html_entities(&strip_tags(subtitle)).to_string();
+  assert_eq!(text.trim(), "\"*.svg\"");
+  assert!(files.contains_key("./~/*.svg.default.html"));
+}
+
+#[tokio::test]
+async fn html_doc_files_namespace_merge() {
+  let files =
+    generate(generate_options(), &get_files("namespace_merge").await).unwrap();
+
+  let page = files.get("./~/Color.html").unwrap();
+  assert!(page.contains("Red"));
+  assert!(page.contains("parse"));
+  assert!(files.contains_key("./~/Color.parse.html"));
+
+  let page = files.get("./~/Options.html").unwrap();
+  assert!(page.contains("verbose"));
+  assert!(page.contains("defaults"));
+  assert!(files.contains_key("./~/Options.defaults.html"));
+}
+
+#[tokio::test]
+async fn redirect_map() {
+  let nodes = |doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<DocNode>>| {
+    doc_nodes_by_url
+      .values()
+      .flatten()
+      .cloned()
+      .collect::<Vec<_>>()
+  };
+  let old_files = get_files("redirect/old").await;
+  let new_files = get_files("redirect/new").await;
+  let old_nodes = nodes(&old_files);
+  let new_nodes = nodes(&new_files);
+
+  let redirects =
+    generate_redirect_map(&generate_options(), &old_nodes, &new_nodes);
+  let mut redirects = redirects.into_iter().collect::<Vec<_>>();
+  redirects.sort();
+  assert_eq!(
+    redirects,
+    [
+      ("./~/Options.html", "./options.ts/~/Options.html"),
+      ("./~/readFile.html", "./mod.ts/~/readTextFile.html"),
+      ("./~/unchanged.html", "./mod.ts/~/unchanged.html"),
+    ]
+    .map(|(old, new)| (old.to_string(), new.to_string()))
+  );
+
+  // the redirects point to the generated pages
+  let old_pages = generate(generate_options(), &old_files).unwrap();
+  let new_pages = generate(generate_options(), &new_files).unwrap();
+  for (old, new) in &redirects {
+    assert!(old_pages.contains_key(old), "{old}");
+    assert!(
+      new_pages.contains_key(new.trim_start_matches("./")),
+      "{new}"
+    );
+  }
+
+  let redirects = generate_redirect_map(
+    &GenerateOptions {
+      per_symbol_pages: false,
+      ..generate_options()
+    },
+    &old_nodes,
+    &new_nodes,
+  );
+  assert_eq!(
+    redirects["./~/index.html#symbol_readFile"],
+    "./mod.ts/~/index.html#symbol_readTextFile"
+  );
+
+  assert!(
+    generate_redirect_map(&generate_options(), &new_nodes, &new_nodes)
+      .is_empty()
+  );
+}
+
+fn strip_tags(html: &str) -> String {
+  let mut text = String::new();
+  let mut in_tag = false;
+  for c in html.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' => in_tag = false,
+      c if !in_tag => text.push(c),
+      _ => {}
+    }
+  }
+  text
+}
+
+#[tokio::test]
+async fn fixture_snapshots() {
+  generate_test_snapshots(
+    &std::env::current_dir()
+      .unwrap()
+      .join("tests")
+      .join("fixtures"),
+  )
+  .await;
+}
+
 #[tokio::test]
 async fn symbol_group() {
   let multiple_dir = std::env::current_dir()
@@ -259,6 +865,16 @@ async fn symbol_group() {
     single_file_mode: false,
     sidebar_hide_all_symbols: false,
     sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    og_base_url: None,
+    og_image: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: true,
+    collapse_type_threshold: None,
   };
 
   let mut files = vec![];
@@ -292,9 +908,9 @@ async fn symbol_group() {
           );
 
           let html_head_ctx = pages::HtmlHeadCtx::new(
+            &ctx,
             &root,
             &symbol_group_ctx.name,
-            ctx.package_name.as_ref(),
             Some(short_path.clone()),
           );
 
@@ -325,13 +941,7 @@ async fn symbol_group() {
   #[cfg(all(not(feature = "syntect"), not(feature = "tree-sitter")))]
   let symbol_group_json_path = testdata_path.join("symbol_group.json");
 
-  if std::env::var("UPDATE").is_ok() {
-    fs::write(&symbol_group_json_path, &files_json).unwrap();
-  }
-
-  let symbol_group_json = fs::read_to_string(symbol_group_json_path).unwrap();
-
-  assert_eq!(files_json, symbol_group_json);
+  assert_snapshot(&symbol_group_json_path, &files_json);
 }
 
 #[tokio::test]
@@ -371,6 +981,16 @@ async fn symbol_search() {
     single_file_mode: false,
     sidebar_hide_all_symbols: false,
     sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    og_base_url: None,
+    og_image: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: true,
+    collapse_type_threshold: None,
   };
 
   let search_index = generate_search_index(&ctx, &doc_nodes_by_url);
@@ -383,13 +1003,7 @@ async fn symbol_search() {
     .join("testdata")
     .join("symbol_search.json");
 
-  if std::env::var("UPDATE").is_ok() {
-    fs::write(&symbol_search_json_path, &file_json).unwrap();
-  }
-
-  let symbol_search_json = fs::read_to_string(symbol_search_json_path).unwrap();
-
-  assert_eq!(file_json, symbol_search_json);
+  assert_snapshot(&symbol_search_json_path, &file_json);
 }
 
 #[tokio::test]
@@ -429,6 +1043,16 @@ async fn module_doc() {
     single_file_mode: true,
     sidebar_hide_all_symbols: false,
     sidebar_flatten_namespaces: false,
+    custom_css: None,
+    custom_head_html: None,
+    og_base_url: None,
+    og_image: None,
+    class_diagrams: false,
+    exact_optional_property_types: false,
+    union_break_threshold: 4,
+    check_implementations: false,
+    per_symbol_pages: true,
+    collapse_type_threshold: None,
   };
 
   let mut module_docs = vec![];
@@ -463,10 +1087,333 @@ async fn module_doc() {
   #[cfg(all(not(feature = "syntect"), not(feature = "tree-sitter")))]
   let module_docs_json_path = testdata_path.join("module_doc.json");
 
-  if std::env::var("UPDATE").is_ok() {
-    fs::write(&module_docs_json_path, &file_json).unwrap();
+  assert_snapshot(&module_docs_json_path, &file_json);
+}
+
+#[tokio::test]
+async fn html_doc_files_max_depth() {
+  let files = generate(
+    generate_options(),
+    &get_files_with_options(
+      "max_depth",
+      DocParserOptions {
+        diagnostics: false,
+        private: false,
+        prefer_declaration_files: false,
+        include_source: false,
+        mode: DocMode::Full,
+        max_depth: Some(2),
+        isolated_declarations: false,
+      },
+    )
+    .await,
+  )
+  .unwrap();
+
+  let page = files.get("./~/Deep.html").unwrap();
+  assert!(page.contains("<span>...</span>"));
+  let page = html_escape::decode_html_entities(&strip_tags(page)).to_string();
+  assert!(page.contains("b: ..."));
+  assert!(!page.contains("c: {"));
+}
+
+#[tokio::test]
+async fn html_doc_files_class_mixins() {
+  let files = generate(generate_options(), &get_files("mixins").await).unwrap();
+
+  let page = |name: &str| {
+    let page = files.get(&format!("./~/{name}.html")).unwrap();
+    html_escape::decode_html_entities(&strip_tags(page)).to_string()
+  };
+  assert!(page("User").contains("extends Timestamped(Activatable(Base))"));
+  assert!(
+    page("Admin").contains("extends Timestamped(Activatable(Tagged(Base)))")
+  );
+  let page = files.get("./~/User.html").unwrap();
+  assert!(page.contains(
+    r#"~&#x2F;Timestamped.html" class="link">Timestamped</a><span>(</span>"#
+  ));
+}
+
+#[tokio::test]
+async fn html_doc_files_override_property() {
+  let files =
+    generate(generate_options(), &get_files("override").await).unwrap();
+
+  let page = |name: &str| {
+    let page = files.get(&format!("./~/{name}.html")).unwrap();
+    html_escape::decode_html_entities(&strip_tags(page))
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ")
+  };
+  assert!(page("Square").contains("override name: string"));
+  assert!(!page("Square").contains("abstract name"));
+  assert!(page("Shape").contains("abstract name: string"));
+}
+
+#[tokio::test]
+async fn html_doc_files_declare_property() {
+  let files =
+    generate(generate_options(), &get_files("declare").await).unwrap();
+
+  let page = files.get("./~/Component.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page))
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  assert!(page.contains("declare element: HTMLElement"));
+  assert!(page.contains("name: string"));
+  assert!(!page.contains("declare name"));
+}
+
+#[tokio::test]
+async fn html_doc_files_object_types() {
+  let files =
+    generate(generate_options(), &get_files("object_types").await).unwrap();
+
+  for (name, ts_type) in [
+    ("nonPrimitive", "object"),
+    ("nonNullish", "{}"),
+    ("wrapper", "Object"),
+  ] {
+    let page = files.get(&format!("./~/{name}.html")).unwrap();
+    let page = html_escape::decode_html_entities(&strip_tags(page))
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ");
+    assert!(page.ends_with(&format!("Type {ts_type}")), "{page}");
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_constructor_overloads() {
+  let files = generate(
+    generate_options(),
+    &get_files("constructor_overloads").await,
+  )
+  .unwrap();
+
+  let page = files.get("./~/Point.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page))
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  let positions = [
+    "Point(x: number, y: number) From coordinates.",
+    "Point(xy: [number, number]) From a tuple.",
+    "Point(point: Point) From another point.",
+    "Point(a: unknown, b?: number)",
+  ]
+  .map(|signature| page.find(signature).expect(signature));
+  assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{page}");
+}
+
+#[tokio::test]
+async fn html_doc_files_param_default_values() {
+  let files =
+    generate(generate_options(), &get_files("default_values").await).unwrap();
+
+  let page = files.get("./~/connect.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page))
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  assert!(page.contains("port: number = 80"), "{page}");
+  assert!(page.contains(r#"host: string = "localhost""#), "{page}");
+  assert!(page.contains("= { retries: 3, verbose: false }"), "{page}");
+  assert!(page.contains("signal: AbortSignal | undefined = undefined"));
+}
+
+#[tokio::test]
+async fn html_doc_files_enum_member_docs() {
+  let files =
+    generate(generate_options(), &get_files("enum_members").await).unwrap();
+
+  let page = files.get("./~/Color.html").unwrap();
+  let page = html_escape::decode_html_entities(&strip_tags(page))
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  // the members are sorted by name, and the undocumented one has no
+  // description
+  assert!(page.contains(
+    "Members Blue = 2 The blue color Green = 1 Red = 0 The red color"
+  ));
+}
+
+#[tokio::test]
+async fn html_doc_files_overridden_by() {
+  let files =
+    generate(generate_options(), &get_files("overridden_by").await).unwrap();
+
+  let page = files.get("./~/Base.html").unwrap();
+  assert!(page.contains("../././~/SubclassA.html#method_start_0"));
+  let page = html_escape::decode_html_entities(&strip_tags(page))
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  assert!(page.contains("Starts the task. Overridden by: SubclassA"));
+  assert!(page.contains("Stops the task. Overridden by: SubclassB"));
+}
+
+/// Links the members of the `Deno` namespace to the API reference, and
+/// leaves the other symbols to the built-in resolution.
+struct DenoApiResolver {}
+
+impl HrefResolver for DenoApiResolver {
+  fn resolve_symbol_link(
+    &self,
+    symbol: &str,
+    _current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    symbol
+      .strip_prefix("Deno.")
+      .map(|name| format!("https://deno.land/api?s=Deno.{name}"))
+  }
+
+  fn resolve_global_symbol(&self, symbol: &[String]) -> Option<String> {
+    EmptyResolver {}.resolve_global_symbol(symbol)
+  }
+
+  fn resolve_import_href(
+    &self,
+    symbol: &[String],
+    src: &str,
+  ) -> Option<String> {
+    EmptyResolver {}.resolve_import_href(symbol, src)
   }
 
-  let module_docs_json = fs::read_to_string(module_docs_json_path).unwrap();
-  assert_eq!(file_json, module_docs_json);
+  fn resolve_usage(
+    &self,
+    current_specifier: &ModuleSpecifier,
+    current_file: Option<&ShortPath>,
+  ) -> Option<String> {
+    EmptyResolver {}.resolve_usage(current_specifier, current_file)
+  }
+
+  fn resolve_source(&self, location: &deno_doc::Location) -> Option<String> {
+    EmptyResolver {}.resolve_source(location)
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_symbol_link_resolver() {
+  let files = generate(
+    GenerateOptions {
+      href_resolver: Rc::new(DenoApiResolver {}),
+      ..generate_options()
+    },
+    &get_files("symbol_link_resolver").await,
+  )
+  .unwrap();
+
+  let page =
+    html_escape::decode_html_entities(files.get("./~/spawn.html").unwrap())
+      .into_owned();
+  assert!(page.contains(r#"href="https://deno.land/api?s=Deno.Command""#));
+  assert!(page.contains(r#"href="https://deno.land/api?s=Deno.ChildProcess""#));
+  // the symbols of the documented modules keep the built-in links
+  assert!(page.contains(r#"href="../././~/SpawnOptions.html""#));
+}
+
+#[tokio::test]
+async fn html_doc_files_check_implementations() {
+  for check_implementations in [true, false] {
+    let files = generate(
+      GenerateOptions {
+        check_implementations,
+        ..generate_options()
+      },
+      &get_files("check_implementations").await,
+    )
+    .unwrap();
+
+    let file_page = files.get("./~/File.html").unwrap();
+    let buffer_page = files.get("./~/Buffer.html").unwrap();
+    let pipe_page = files.get("./~/Pipe.html").unwrap();
+    let sink_page = files.get("./~/io.Sink.html").unwrap();
+    if check_implementations {
+      assert!(file_page.contains(
+        r#"<span class="implements_check_ok" title="Implements all the required members"> &#x2713;</span>"#
+      ));
+      assert!(buffer_page.contains(
+        r#"<span class="implements_check_missing" title="Missing: close"> &#x2717;</span>"#
+      ));
+      assert!(pipe_page.contains(
+        r#"<span class="implements_check_ok" title="Implements all the required members"> &#x2713;</span>"#
+      ));
+      assert!(sink_page.contains(
+        r#"<span class="implements_check_missing" title="Missing: write"> &#x2717;</span>"#
+      ));
+    } else {
+      assert!(!file_page.contains("implements_check"));
+      assert!(!buffer_page.contains("implements_check"));
+      assert!(!pipe_page.contains("implements_check"));
+      assert!(!sink_page.contains("implements_check"));
+    }
+  }
+}
+
+#[tokio::test]
+async fn html_doc_files_per_symbol_pages() {
+  let symbols = [
+    "ClientOptions",
+    "Client",
+    "createClient",
+    "Method",
+    "Id",
+    "defaultOptions",
+    "helpers",
+    "helpers.formatId",
+  ];
+
+  for per_symbol_pages in [true, false] {
+    let files = generate(
+      GenerateOptions {
+        per_symbol_pages,
+        sitemap_base_url: Some("https://example.com/".to_string()),
+        ..generate_options()
+      },
+      &get_files("symbol_pages").await,
+    )
+    .unwrap();
+
+    assert!(files.contains_key("./index.html"));
+    let module_page =
+      html_escape::decode_html_entities(files.get("./~/index.html").unwrap())
+        .into_owned();
+
+    for symbol in symbols {
+      let symbol_page_url =
+        symbol_page_url(&ShortPath::from(".".to_string()), symbol);
+      let symbol_page_url = symbol_page_url.to_str().unwrap();
+      let anchor = format!(r#"id="symbol_{symbol}""#);
+
+      if per_symbol_pages {
+        assert!(files.get(symbol_page_url).unwrap().contains(&anchor));
+        assert!(!module_page.contains(&anchor));
+      } else {
+        assert!(!files.contains_key(symbol_page_url));
+        assert!(module_page.contains(&anchor));
+      }
+    }
+
+    let search_index = files.get("search_index.js").unwrap();
+    let sitemap = files.get("sitemap.xml").unwrap();
+    assert_eq!(
+      sitemap.contains("<loc>https://example.com/~/Client.html</loc>"),
+      per_symbol_pages
+    );
+    assert!(sitemap.contains("<loc>https://example.com/~/index.html</loc>"));
+    if per_symbol_pages {
+      assert!(module_page.contains(r#"href="../././~/Client.html""#));
+      assert!(search_index.contains(r#""perSymbolPages":true"#));
+    } else {
+      assert!(
+        module_page.contains(r#"href="../././~/index.html#symbol_Client""#)
+      );
+      assert!(search_index.contains(r#""perSymbolPages":false"#));
+    }
+  }
 }
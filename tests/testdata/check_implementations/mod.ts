@@ -0,0 +1,47 @@
+/** A source of bytes. */
+export interface Reader {
+  /** Reads bytes into `p`. */
+  read(p: Uint8Array): number;
+  /** Reads bytes without consuming them. */
+  peek?(): number;
+}
+
+/** A resource to release. */
+export interface Closer {
+  /** Releases the resource. */
+  close(): void;
+}
+
+/** A file, which implements all the members of its interfaces. */
+export class File implements Reader, Closer {
+  read(p: Uint8Array): number {
+    return 0;
+  }
+  close(): void {}
+}
+
+/** A buffer, which doesn't implement `close`. */
+export class Buffer implements Reader, Closer {
+  read(p: Uint8Array): number {
+    return 0;
+  }
+}
+
+/** Writable resources. */
+export namespace io {
+  /** A sink of bytes. */
+  export interface Writer {
+    /** Writes the bytes of `p`. */
+    write(p: Uint8Array): number;
+  }
+
+  /** A sink, which doesn't implement `write`. */
+  export class Sink implements Writer {}
+}
+
+/** A pipe, which implements an interface of a namespace. */
+export class Pipe implements io.Writer {
+  write(p: Uint8Array): number {
+    return p.length;
+  }
+}
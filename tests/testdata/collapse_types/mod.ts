@@ -0,0 +1,13 @@
+/** A simple type. */
+export type Name = string;
+
+/** A complex type. */
+export type DeepPartial<T> = T extends object
+  ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : T;
+
+/** A point. */
+export interface Point {
+  x: number;
+  transform: (point: { x: number; y: number }) => { x: number; y: number };
+}
@@ -0,0 +1,10 @@
+/** A point. */
+export class Point {
+  /** From coordinates. */
+  constructor(x: number, y: number);
+  /** From a tuple. */
+  constructor(xy: [number, number]);
+  /** From another point. */
+  constructor(point: Point);
+  constructor(a: unknown, b?: number) {}
+}
@@ -0,0 +1,7 @@
+/** A component. */
+export class Component {
+  /** The element of the component. */
+  declare element: HTMLElement;
+  /** The name of the component. */
+  name = "component";
+}
@@ -0,0 +1,12 @@
+declare function Injectable(): ClassDecorator;
+declare function Component(options: { selector: string }): ClassDecorator;
+declare const sealed: ClassDecorator;
+
+/** A service. */
+@Injectable()
+export class Service {}
+
+/** A component. */
+@sealed
+@Component({ selector: "app" })
+export class AppComponent {}
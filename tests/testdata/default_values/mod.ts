@@ -0,0 +1,7 @@
+/** Connects. */
+export function connect(
+  port: number = 80,
+  host = "localhost",
+  options = { retries: 3, verbose: false },
+  signal: AbortSignal | undefined = undefined,
+) {}
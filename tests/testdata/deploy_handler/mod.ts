@@ -0,0 +1,6 @@
+/** Serves the requests of the deployment. */
+export default {
+  fetch(_req: Request): Response {
+    return new Response("Hello");
+  },
+};
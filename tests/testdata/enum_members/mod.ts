@@ -0,0 +1,8 @@
+/** A color. */
+export enum Color {
+  /** The red color */
+  Red = 0,
+  Green = 1,
+  /** The blue color */
+  Blue = 2,
+}
@@ -0,0 +1,7 @@
+/**
+ * A connection to a server.
+ *
+ * @fires MyEvent
+ * @emits OtherEvent - When something happens
+ */
+export class Connection extends EventTarget {}
@@ -0,0 +1,12 @@
+/** A pair where the second value extends the first. */
+export class Pair<A, B extends A> {
+  constructor(public first: A, public second: B) {}
+}
+
+/** A pair of numbers. */
+export class NumberPair extends Pair<number, number> {}
+
+/** The first value, or the fallback if there are none. */
+export function first<T>(values: T[], fallback: NoInfer<T>): T {
+  return values.length ? values[0] : fallback;
+}
@@ -0,0 +1,10 @@
+/** Options for connecting to a server. */
+export interface Options {
+  /** The port to connect to. */
+  port: number;
+}
+
+/** Connects to a server. */
+export declare function connect(
+  options: import("./mod.d.ts").Options,
+): Promise<import("./mod.d.ts").Options>;
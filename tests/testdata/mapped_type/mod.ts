@@ -0,0 +1,7 @@
+/** A set of boolean flags, one for each key in `T`. */
+export type Flags<T extends string> = { [K in T]: boolean };
+
+/** The getters of `T`. */
+export type Getters<T> = {
+  readonly [K in keyof T as `get${Capitalize<string & K>}`]?: () => T[K];
+};
@@ -0,0 +1,2 @@
+/** A deeply nested type. */
+export type Deep = { a: { b: { c: { d: string } } } };
@@ -0,0 +1,20 @@
+// deno-lint-ignore no-explicit-any
+export type Constructor<T = {}> = new (...args: any[]) => T;
+
+export function Timestamped<T extends Constructor>(base: T) {
+  return class extends base {};
+}
+
+export function Activatable<T extends Constructor>(base: T) {
+  return class extends base {};
+}
+
+export function Tagged<T extends Constructor>(base: T) {
+  return class extends base {};
+}
+
+export class Base {}
+
+export class User extends Timestamped(Activatable(Base)) {}
+
+export class Admin extends Timestamped(Activatable(Tagged(Base))) {}
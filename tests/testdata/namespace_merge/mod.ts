@@ -0,0 +1,24 @@
+/** A color. */
+export enum Color {
+  Red,
+  Green,
+}
+
+/** The color namespace. */
+export namespace Color {
+  /** Parses a color. */
+  export function parse(text: string): Color {
+    return Color.Red;
+  }
+}
+
+/** Some options. */
+export interface Options {
+  verbose?: boolean;
+}
+
+/** The options namespace. */
+export namespace Options {
+  /** The default options. */
+  export const defaults: Options = {};
+}
@@ -0,0 +1,8 @@
+/** A non-primitive. */
+export declare const nonPrimitive: object;
+
+/** Anything but null and undefined. */
+export declare const nonNullish: {};
+
+/** The object wrapper. */
+export declare const wrapper: Object;
@@ -0,0 +1,11 @@
+/** Options for connecting to a server. */
+export interface Options {
+  /** The port to connect to. */
+  port?: number;
+}
+
+/** A connection to a server. */
+export class Connection {
+  /** The timeout in milliseconds. */
+  timeout?: number;
+}
@@ -0,0 +1,15 @@
+export class Base {
+  /** Starts the task. */
+  start(): void {}
+  /** Stops the task. */
+  stop(): void {}
+}
+
+export class SubclassA extends Base {
+  override start(): void {}
+}
+
+export class SubclassB extends Base {
+  override stop(): void {}
+}
+
@@ -0,0 +1,11 @@
+/** A shape. */
+export abstract class Shape {
+  /** The name of the shape. */
+  abstract name: string;
+}
+
+/** A square. */
+export class Square extends Shape {
+  /** The name of the square. */
+  override name = "square";
+}
@@ -0,0 +1,6 @@
+/** Still here. */
+export function unchanged(): void {}
+export function readTextFile(path: string): string {
+  return path;
+}
+export const unrelated = "a";
@@ -0,0 +1,8 @@
+export function unchanged(): void {}
+export function readFile(path: string): string {
+  return path;
+}
+export interface Options {
+  verbose: boolean;
+}
+export const removed = 1;
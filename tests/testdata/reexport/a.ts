@@ -0,0 +1,2 @@
+/** A value. */
+export const value = 1;
@@ -0,0 +1 @@
+export * as values from "./a.ts";
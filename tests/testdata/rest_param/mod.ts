@@ -0,0 +1,8 @@
+/**
+ * Collects items into an array.
+ *
+ * @param ...items The items
+ */
+export function collect(...items: string[]): string[] {
+  return items;
+}
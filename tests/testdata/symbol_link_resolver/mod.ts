@@ -0,0 +1,13 @@
+/** Options of {@linkcode spawn}. */
+export interface SpawnOptions {
+  /** The arguments of the command. */
+  args: string[];
+}
+
+/** Spawns a subprocess. */
+export function spawn(
+  command: Deno.Command,
+  options: SpawnOptions,
+): Deno.ChildProcess {
+  return command.spawn();
+}
@@ -0,0 +1,34 @@
+/** Options of a {@link Client}. */
+export interface ClientOptions {
+  baseUrl: string;
+}
+
+/** A client for the API. */
+export class Client {
+  constructor(options: ClientOptions) {}
+}
+
+/** Creates a {@link Client}. */
+export function createClient(options: ClientOptions): Client {
+  return new Client(options);
+}
+
+/** The methods of a request. */
+export enum Method {
+  Get,
+  Post,
+}
+
+/** The identifier of a resource. */
+export type Id = string | number;
+
+/** The default options. */
+export const defaultOptions: ClientOptions = { baseUrl: "/" };
+
+/** Helpers for the client. */
+export namespace helpers {
+  /** Formats an identifier. */
+  export function formatId(id: Id): string {
+    return String(id);
+  }
+}
@@ -0,0 +1,12 @@
+/**
+ * Opens a connection.
+ *
+ * @tag networking
+ * @tag database access
+ */
+export function connect(): void {}
+
+/** Adds two numbers. */
+export function add(a: number, b: number): number {
+  return a + b;
+}
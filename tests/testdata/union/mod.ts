@@ -0,0 +1,5 @@
+/** A direction to move in. */
+export type Direction = "north" | "south" | "east" | "west" | "up" | "down";
+
+/** An axis. */
+export type Axis = "x" | "y" | "z";
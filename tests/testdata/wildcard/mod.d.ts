@@ -0,0 +1,6 @@
+/** Images imported as URLs. */
+declare module "*.svg" {
+  /** The URL of the image. */
+  const content: string;
+  export default content;
+}